@@ -59,6 +59,8 @@ fn gen_c_api() {
         "gpu",
         #[cfg(feature = "zk-pok")]
         "zk-pok",
+        #[cfg(feature = "strings")]
+        "strings",
         #[cfg(feature = "extended-types")]
         "extended-types",
     ];
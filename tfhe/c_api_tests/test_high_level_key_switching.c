@@ -0,0 +1,224 @@
+#include "tfhe.h"
+
+#include <assert.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Generates a key set from the default config
+void generate_default_keys(ClientKey **client_key, ServerKey **server_key) {
+  ConfigBuilder *builder;
+  Config *config;
+
+  int ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  // Takes ownership of the config
+  ok = generate_keys(config, client_key, server_key);
+  assert(ok == 0);
+}
+
+int keyswitch_expanded_list_test(const ClientKey *client_key_1, const ClientKey *client_key_2,
+                                 const KeySwitchingKey *ksk) {
+  int ok;
+  CompactPublicKey *public_key = NULL;
+  CompactCiphertextListBuilder *builder = NULL;
+  CompactCiphertextList *list = NULL;
+  CompactCiphertextListExpander *expander = NULL;
+
+  // The list is encrypted and expanded under the first key set
+  ok = compact_public_key_new(client_key_1, &public_key);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_new(public_key, &builder);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_u32(builder, 38382);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_i64(builder, -1);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_bool(builder, true);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_build(builder, &list);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expand(list, &expander);
+  assert(ok == 0);
+
+  FheUint32 *a = NULL;
+  FheInt64 *b = NULL;
+  FheBool *c = NULL;
+
+  ok = compact_ciphertext_list_expander_get_fhe_uint32(expander, 0, &a);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expander_get_fhe_int64(expander, 1, &b);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expander_get_fhe_bool(expander, 2, &c);
+  assert(ok == 0);
+
+  // Then switched to the second one
+  FheUint32 *switched_a = NULL;
+  FheInt64 *switched_b = NULL;
+  FheBool *switched_c = NULL;
+
+  ok = fhe_uint32_keyswitch(a, ksk, &switched_a);
+  assert(ok == 0);
+
+  ok = fhe_int64_keyswitch(b, ksk, &switched_b);
+  assert(ok == 0);
+
+  ok = fhe_bool_keyswitch(c, ksk, &switched_c);
+  assert(ok == 0);
+
+  // The server key of the second key set is set, so computations can be done on the results
+  FheUint32 *sum = NULL;
+  ok = fhe_uint32_scalar_add(switched_a, 1, &sum);
+  assert(ok == 0);
+
+  uint32_t clear_a = 0;
+  ok = fhe_uint32_decrypt(sum, client_key_2, &clear_a);
+  assert(ok == 0);
+  assert(clear_a == 38383);
+
+  int64_t clear_b = 0;
+  ok = fhe_int64_decrypt(switched_b, client_key_2, &clear_b);
+  assert(ok == 0);
+  assert(clear_b == -1);
+
+  bool clear_c = false;
+  ok = fhe_bool_decrypt(switched_c, client_key_2, &clear_c);
+  assert(ok == 0);
+  assert(clear_c == true);
+
+  fhe_uint32_destroy(a);
+  fhe_int64_destroy(b);
+  fhe_bool_destroy(c);
+  fhe_uint32_destroy(switched_a);
+  fhe_int64_destroy(switched_b);
+  fhe_bool_destroy(switched_c);
+  fhe_uint32_destroy(sum);
+  compact_ciphertext_list_expander_destroy(expander);
+  compact_ciphertext_list_destroy(list);
+  compact_ciphertext_list_builder_destroy(builder);
+  compact_public_key_destroy(public_key);
+
+  return ok;
+}
+
+int serialization_test(const ClientKey *client_key_1, const ClientKey *client_key_2,
+                       const KeySwitchingKey *ksk) {
+  int ok;
+  KeySwitchingKey *deserialized_ksk = NULL;
+  DynamicBuffer buffer = {.pointer = NULL, .length = 0, .destructor = NULL};
+  DynamicBufferView view = {.pointer = NULL, .length = 0};
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(30);
+
+  ok = key_switching_key_safe_serialize(ksk, &buffer, max_serialization_size);
+  assert(ok == 0);
+
+  view.pointer = buffer.pointer;
+  view.length = buffer.length;
+  ok = key_switching_key_safe_deserialize(view, max_serialization_size, &deserialized_ksk);
+  assert(ok == 0);
+
+  FheUint8 *value = NULL;
+  FheUint8 *switched = NULL;
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(213, client_key_1, &value);
+  assert(ok == 0);
+
+  ok = fhe_uint8_keyswitch(value, deserialized_ksk, &switched);
+  assert(ok == 0);
+
+  uint8_t clear = 0;
+  ok = fhe_uint8_decrypt(switched, client_key_2, &clear);
+  assert(ok == 0);
+  assert(clear == 213);
+
+  fhe_uint8_destroy(value);
+  fhe_uint8_destroy(switched);
+  key_switching_key_destroy(deserialized_ksk);
+  destroy_dynamic_buffer(&buffer);
+
+  return ok;
+}
+
+int with_parameters_test(const ClientKey *client_key_1, const ServerKey *server_key_1,
+                         const ClientKey *client_key_2, const ServerKey *server_key_2) {
+  int ok;
+  KeySwitchingKey *ksk = NULL;
+
+  // Use the keyswitch parameters of the compute parameters of the key sets
+  ShortintPBSParameters params = SHORTINT_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+  ShortintCompactCiphertextListCastingParameters ks_params = {
+      .ks_base_log = params.ks_base_log,
+      .ks_level = params.ks_level,
+      .destination_key = params.encryption_key_choice,
+  };
+
+  ok = key_switching_key_with_parameters(client_key_1, server_key_1, client_key_2, server_key_2,
+                                         ks_params, &ksk);
+  assert(ok == 0);
+
+  FheInt16 *value = NULL;
+  FheInt16 *switched = NULL;
+
+  ok = fhe_int16_try_encrypt_with_client_key_i16(-1234, client_key_1, &value);
+  assert(ok == 0);
+
+  ok = fhe_int16_keyswitch(value, ksk, &switched);
+  assert(ok == 0);
+
+  int16_t clear = 0;
+  ok = fhe_int16_decrypt(switched, client_key_2, &clear);
+  assert(ok == 0);
+  assert(clear == -1234);
+
+  fhe_int16_destroy(value);
+  fhe_int16_destroy(switched);
+  key_switching_key_destroy(ksk);
+
+  return ok;
+}
+
+int main(void) {
+  int ok = 0;
+
+  ClientKey *client_key_1 = NULL;
+  ServerKey *server_key_1 = NULL;
+  ClientKey *client_key_2 = NULL;
+  ServerKey *server_key_2 = NULL;
+
+  generate_default_keys(&client_key_1, &server_key_1);
+  generate_default_keys(&client_key_2, &server_key_2);
+
+  KeySwitchingKey *ksk = NULL;
+  ok = key_switching_key_new(client_key_1, server_key_1, client_key_2, server_key_2, &ksk);
+  assert(ok == 0);
+
+  ok = set_server_key(server_key_2);
+  assert(ok == 0);
+
+  ok = keyswitch_expanded_list_test(client_key_1, client_key_2, ksk);
+  assert(ok == 0);
+  ok = serialization_test(client_key_1, client_key_2, ksk);
+  assert(ok == 0);
+  ok = with_parameters_test(client_key_1, server_key_1, client_key_2, server_key_2);
+  assert(ok == 0);
+
+  key_switching_key_destroy(ksk);
+  client_key_destroy(client_key_1);
+  server_key_destroy(server_key_1);
+  client_key_destroy(client_key_2);
+  server_key_destroy(server_key_2);
+
+  return EXIT_SUCCESS;
+}
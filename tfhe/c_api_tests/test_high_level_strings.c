@@ -0,0 +1,294 @@
+#include "tfhe.h"
+
+#include <assert.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+// Decrypts the string and checks it is equal to `expected`
+void assert_decrypts_to(const FheAsciiString *string, const ClientKey *client_key,
+                        const char *expected) {
+  DynamicBuffer buffer = {.pointer = NULL, .length = 0, .destructor = NULL};
+
+  int ok = fhe_ascii_string_decrypt(string, client_key, &buffer);
+  assert(ok == 0);
+
+  // The buffer holds the null terminator
+  assert(buffer.length == strlen(expected) + 1);
+  assert(strcmp((const char *)buffer.pointer, expected) == 0);
+
+  destroy_dynamic_buffer(&buffer);
+}
+
+bool decrypt_bool(FheBool *value, const ClientKey *client_key) {
+  bool clear = false;
+  int ok = fhe_bool_decrypt(value, client_key, &clear);
+  assert(ok == 0);
+  fhe_bool_destroy(value);
+  return clear;
+}
+
+int comparisons_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *lhs = NULL;
+  FheAsciiString *rhs = NULL;
+  FheBool *result = NULL;
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("apple", 2, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_try_encrypt_with_fixed_sized("banana", 8, client_key, &rhs);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_eq(lhs, rhs, &result);
+  assert(ok == 0);
+  assert(decrypt_bool(result, client_key) == false);
+
+  ok = fhe_ascii_string_lt(lhs, rhs, &result);
+  assert(ok == 0);
+  assert(decrypt_bool(result, client_key) == true);
+
+  ok = fhe_ascii_string_scalar_eq(lhs, "apple", &result);
+  assert(ok == 0);
+  assert(decrypt_bool(result, client_key) == true);
+
+  ok = fhe_ascii_string_scalar_ge(rhs, "cherry", &result);
+  assert(ok == 0);
+  assert(decrypt_bool(result, client_key) == false);
+
+  FheUint16 *len = NULL;
+  ok = fhe_ascii_string_len(lhs, &len);
+  assert(ok == 0);
+
+  uint16_t clear_len = 0;
+  ok = fhe_uint16_decrypt(len, client_key, &clear_len);
+  assert(ok == 0);
+  assert(clear_len == 5);
+
+  ok = fhe_ascii_string_is_empty(rhs, &result);
+  assert(ok == 0);
+  assert(decrypt_bool(result, client_key) == false);
+
+  fhe_uint16_destroy(len);
+  fhe_ascii_string_destroy(lhs);
+  fhe_ascii_string_destroy(rhs);
+
+  return ok;
+}
+
+int patterns_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *string = NULL;
+  FheAsciiString *pattern = NULL;
+  FheAsciiString *to = NULL;
+  FheAsciiString *result = NULL;
+  FheBool *found = NULL;
+  FheUint32 *index = NULL;
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("the cat sat", 0, client_key, &string);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("at", 0, client_key, &pattern);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("og", 0, client_key, &to);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_contains(string, pattern, &found);
+  assert(ok == 0);
+  assert(decrypt_bool(found, client_key) == true);
+
+  ok = fhe_ascii_string_scalar_starts_with(string, "dog", &found);
+  assert(ok == 0);
+  assert(decrypt_bool(found, client_key) == false);
+
+  ok = fhe_ascii_string_rfind(string, pattern, &index, &found);
+  assert(ok == 0);
+  assert(decrypt_bool(found, client_key) == true);
+
+  uint32_t clear_index = 0;
+  ok = fhe_uint32_decrypt(index, client_key, &clear_index);
+  assert(ok == 0);
+  assert(clear_index == 9);
+  fhe_uint32_destroy(index);
+
+  ok = fhe_ascii_string_scalar_find(string, "cat", &index, &found);
+  assert(ok == 0);
+  assert(decrypt_bool(found, client_key) == true);
+
+  ok = fhe_uint32_decrypt(index, client_key, &clear_index);
+  assert(ok == 0);
+  assert(clear_index == 4);
+  fhe_uint32_destroy(index);
+
+  ok = fhe_ascii_string_replace(string, pattern, to, &result);
+  assert(ok == 0);
+  assert_decrypts_to(result, client_key, "the cog sog");
+  fhe_ascii_string_destroy(result);
+
+  ok = fhe_ascii_string_scalar_replace(string, "the ", to, &result);
+  assert(ok == 0);
+  assert_decrypts_to(result, client_key, "ogcat sat");
+  fhe_ascii_string_destroy(result);
+
+  fhe_ascii_string_destroy(string);
+  fhe_ascii_string_destroy(pattern);
+  fhe_ascii_string_destroy(to);
+
+  return ok;
+}
+
+int trim_and_case_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *string = NULL;
+  FheAsciiString *trimmed = NULL;
+  FheAsciiString *result = NULL;
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("  TFHE-rs ", 1, client_key, &string);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_trim(string, &trimmed);
+  assert(ok == 0);
+  assert_decrypts_to(trimmed, client_key, "TFHE-rs");
+
+  ok = fhe_ascii_string_trim_end(string, &result);
+  assert(ok == 0);
+  assert_decrypts_to(result, client_key, "  TFHE-rs");
+  fhe_ascii_string_destroy(result);
+
+  ok = fhe_ascii_string_to_lowercase(trimmed, &result);
+  assert(ok == 0);
+  assert_decrypts_to(result, client_key, "tfhe-rs");
+  fhe_ascii_string_destroy(result);
+
+  ok = fhe_ascii_string_concat(trimmed, trimmed, &result);
+  assert(ok == 0);
+  assert_decrypts_to(result, client_key, "TFHE-rsTFHE-rs");
+  fhe_ascii_string_destroy(result);
+
+  fhe_ascii_string_destroy(string);
+  fhe_ascii_string_destroy(trimmed);
+
+  return ok;
+}
+
+int serialization_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *string = NULL;
+  FheAsciiString *cloned = NULL;
+  FheAsciiString *deserialized = NULL;
+  DynamicBuffer buffer = {.pointer = NULL, .length = 0, .destructor = NULL};
+  DynamicBufferView view = {.pointer = NULL, .length = 0};
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(30);
+
+  ok = fhe_ascii_string_try_encrypt_with_padding("zama", 3, client_key, &string);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_clone(string, &cloned);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_safe_serialize(cloned, &buffer, max_serialization_size);
+  assert(ok == 0);
+
+  view.pointer = buffer.pointer;
+  view.length = buffer.length;
+  ok = fhe_ascii_string_safe_deserialize(view, max_serialization_size, &deserialized);
+  assert(ok == 0);
+  assert_decrypts_to(deserialized, client_key, "zama");
+
+  destroy_dynamic_buffer(&buffer);
+  fhe_ascii_string_destroy(string);
+  fhe_ascii_string_destroy(cloned);
+  fhe_ascii_string_destroy(deserialized);
+
+  return ok;
+}
+
+int compact_list_test(const ClientKey *client_key) {
+  int ok;
+  CompactPublicKey *public_key = NULL;
+  CompactCiphertextListBuilder *builder = NULL;
+  CompactCiphertextList *list = NULL;
+  CompactCiphertextListExpander *expander = NULL;
+  FheAsciiString *a = NULL;
+  FheAsciiString *b = NULL;
+
+  ok = compact_public_key_new(client_key, &public_key);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_new(public_key, &builder);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_string(builder, "hello");
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_string_with_padding(builder, "world", 4);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_build(builder, &list);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expand(list, &expander);
+  assert(ok == 0);
+
+  FheTypes type = Type_FheBool;
+  ok = compact_ciphertext_list_expander_get_kind_of(expander, 1, &type);
+  assert(ok == 0 && type == Type_FheAsciiString);
+
+  ok = compact_ciphertext_list_expander_get_fhe_ascii_string(expander, 0, &a);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expander_get_fhe_ascii_string(expander, 1, &b);
+  assert(ok == 0);
+
+  assert_decrypts_to(a, client_key, "hello");
+  assert_decrypts_to(b, client_key, "world");
+
+  fhe_ascii_string_destroy(a);
+  fhe_ascii_string_destroy(b);
+  compact_ciphertext_list_expander_destroy(expander);
+  compact_ciphertext_list_destroy(list);
+  compact_ciphertext_list_builder_destroy(builder);
+  compact_public_key_destroy(public_key);
+
+  return ok;
+}
+
+int main(void) {
+  int ok = 0;
+
+  ConfigBuilder *builder;
+  Config *config;
+
+  ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+
+  ok = generate_keys(config, &client_key, &server_key);
+  assert(ok == 0);
+
+  ok = set_server_key(server_key);
+  assert(ok == 0);
+
+  ok = comparisons_test(client_key);
+  assert(ok == 0);
+  ok = patterns_test(client_key);
+  assert(ok == 0);
+  ok = trim_and_case_test(client_key);
+  assert(ok == 0);
+  ok = serialization_test(client_key);
+  assert(ok == 0);
+  ok = compact_list_test(client_key);
+  assert(ok == 0);
+
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+
+  return EXIT_SUCCESS;
+}
@@ -11,7 +11,12 @@ const {
   TfheCompressedCompactPublicKey,
   ShortintCompactPublicKeyEncryptionParametersName,
   ShortintCompactPublicKeyEncryptionParameters,
+  ShortintCompressionParametersName,
+  ShortintCompressionParameters,
   TfheCompactPublicKey,
+  TfheServerKey,
+  TfheKeySwitchingKey,
+  set_server_key,
   TfheConfigBuilder,
   CompressedFheInt8,
   FheInt8,
@@ -21,7 +26,9 @@ const {
   FheInt128,
   CompressedFheInt256,
   FheInt256,
+  FheBool,
   CompactCiphertextList,
+  CompressedCiphertextList,
   ProvenCompactCiphertextList,
   CompactPkeCrs,
   ZkComputeLoad,
@@ -561,6 +568,113 @@ test("hlapi_compact_ciphertext_list", (t) => {
   );
 });
 
+test("hlapi_key_switching_key_on_expanded_list", (t) => {
+  let config = TfheConfigBuilder.default().build();
+
+  let clientKey1 = TfheClientKey.generate(config);
+  let serverKey1 = TfheServerKey.new(clientKey1);
+  let clientKey2 = TfheClientKey.generate(config);
+  let serverKey2 = TfheServerKey.new(clientKey2);
+
+  let ksk = TfheKeySwitchingKey.new(
+    clientKey1,
+    serverKey1,
+    clientKey2,
+    serverKey2,
+  );
+
+  let serialized = ksk.safe_serialize(BigInt(1000000000));
+  ksk = TfheKeySwitchingKey.safe_deserialize(serialized, BigInt(1000000000));
+
+  let publicKey = TfheCompactPublicKey.new(clientKey1);
+
+  let clear_i32 = -3284;
+  let clear_bool = true;
+
+  let builder = CompactCiphertextList.builder(publicKey);
+  builder.push_i32(clear_i32);
+  builder.push_boolean(clear_bool);
+  let expander = builder.build().expand();
+
+  let int32 = expander.get_int32(0).keyswitch(ksk);
+  let bool = expander.get_bool(1).keyswitch(ksk);
+
+  assert.deepStrictEqual(int32.decrypt(clientKey2), clear_i32);
+  assert.deepStrictEqual(bool.decrypt(clientKey2), clear_bool);
+
+  const block_params = new ShortintParameters(
+    ShortintParametersName.PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+  );
+  let ksk_with_params = TfheKeySwitchingKey.with_parameters(
+    clientKey1,
+    serverKey1,
+    clientKey2,
+    serverKey2,
+    block_params.ks_base_log(),
+    block_params.ks_level(),
+    block_params.encryption_key_choice(),
+  );
+
+  let int8 = FheInt8.encrypt_with_client_key(-42, clientKey1);
+  assert.deepStrictEqual(
+    int8.keyswitch(ksk_with_params).decrypt(clientKey2),
+    -42,
+  );
+});
+
+test("hlapi_compressed_ciphertext_list", (t) => {
+  const compression_params = new ShortintCompressionParameters(
+    ShortintCompressionParametersName.COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+  );
+  let config = TfheConfigBuilder.default()
+    .enable_compression(compression_params)
+    .build();
+
+  let clientKey = TfheClientKey.generate(config);
+  let serverKey = TfheServerKey.new(clientKey);
+  // Building and getting values from the list uses the compression keys
+  set_server_key(serverKey);
+
+  let clear_i8 = -27;
+  let clear_i32 = -3284;
+  let clear_bool = true;
+  let clear_i256 = I256_MIN;
+
+  let builder = CompressedCiphertextList.builder();
+  builder.push_int8(FheInt8.encrypt_with_client_key(clear_i8, clientKey));
+  builder.push_int32(FheInt32.encrypt_with_client_key(clear_i32, clientKey));
+  builder.push_boolean(FheBool.encrypt_with_client_key(clear_bool, clientKey));
+  builder.push_int256(FheInt256.encrypt_with_client_key(clear_i256, clientKey));
+  let list = builder.build();
+
+  let serialized = list.safe_serialize(BigInt(10000000));
+  let deserialized = CompressedCiphertextList.safe_deserialize(
+    serialized,
+    BigInt(10000000),
+  );
+
+  assert.deepStrictEqual(deserialized.is_empty(), false);
+  assert.deepStrictEqual(deserialized.len(), 4);
+  assert.deepStrictEqual(deserialized.get_kind_of(0), FheTypes.Int8);
+  assert.deepStrictEqual(deserialized.get_kind_of(1), FheTypes.Int32);
+  assert.deepStrictEqual(deserialized.get_kind_of(2), FheTypes.Bool);
+  assert.deepStrictEqual(deserialized.get_kind_of(3), FheTypes.Int256);
+
+  assert.deepStrictEqual(deserialized.get_int8(0).decrypt(clientKey), clear_i8);
+  assert.deepStrictEqual(
+    deserialized.get_int32(1).decrypt(clientKey),
+    clear_i32,
+  );
+  assert.deepStrictEqual(
+    deserialized.get_bool(2).decrypt(clientKey),
+    clear_bool,
+  );
+  assert.deepStrictEqual(
+    deserialized.get_int256(3).decrypt(clientKey),
+    clear_i256,
+  );
+});
+
 test("hlapi_compact_ciphertext_list_with_proof", (t) => {
   const block_params = new ShortintParameters(
     ShortintParametersName.PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
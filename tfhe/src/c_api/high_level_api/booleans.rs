@@ -74,3 +74,19 @@ pub unsafe extern "C" fn fhe_bool_compress(
         *result = Box::into_raw(Box::new(CompressedFheBool(compressed_inner)));
     })
 }
+
+/// Switches the ciphertext to the key set the key switching key targets
+#[no_mangle]
+pub unsafe extern "C" fn fhe_bool_keyswitch(
+    sself: *const FheBool,
+    key_switching_key: *const crate::c_api::high_level_api::keys::KeySwitchingKey,
+    result: *mut *mut FheBool,
+) -> std::os::raw::c_int {
+    crate::c_api::utils::catch_panic(|| {
+        let ct = crate::c_api::utils::get_ref_checked(sself).unwrap();
+        let ksk = crate::c_api::utils::get_ref_checked(key_switching_key).unwrap();
+
+        let inner = ksk.0.keyswitch(&ct.0);
+        *result = Box::into_raw(Box::new(FheBool(inner)));
+    })
+}
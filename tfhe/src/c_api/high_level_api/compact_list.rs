@@ -7,6 +7,8 @@ use crate::c_api::high_level_api::integers::{
     FheUint160, FheUint2, FheUint256, FheUint32, FheUint4, FheUint6, FheUint64, FheUint8,
 };
 use crate::c_api::high_level_api::keys::CompactPublicKey;
+#[cfg(feature = "strings")]
+use crate::c_api::high_level_api::strings::FheAsciiString;
 use crate::c_api::high_level_api::u128::U128;
 use crate::c_api::high_level_api::u256::U256;
 use crate::c_api::high_level_api::utils::{
@@ -164,6 +166,40 @@ define_compact_ciphertext_list_builder_push_method!(
     signed: 2: i8, 4: i8, 6: i8, 8: i8, 10: i16, 12: i16, 14: i16, 16: i16, 32: i32, 64: i64, 128: I128, 160: I256, 256: I256
 );
 
+/// Pushes a null-terminated ASCII string to the list
+#[cfg(feature = "strings")]
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_builder_push_string(
+    builder: *mut CompactCiphertextListBuilder,
+    value: *const std::ffi::c_char,
+) -> c_int {
+    catch_panic(|| {
+        let builder = get_mut_checked(builder).unwrap();
+        let value = crate::c_api::utils::get_str_checked(value).unwrap();
+        builder
+            .0
+            .push_string(&crate::ClearString::new(value.to_owned()));
+    })
+}
+
+/// Pushes a null-terminated ASCII string to the list, followed by `padding` nulls that hide its
+/// length
+#[cfg(feature = "strings")]
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_builder_push_string_with_padding(
+    builder: *mut CompactCiphertextListBuilder,
+    value: *const std::ffi::c_char,
+    padding: u32,
+) -> c_int {
+    catch_panic(|| {
+        let builder = get_mut_checked(builder).unwrap();
+        let value = crate::c_api::utils::get_str_checked(value).unwrap();
+        builder
+            .0
+            .push_string_with_padding(&crate::ClearString::new(value.to_owned()), padding);
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn compact_ciphertext_list_expand(
     compact_list: *const CompactCiphertextList,
@@ -321,3 +357,19 @@ pub unsafe extern "C" fn compact_ciphertext_list_expander_get_fhe_bool(
         *out = Box::into_raw(Box::new(FheBool(inner)));
     })
 }
+
+#[cfg(feature = "strings")]
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_expander_get_fhe_ascii_string(
+    expander: *mut CompactCiphertextListExpander,
+    index: usize,
+    out: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        let expander = get_mut_checked(expander).unwrap();
+
+        let inner = expander.0.get(index).unwrap().unwrap();
+
+        *out = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
@@ -345,6 +345,25 @@ macro_rules! create_integer_wrapper_type {
         #[cfg(feature = "extended-types")]
         define_all_cast_into_for_extended_integer_type!($name);
 
+        ::paste::paste! {
+            /// Switches the ciphertext to the key set the key switching key targets
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _keyswitch>](
+                sself: *const $name,
+                key_switching_key: *const $crate::c_api::high_level_api::keys::KeySwitchingKey,
+                result: *mut *mut $name,
+            ) -> ::std::os::raw::c_int {
+                $crate::c_api::utils::catch_panic(|| {
+                    let ct = $crate::c_api::utils::get_ref_checked(sself).unwrap();
+                    let ksk = $crate::c_api::utils::get_ref_checked(key_switching_key).unwrap();
+
+                    let inner = ksk.0.keyswitch(&ct.0);
+
+                    *result = Box::into_raw(Box::new($name(inner)));
+                })
+            }
+        }
+
         // The compressed version of the ciphertext type
         ::paste::paste! {
             pub struct [<Compressed $name>]($crate::high_level_api::[<Compressed $name>]);
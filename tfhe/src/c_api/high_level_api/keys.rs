@@ -18,6 +18,9 @@ pub struct CompressedServerKey(pub(crate) crate::high_level_api::CompressedServe
 #[cfg(feature = "gpu")]
 pub struct CudaServerKey(pub(crate) crate::high_level_api::CudaServerKey);
 
+/// Key to switch ciphertexts encrypted under one key set to another key set
+pub struct KeySwitchingKey(pub(crate) crate::high_level_api::KeySwitchingKey);
+
 impl_destroy_on_type!(ClientKey);
 impl_destroy_on_type!(PublicKey);
 impl_destroy_on_type!(CompactPublicKey);
@@ -26,6 +29,7 @@ impl_destroy_on_type!(ServerKey);
 impl_destroy_on_type!(CompressedServerKey);
 #[cfg(feature = "gpu")]
 impl_destroy_on_type!(CudaServerKey);
+impl_destroy_on_type!(KeySwitchingKey);
 
 impl_serialize_deserialize_on_type!(ClientKey);
 impl_serialize_deserialize_on_type!(PublicKey);
@@ -33,6 +37,7 @@ impl_serialize_deserialize_on_type!(CompactPublicKey);
 impl_serialize_deserialize_on_type!(CompressedCompactPublicKey);
 impl_serialize_deserialize_on_type!(ServerKey);
 impl_serialize_deserialize_on_type!(CompressedServerKey);
+impl_serialize_deserialize_on_type!(KeySwitchingKey);
 
 impl_safe_serialize_on_type!(ClientKey);
 impl_safe_serialize_on_type!(PublicKey);
@@ -40,12 +45,14 @@ impl_safe_serialize_on_type!(CompactPublicKey);
 impl_safe_serialize_on_type!(CompressedCompactPublicKey);
 impl_safe_serialize_on_type!(ServerKey);
 impl_safe_serialize_on_type!(CompressedServerKey);
+impl_safe_serialize_on_type!(KeySwitchingKey);
 
 impl_safe_deserialize_on_type!(ClientKey);
 impl_safe_deserialize_on_type!(PublicKey);
 impl_safe_deserialize_on_type!(CompactPublicKey);
 impl_safe_deserialize_on_type!(CompressedCompactPublicKey);
 impl_safe_deserialize_on_type!(CompressedServerKey);
+impl_safe_deserialize_on_type!(KeySwitchingKey);
 
 #[no_mangle]
 pub unsafe extern "C" fn generate_keys(
@@ -230,3 +237,64 @@ pub unsafe extern "C" fn compressed_compact_public_key_decompress(
         *result_public_key = Box::into_raw(Box::new(CompactPublicKey(public_key.0.decompress())));
     })
 }
+
+/// Creates a key to switch ciphertexts from the `from` key set to the `to` key set
+///
+/// Both key sets must have been generated with the same parameters, otherwise an error is
+/// returned, in that case use `key_switching_key_with_parameters`.
+#[no_mangle]
+pub unsafe extern "C" fn key_switching_key_new(
+    client_key_from: *const ClientKey,
+    server_key_from: *const ServerKey,
+    client_key_to: *const ClientKey,
+    server_key_to: *const ServerKey,
+    result_key_switching_key: *mut *mut KeySwitchingKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_key_switching_key).unwrap();
+        *result_key_switching_key = std::ptr::null_mut();
+
+        let client_key_from = get_ref_checked(client_key_from).unwrap();
+        let server_key_from = get_ref_checked(server_key_from).unwrap();
+        let client_key_to = get_ref_checked(client_key_to).unwrap();
+        let server_key_to = get_ref_checked(server_key_to).unwrap();
+
+        let inner = crate::high_level_api::KeySwitchingKey::new(
+            (&client_key_from.0, &server_key_from.0),
+            (&client_key_to.0, &server_key_to.0),
+        )
+        .unwrap();
+
+        *result_key_switching_key = Box::into_raw(Box::new(KeySwitchingKey(inner)));
+    })
+}
+
+/// Creates a key to switch ciphertexts from the `from` key set to the `to` key set, using the
+/// given key switching parameters
+#[no_mangle]
+pub unsafe extern "C" fn key_switching_key_with_parameters(
+    client_key_from: *const ClientKey,
+    server_key_from: *const ServerKey,
+    client_key_to: *const ClientKey,
+    server_key_to: *const ServerKey,
+    params: crate::c_api::shortint::parameters::ShortintCompactCiphertextListCastingParameters,
+    result_key_switching_key: *mut *mut KeySwitchingKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_key_switching_key).unwrap();
+        *result_key_switching_key = std::ptr::null_mut();
+
+        let client_key_from = get_ref_checked(client_key_from).unwrap();
+        let server_key_from = get_ref_checked(server_key_from).unwrap();
+        let client_key_to = get_ref_checked(client_key_to).unwrap();
+        let server_key_to = get_ref_checked(server_key_to).unwrap();
+
+        let inner = crate::high_level_api::KeySwitchingKey::with_parameters(
+            (&client_key_from.0, &server_key_from.0),
+            (&client_key_to.0, &server_key_to.0),
+            params.into(),
+        );
+
+        *result_key_switching_key = Box::into_raw(Box::new(KeySwitchingKey(inner)));
+    })
+}
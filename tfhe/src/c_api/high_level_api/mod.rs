@@ -11,6 +11,8 @@ pub mod i256;
 pub mod i512;
 pub mod integers;
 pub mod keys;
+#[cfg(feature = "strings")]
+pub mod strings;
 mod threading;
 pub mod u1024;
 pub mod u128;
@@ -0,0 +1,257 @@
+use super::utils::*;
+use crate::c_api::high_level_api::booleans::FheBool;
+use crate::c_api::high_level_api::integers::{FheUint16, FheUint32};
+use crate::c_api::high_level_api::keys::ClientKey;
+use crate::c_api::utils::{catch_panic, get_ref_checked, get_str_checked};
+use crate::high_level_api::prelude::*;
+use crate::strings::ciphertext::ClearString;
+use std::os::raw::{c_char, c_int};
+
+pub struct FheAsciiString(pub(in crate::c_api) crate::high_level_api::FheAsciiString);
+
+impl_destroy_on_type!(FheAsciiString);
+impl_clone_on_type!(FheAsciiString);
+impl_serialize_deserialize_on_type!(FheAsciiString);
+impl_safe_serialize_on_type!(FheAsciiString);
+impl_safe_deserialize_on_type!(FheAsciiString);
+
+impl_comparison_fn_on_type!(
+    lhs_type: FheAsciiString,
+    rhs_type: FheAsciiString,
+    comparison_fn_names: eq, ne, lt, le, gt, ge, contains, starts_with, ends_with,
+);
+impl_binary_fn_on_type!(FheAsciiString => concat);
+impl_unary_fn_on_type!(FheAsciiString =>
+    trim,
+    trim_start,
+    trim_end,
+    to_lowercase,
+    to_uppercase,
+);
+
+/// Encrypts a null-terminated ASCII string and adds `padding` encrypted nulls at its end
+///
+/// The padding hides the length of the string: strings encrypted with the same number of
+/// characters plus padding cannot be told apart.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_try_encrypt_with_padding(
+    value: *const c_char,
+    padding: u32,
+    client_key: *const ClientKey,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        let value = get_str_checked(value).unwrap();
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let inner = crate::high_level_api::FheAsciiString::try_encrypt_with_padding(
+            value,
+            padding,
+            &client_key.0,
+        )
+        .unwrap();
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
+
+/// Encrypts a null-terminated ASCII string into exactly `size` characters
+///
+/// Shorter strings are padded with encrypted nulls, longer ones are truncated.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_try_encrypt_with_fixed_sized(
+    value: *const c_char,
+    size: usize,
+    client_key: *const ClientKey,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        let value = get_str_checked(value).unwrap();
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let inner = crate::high_level_api::FheAsciiString::try_encrypt_with_fixed_sized(
+            value,
+            size,
+            &client_key.0,
+        )
+        .unwrap();
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
+
+/// Decrypts the string
+///
+/// The `result` buffer holds the characters followed by a null terminator (which is counted in
+/// its length), so its pointer can be used as a C string. It must be destroyed with
+/// `destroy_dynamic_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_decrypt(
+    encrypted_value: *const FheAsciiString,
+    client_key: *const ClientKey,
+    result: *mut crate::c_api::buffer::DynamicBuffer,
+) -> c_int {
+    catch_panic(|| {
+        crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let encrypted_value = get_ref_checked(encrypted_value).unwrap();
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let clear: String = encrypted_value.0.decrypt(&client_key.0);
+        let mut bytes = clear.into_bytes();
+        bytes.push(0);
+
+        *result = bytes.into();
+    })
+}
+
+/// Returns the encrypted number of characters, not counting the padding
+///
+/// If the string has no padding, its length is known and the result is a trivial encryption.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_len(
+    sself: *const FheAsciiString,
+    result: *mut *mut FheUint16,
+) -> c_int {
+    catch_panic(|| {
+        let sself = get_ref_checked(sself).unwrap();
+
+        let inner = sself.0.len().into_ciphertext();
+
+        *result = Box::into_raw(Box::new(FheUint16(inner)));
+    })
+}
+
+/// Returns whether the string is empty, not counting the padding
+///
+/// If the string has no padding, the result is a trivial encryption.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_is_empty(
+    sself: *const FheAsciiString,
+    result: *mut *mut FheBool,
+) -> c_int {
+    catch_panic(|| {
+        let sself = get_ref_checked(sself).unwrap();
+
+        let inner = sself.0.is_empty().into_ciphertext();
+
+        *result = Box::into_raw(Box::new(FheBool(inner)));
+    })
+}
+
+// Same as the encrypted versions, but the right-hand side is a clear null-terminated string
+macro_rules! impl_scalar_comparison_fn_on_string {
+    ($($fn_name:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<fhe_ascii_string_scalar_ $fn_name>](
+                    lhs: *const FheAsciiString,
+                    rhs: *const c_char,
+                    result: *mut *mut FheBool,
+                ) -> c_int {
+                    catch_panic(|| {
+                        let lhs = get_ref_checked(lhs).unwrap();
+                        let rhs = ClearString::new(get_str_checked(rhs).unwrap().to_owned());
+
+                        let inner = lhs.0.$fn_name(&rhs);
+
+                        *result = Box::into_raw(Box::new(FheBool(inner)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_comparison_fn_on_string!(eq, ne, lt, le, gt, ge, contains, starts_with, ends_with);
+
+macro_rules! impl_find_fn_on_string {
+    ($($fn_name:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                /// Returns the encrypted index of the pattern and whether it was found
+                ///
+                /// If the pattern was not found, the index encrypts 0.
+                #[no_mangle]
+                pub unsafe extern "C" fn [<fhe_ascii_string_ $fn_name>](
+                    sself: *const FheAsciiString,
+                    pattern: *const FheAsciiString,
+                    out_index: *mut *mut FheUint32,
+                    out_found: *mut *mut FheBool,
+                ) -> c_int {
+                    catch_panic(|| {
+                        let sself = get_ref_checked(sself).unwrap();
+                        let pattern = get_ref_checked(pattern).unwrap();
+
+                        let (index, found) = sself.0.$fn_name(&pattern.0);
+
+                        *out_index = Box::into_raw(Box::new(FheUint32(index)));
+                        *out_found = Box::into_raw(Box::new(FheBool(found)));
+                    })
+                }
+
+                /// Returns the encrypted index of the clear pattern and whether it was found
+                ///
+                /// If the pattern was not found, the index encrypts 0.
+                #[no_mangle]
+                pub unsafe extern "C" fn [<fhe_ascii_string_scalar_ $fn_name>](
+                    sself: *const FheAsciiString,
+                    pattern: *const c_char,
+                    out_index: *mut *mut FheUint32,
+                    out_found: *mut *mut FheBool,
+                ) -> c_int {
+                    catch_panic(|| {
+                        let sself = get_ref_checked(sself).unwrap();
+                        let pattern = ClearString::new(get_str_checked(pattern).unwrap().to_owned());
+
+                        let (index, found) = sself.0.$fn_name(&pattern);
+
+                        *out_index = Box::into_raw(Box::new(FheUint32(index)));
+                        *out_found = Box::into_raw(Box::new(FheBool(found)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_find_fn_on_string!(find, rfind);
+
+/// Replaces all the occurrences of the `from` pattern by `to`
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_replace(
+    sself: *const FheAsciiString,
+    from: *const FheAsciiString,
+    to: *const FheAsciiString,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        let sself = get_ref_checked(sself).unwrap();
+        let from = get_ref_checked(from).unwrap();
+        let to = get_ref_checked(to).unwrap();
+
+        let inner = sself.0.replace(&from.0, &to.0);
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
+
+/// Replaces all the occurrences of the clear `from` pattern by `to`
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_scalar_replace(
+    sself: *const FheAsciiString,
+    from: *const c_char,
+    to: *const FheAsciiString,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        let sself = get_ref_checked(sself).unwrap();
+        let from = ClearString::new(get_str_checked(from).unwrap().to_owned());
+        let to = get_ref_checked(to).unwrap();
+
+        let inner = sself.0.replace(&from, &to.0);
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
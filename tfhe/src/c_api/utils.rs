@@ -1,4 +1,4 @@
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int};
 
 pub fn catch_panic<F>(closure: F) -> c_int
 where
@@ -74,3 +74,18 @@ pub(super) unsafe fn get_ref_checked<'a, T>(ptr: *const T) -> Result<&'a T, Stri
         Err(e) => Err(e),
     }
 }
+
+/// Get a string slice from a pointer to a null-terminated C string, checking the pointer is not
+/// null and the string is valid UTF-8.
+///
+/// # Safety
+///
+/// Caller of this function needs to make sure the pointer points to a null-terminated string that
+/// is not modified nor freed while the returned reference is alive.
+pub(super) unsafe fn get_str_checked<'a>(ptr: *const c_char) -> Result<&'a str, String> {
+    check_ptr_is_non_null_and_aligned(ptr)?;
+
+    std::ffi::CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|err| format!("string is not valid UTF-8: {err}"))
+}
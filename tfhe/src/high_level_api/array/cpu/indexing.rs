@@ -0,0 +1,68 @@
+//! Accesses at an encrypted index for the CPU integer array backend
+use super::integers::CpuIntegerArrayBackend;
+use crate::array::traits::{EncryptedIndexArrayBackend, FromFheScalars, TensorSlice};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
+use rayon::prelude::*;
+
+impl<T> EncryptedIndexArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn get_at_index(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        index: &RadixCiphertext,
+    ) -> Self::Owned {
+        // Elements in row-major order
+        let values = slice.iter().cloned().collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            vec![cpu_key.pbs_key().get_at_index_parallelized(&values, index)]
+        })
+    }
+
+    fn set_at_index(
+        slice: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &RadixCiphertext,
+        value: Self::Slice<'_>,
+    ) {
+        assert_eq!(value.len(), 1, "Expected exactly one value to set");
+        let mut values = TensorSlice::new(&*slice.slice, slice.dims)
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            cpu_key
+                .pbs_key()
+                .set_at_index_parallelized(&mut values, index, &value[0]);
+        });
+        slice
+            .par_iter_mut()
+            .zip(values.into_par_iter())
+            .for_each(|(dst, src)| *dst = src);
+    }
+}
+
+impl<Id> FromFheScalars<Id> for Vec<RadixCiphertext>
+where
+    Id: FheUintId,
+{
+    fn from_fhe_scalars(scalars: &[Self::Scalar]) -> Self {
+        scalars
+            .iter()
+            .map(|scalar| scalar.ciphertext.on_cpu().into_owned())
+            .collect()
+    }
+}
+
+impl<Id> FromFheScalars<Id> for Vec<SignedRadixCiphertext>
+where
+    Id: FheIntId,
+{
+    fn from_fhe_scalars(scalars: &[Self::Scalar]) -> Self {
+        scalars
+            .iter()
+            .map(|scalar| scalar.ciphertext.on_cpu().into_owned())
+            .collect()
+    }
+}
@@ -72,6 +72,43 @@ where
     })
 }
 
+/// Returns the number of blocks needed to encrypt the indices of an array of `len` elements
+///
+/// # Panics
+///
+/// Panics if the indices cannot be represented by elements of `num_blocks` blocks
+pub(super) fn num_index_blocks<T>(
+    sks: &crate::integer::ServerKey,
+    len: usize,
+    num_blocks: usize,
+) -> usize
+where
+    T: IntegerRadixCiphertext,
+{
+    let bits_per_block = sks.message_modulus().0.ilog2();
+    let num_index_bits = (usize::BITS - len.saturating_sub(1).leading_zeros()).max(1);
+    let num_value_bits = num_blocks as u32 * bits_per_block - u32::from(T::IS_SIGNED);
+    assert!(
+        num_index_bits <= num_value_bits,
+        "The indices of an array of {len} elements cannot be represented by its elements"
+    );
+    num_index_bits.div_ceil(bits_per_block) as usize
+}
+
+/// Converts an index computed on fewer blocks to the type of the elements
+pub(super) fn index_into_element<T>(
+    sks: &crate::integer::ServerKey,
+    mut index: RadixCiphertext,
+    num_blocks: usize,
+) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    let num_missing_blocks = num_blocks - index.blocks.len();
+    sks.extend_radix_with_trivial_zero_blocks_msb_assign(&mut index, num_missing_blocks);
+    T::from_blocks(index.blocks)
+}
+
 impl<T> ArithmeticArrayBackend for CpuIntegerArrayBackend<T>
 where
     T: IntegerRadixCiphertext,
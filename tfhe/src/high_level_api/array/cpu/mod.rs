@@ -1,5 +1,8 @@
 pub(crate) mod booleans;
+mod indexing;
 pub(crate) mod integers;
+mod reductions;
+mod sorting;
 
 use crate::array::helpers::{create_sub_mut_slice_with_bound, create_sub_slice_with_bound};
 use crate::array::traits::{
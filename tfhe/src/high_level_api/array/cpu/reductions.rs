@@ -0,0 +1,181 @@
+//! Reductions for the CPU integer array backend
+use super::integers::{index_into_element, num_index_blocks, CpuIntegerArrayBackend};
+use crate::array::stride::DynDimensions;
+use crate::array::traits::{ClearReductionArrayBackend, ReductionArrayBackend, TensorSlice};
+use crate::high_level_api::global_state;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::server_key::ScalarMultiplier;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+fn sum<'a, T>(sks: &ServerKey, values: impl IntoIterator<Item = &'a T>) -> T
+where
+    T: IntegerRadixCiphertext + 'a,
+{
+    sks.sum_ciphertexts_parallelized(values)
+        .expect("Cannot sum an empty array")
+}
+
+/// Multiplies each element of `lhs` by the clear element of `rhs` at the same position
+fn mul_slices<T, Clear>(
+    sks: &ServerKey,
+    lhs: TensorSlice<'_, &[T]>,
+    rhs: TensorSlice<'_, &[Clear]>,
+) -> Vec<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: ScalarMultiplier + DecomposableInto<u8> + Copy + Send + Sync,
+{
+    lhs.par_iter()
+        .zip(rhs.par_iter())
+        .map(|(lhs, rhs)| sks.scalar_mul_parallelized(lhs, *rhs))
+        .collect()
+}
+
+/// Reduces the slice to its element that is selected by `is_better`,
+/// along with the index of its first occurrence.
+fn select_with_index<T, F>(slice: TensorSlice<'_, &[T]>, is_better: F) -> Vec<T>
+where
+    T: IntegerRadixCiphertext,
+    F: Fn(&ServerKey, &T, &T) -> BooleanBlock + Sync + Send,
+{
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        let sks = cpu_key.pbs_key();
+        let len = slice.dims.flattened_len();
+        let num_blocks = slice
+            .slice
+            .first()
+            .expect("Cannot reduce an empty array")
+            .blocks()
+            .len();
+        let num_index_blocks = num_index_blocks::<T>(sks, len, num_blocks);
+
+        let (value, index) = slice
+            .par_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let index: RadixCiphertext = sks.create_trivial_radix(i as u64, num_index_blocks);
+                (value.clone(), index)
+            })
+            .reduce_with(|(lhs, lhs_index), (rhs, rhs_index)| {
+                // The rhs comes after the lhs, so it is only kept when strictly better
+                let rhs_is_better = is_better(sks, &rhs, &lhs);
+                rayon::join(
+                    || sks.if_then_else_parallelized(&rhs_is_better, &rhs, &lhs),
+                    || sks.if_then_else_parallelized(&rhs_is_better, &rhs_index, &lhs_index),
+                )
+            })
+            .unwrap();
+
+        vec![value, index_into_element(sks, index, num_blocks)]
+    })
+}
+
+impl<T> ReductionArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn sum(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        global_state::with_cpu_internal_keys(|cpu_key| vec![sum(cpu_key.pbs_key(), slice.iter())])
+    }
+
+    fn sum_axis(slice: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        let shape = slice.dims.shape().to_vec();
+        assert!(
+            axis < shape.len(),
+            "axis {axis} is out of bounds for an array of {} dimensions",
+            shape.len()
+        );
+        let axis_len = shape[axis];
+        let outer_len = shape[..axis].iter().product::<usize>();
+        let inner_len = shape[axis + 1..].iter().product::<usize>();
+
+        // Elements in row-major order
+        let values = slice.iter().collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            (0..outer_len * inner_len)
+                .into_par_iter()
+                .map(|output_index| {
+                    let (outer, inner) = (output_index / inner_len, output_index % inner_len);
+                    let start = outer * axis_len * inner_len + inner;
+                    sum(sks, (0..axis_len).map(|i| values[start + i * inner_len]))
+                })
+                .collect()
+        })
+    }
+
+    fn product(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let product = slice
+                .par_iter()
+                .cloned()
+                .reduce_with(|lhs, rhs| sks.mul_parallelized(&lhs, &rhs))
+                .expect("Cannot compute the product of an empty array");
+            vec![product]
+        })
+    }
+
+    fn min(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        select_with_index(slice, ServerKey::lt_parallelized)
+    }
+
+    fn max(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        select_with_index(slice, ServerKey::gt_parallelized)
+    }
+}
+
+impl<T, Clear> ClearReductionArrayBackend<Clear> for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: ScalarMultiplier + DecomposableInto<u8> + Copy + Send + Sync,
+{
+    fn dot(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let products = mul_slices(sks, lhs, rhs);
+            vec![sum(sks, &products)]
+        })
+    }
+
+    fn matmul(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        let (num_rows, inner_len) = (lhs.dims.shape()[0], lhs.dims.shape()[1]);
+        let num_cols = rhs.dims.shape()[1];
+        let rhs_values = rhs.iter().copied().collect::<Vec<_>>();
+        let broadcast_dims = DynDimensions::from(vec![num_rows, inner_len]);
+
+        // Each column of the result is computed by multiplying the lhs with
+        // a matrix where each row is the corresponding column of the rhs
+        let columns = (0..num_cols)
+            .map(|col| {
+                let broadcast_column = (0..num_rows * inner_len)
+                    .map(|i| rhs_values[(i % inner_len) * num_cols + col])
+                    .collect::<Vec<_>>();
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    let products = mul_slices(
+                        sks,
+                        TensorSlice::new(lhs.slice, lhs.dims),
+                        TensorSlice::new(broadcast_column.as_slice(), &broadcast_dims),
+                    );
+                    products
+                        .par_chunks(inner_len)
+                        .map(|row| sum(sks, row))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        (0..num_rows * num_cols)
+            .map(|i| columns[i % num_cols][i / num_cols].clone())
+            .collect()
+    }
+}
@@ -0,0 +1,215 @@
+//! Bitonic sorting networks for the CPU integer array backend
+//!
+//! The comparisons of a stage of the network are independent from each other
+//! so they are all executed in parallel.
+use super::integers::{index_into_element, num_index_blocks, CpuIntegerArrayBackend};
+use crate::array::traits::{IntoFheScalars, SortingArrayBackend, TensorSlice};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::server_key::radix_parallel::sorting_network::bitonic_stages;
+use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::{FheInt, FheUint, Seed};
+use rayon::prelude::*;
+
+fn bitonic_sort<T>(sks: &ServerKey, values: &[T]) -> Vec<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    let len = values.len();
+    if len <= 1 {
+        return values.to_vec();
+    }
+
+    // The padding values are the greatest possible, so they end up at the end
+    let num_blocks = values[0].blocks().len();
+    let mut values = values.to_vec();
+    values.resize(
+        len.next_power_of_two(),
+        sks.create_trivial_max_radix(num_blocks),
+    );
+
+    for stage in bitonic_stages(values.len()) {
+        let exchanged = stage
+            .par_iter()
+            .map(|&(lo, hi)| {
+                rayon::join(
+                    || sks.min_parallelized(&values[lo], &values[hi]),
+                    || sks.max_parallelized(&values[lo], &values[hi]),
+                )
+            })
+            .collect::<Vec<_>>();
+        for (&(lo, hi), (min, max)) in stage.iter().zip(exchanged) {
+            values[lo] = min;
+            values[hi] = max;
+        }
+    }
+
+    values.truncate(len);
+    values
+}
+
+/// Sorts the values along with their index, using the index to break ties
+/// which makes the sort stable.
+///
+/// Returns the indices of the sorted values
+fn bitonic_argsort<T>(sks: &ServerKey, values: &[T]) -> Vec<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    let len = values.len();
+    let Some(first) = values.first() else {
+        return Vec::new();
+    };
+    let num_blocks = first.blocks().len();
+    let padded_len = len.next_power_of_two();
+    // `padded_len - 1` needs as many bits as `len - 1`
+    let num_index_blocks = num_index_blocks::<T>(sks, len, num_blocks);
+
+    let mut values = values.to_vec();
+    values.resize(padded_len, sks.create_trivial_max_radix(num_blocks));
+    let mut indices = (0..padded_len as u64)
+        .map(|i| sks.create_trivial_radix::<_, RadixCiphertext>(i, num_index_blocks))
+        .collect::<Vec<_>>();
+
+    for stage in bitonic_stages(padded_len) {
+        let exchanged = stage
+            .par_iter()
+            .map(|&(lo, hi)| {
+                // Lexicographic comparison of (value, index)
+                let (value_gt, (value_eq, index_gt)) = rayon::join(
+                    || sks.gt_parallelized(&values[lo], &values[hi]),
+                    || {
+                        rayon::join(
+                            || sks.eq_parallelized(&values[lo], &values[hi]),
+                            || sks.gt_parallelized(&indices[lo], &indices[hi]),
+                        )
+                    },
+                );
+                let tie_is_gt = sks.boolean_bitand(&value_eq, &index_gt);
+                let must_swap = sks.boolean_bitor(&value_gt, &tie_is_gt);
+
+                rayon::join(
+                    || {
+                        rayon::join(
+                            || sks.if_then_else_parallelized(&must_swap, &values[hi], &values[lo]),
+                            || sks.if_then_else_parallelized(&must_swap, &values[lo], &values[hi]),
+                        )
+                    },
+                    || {
+                        rayon::join(
+                            || {
+                                sks.if_then_else_parallelized(
+                                    &must_swap,
+                                    &indices[hi],
+                                    &indices[lo],
+                                )
+                            },
+                            || {
+                                sks.if_then_else_parallelized(
+                                    &must_swap,
+                                    &indices[lo],
+                                    &indices[hi],
+                                )
+                            },
+                        )
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        for (&(lo, hi), ((new_lo, new_hi), (new_lo_index, new_hi_index))) in
+            stage.iter().zip(exchanged)
+        {
+            values[lo] = new_lo;
+            values[hi] = new_hi;
+            indices[lo] = new_lo_index;
+            indices[hi] = new_hi_index;
+        }
+    }
+
+    // Padding elements come after real elements with the same value,
+    // so the first `len` indices are the real ones
+    indices
+        .into_iter()
+        .take(len)
+        .map(|index| index_into_element(sks, index, num_blocks))
+        .collect()
+}
+
+impl<T> SortingArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn sort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        let values = slice.iter().cloned().collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| bitonic_sort(cpu_key.pbs_key(), &values))
+    }
+
+    fn argsort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        let values = slice.iter().cloned().collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| bitonic_argsort(cpu_key.pbs_key(), &values))
+    }
+
+    fn top_k(slice: TensorSlice<'_, Self::Slice<'_>>, k: usize) -> Self::Owned {
+        let mut sorted = Self::sort(slice);
+        let len = sorted.len();
+        assert!(
+            k <= len,
+            "Cannot take the top {k} elements of an array of {len} elements"
+        );
+        let mut top = sorted.split_off(len - k);
+        top.reverse();
+        top
+    }
+
+    fn median(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        let mut sorted = Self::sort(slice);
+        assert!(
+            !sorted.is_empty(),
+            "Cannot compute the median of an empty array"
+        );
+        let median_index = (sorted.len() - 1) / 2;
+        vec![sorted.swap_remove(median_index)]
+    }
+
+    fn shuffle(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        seed: Seed,
+        statistical_security_bits: u64,
+    ) -> Self::Owned {
+        let values = slice.iter().cloned().collect::<Vec<_>>();
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            cpu_key
+                .pbs_key()
+                .par_oblivious_shuffle(seed, &values, statistical_security_bits)
+        })
+    }
+}
+
+impl<Id> IntoFheScalars<Id> for Vec<RadixCiphertext>
+where
+    Id: FheUintId,
+{
+    type Scalar = FheUint<Id>;
+
+    fn into_fhe_scalars(self) -> Vec<Self::Scalar> {
+        let tag = global_state::with_cpu_internal_keys(|cpu_key| cpu_key.tag.clone());
+        self.into_iter()
+            .map(|ct| FheUint::new(ct, tag.clone()))
+            .collect()
+    }
+}
+
+impl<Id> IntoFheScalars<Id> for Vec<SignedRadixCiphertext>
+where
+    Id: FheIntId,
+{
+    type Scalar = FheInt<Id>;
+
+    fn into_fhe_scalars(self) -> Vec<Self::Scalar> {
+        let tag = global_state::with_cpu_internal_keys(|cpu_key| cpu_key.tag.clone());
+        self.into_iter()
+            .map(|ct| FheInt::new(ct, tag.clone()))
+            .collect()
+    }
+}
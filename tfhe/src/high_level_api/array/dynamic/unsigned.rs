@@ -3,7 +3,8 @@ use crate::array::helpers::{
 };
 use crate::array::traits::{
     ArithmeticArrayBackend, ArrayBackend, BackendDataContainer, BackendDataContainerMut,
-    BitwiseArrayBackend, TensorSlice,
+    BitwiseArrayBackend, ClearReductionArrayBackend, EncryptedIndexArrayBackend, FromFheScalars,
+    IntoFheScalars, ReductionArrayBackend, SortingArrayBackend, TensorSlice,
 };
 use crate::core_crypto::prelude::UnsignedNumeric;
 use crate::high_level_api::array::cpu::CpuIntegerArrayBackend;
@@ -16,7 +17,7 @@ use crate::high_level_api::integers::FheUintId;
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::RadixCiphertext;
 use crate::prelude::{FheDecrypt, FheTryEncrypt};
-use crate::{ClientKey, Device, Error};
+use crate::{ClientKey, Device, Error, FheUint, Seed};
 use std::borrow::{Borrow, Cow};
 use std::ops::RangeBounds;
 
@@ -299,6 +300,127 @@ impl BitwiseArrayBackend for DynUintBackend {
     }
 }
 
+impl SortingArrayBackend for DynUintBackend {
+    fn sort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::sort)
+    }
+
+    fn argsort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::argsort)
+    }
+
+    fn top_k(slice: TensorSlice<'_, Self::Slice<'_>>, k: usize) -> Self::Owned {
+        dispatch_unary_op(&slice, |slice| CpuIntegerArrayBackend::top_k(slice, k))
+    }
+
+    fn median(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::median)
+    }
+
+    fn shuffle(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        seed: Seed,
+        statistical_security_bits: u64,
+    ) -> Self::Owned {
+        dispatch_unary_op(&slice, |slice| {
+            CpuIntegerArrayBackend::shuffle(slice, seed, statistical_security_bits)
+        })
+    }
+}
+
+impl ReductionArrayBackend for DynUintBackend {
+    fn sum(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::sum)
+    }
+
+    fn sum_axis(slice: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&slice, |slice| {
+            CpuIntegerArrayBackend::sum_axis(slice, axis)
+        })
+    }
+
+    fn product(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::product)
+    }
+
+    fn min(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::min)
+    }
+
+    fn max(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&slice, CpuIntegerArrayBackend::max)
+    }
+}
+
+impl<Clear> ClearReductionArrayBackend<Clear> for DynUintBackend
+where
+    Clear: Copy,
+    CpuIntegerArrayBackend<RadixCiphertext>: ClearReductionArrayBackend<Clear>,
+{
+    fn dot(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(&lhs, &rhs, CpuIntegerArrayBackend::dot)
+    }
+
+    fn matmul(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(&lhs, &rhs, CpuIntegerArrayBackend::matmul)
+    }
+}
+
+impl EncryptedIndexArrayBackend for DynUintBackend {
+    fn get_at_index(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        index: &RadixCiphertext,
+    ) -> Self::Owned {
+        dispatch_unary_op(&slice, |slice| {
+            CpuIntegerArrayBackend::get_at_index(slice, index)
+        })
+    }
+
+    fn set_at_index(
+        slice: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &RadixCiphertext,
+        value: Self::Slice<'_>,
+    ) {
+        let value_cpu_cow = value.on_cpu();
+        let value_cpu_slice: &[RadixCiphertext] = value_cpu_cow.borrow();
+        match slice.slice {
+            InnerUintSliceMut::Cpu(cpu_slice) => CpuIntegerArrayBackend::set_at_index(
+                TensorSlice::new(cpu_slice, slice.dims),
+                index,
+                value_cpu_slice,
+            ),
+        }
+    }
+}
+
+impl<Id> IntoFheScalars<Id> for InnerUintArray
+where
+    Id: FheUintId,
+{
+    type Scalar = FheUint<Id>;
+
+    fn into_fhe_scalars(self) -> Vec<Self::Scalar> {
+        match self {
+            Self::Cpu(cpu_array) => cpu_array.into_fhe_scalars(),
+        }
+    }
+}
+
+impl<Id> FromFheScalars<Id> for InnerUintArray
+where
+    Id: FheUintId,
+{
+    fn from_fhe_scalars(scalars: &[Self::Scalar]) -> Self {
+        Self::Cpu(<Vec<RadixCiphertext> as FromFheScalars<Id>>::from_fhe_scalars(scalars))
+    }
+}
+
 impl<Clear> ClearBitwiseArrayBackend<Clear> for DynUintBackend
 where
     Clear: DecomposableInto<u8>,
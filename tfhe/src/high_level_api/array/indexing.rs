@@ -0,0 +1,60 @@
+use super::reductions::Scalar;
+use super::traits::{EncryptedIndexArrayBackend, FromFheScalars, IntoFheScalars};
+use crate::high_level_api::array::{
+    ArrayBackend, BackendDataContainer, BackendDataContainerMut, FheArrayBase,
+};
+use crate::high_level_api::integers::FheUintId;
+use crate::FheUint;
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: EncryptedIndexArrayBackend,
+    <C::Backend as ArrayBackend>::Owned: IntoFheScalars<Id>,
+{
+    /// Returns the element at the encrypted `index`
+    ///
+    /// The index is the position in the flattened array. It is not revealed:
+    /// every element of the array takes part in the computation.
+    ///
+    /// If the index is out of bounds, the result is an encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty
+    pub fn get_encrypted<IndexId>(&self, index: &FheUint<IndexId>) -> Scalar<C, Id>
+    where
+        IndexId: FheUintId,
+    {
+        let index = index.ciphertext.on_cpu();
+        C::Backend::get_at_index(self.as_tensor_slice(), &index)
+            .into_fhe_scalars()
+            .pop()
+            .unwrap()
+    }
+}
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainerMut,
+    C::Backend: EncryptedIndexArrayBackend,
+    <C::Backend as ArrayBackend>::Owned: FromFheScalars<Id>,
+{
+    /// Replaces the element at the encrypted `index` by `value`
+    ///
+    /// The index is the position in the flattened array. It is not revealed:
+    /// every element of the array is rewritten.
+    ///
+    /// If the index is out of bounds, the array is left unchanged.
+    pub fn set_encrypted<IndexId>(&mut self, index: &FheUint<IndexId>, value: &Scalar<C, Id>)
+    where
+        IndexId: FheUintId,
+    {
+        let index = index.ciphertext.on_cpu();
+        let value =
+            <C::Backend as ArrayBackend>::Owned::from_fhe_scalars(std::slice::from_ref(value));
+        C::Backend::set_at_index(self.as_tensor_slice_mut(), &index, value.as_slice());
+    }
+}
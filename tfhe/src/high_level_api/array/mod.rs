@@ -4,7 +4,10 @@ mod dynamic;
 #[cfg(feature = "gpu")]
 mod gpu;
 mod helpers;
+mod indexing;
 mod ops;
+mod reductions;
+mod sorting;
 pub mod stride;
 #[cfg(test)]
 mod tests;
@@ -109,6 +112,15 @@ where
         TensorSlice::new(self.elems.as_slice(), &self.dims)
     }
 
+    pub(in crate::high_level_api) fn as_tensor_slice_mut(
+        &mut self,
+    ) -> TensorSlice<'_, <C::Backend as ArrayBackend>::SliceMut<'_>>
+    where
+        C: BackendDataContainerMut,
+    {
+        TensorSlice::new(self.elems.as_slice_mut(), &self.dims)
+    }
+
     pub fn get_slice<R>(
         &self,
         ranges: &[R],
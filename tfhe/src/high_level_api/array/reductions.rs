@@ -0,0 +1,160 @@
+use super::traits::{ClearReductionArrayBackend, IntoFheScalars, ReductionArrayBackend};
+use crate::array::cpu::ClearContainer;
+use crate::array::ClearArray;
+use crate::high_level_api::array::{ArrayBackend, BackendDataContainer, FheArrayBase};
+
+pub(super) type Scalar<C, Id> =
+    <<<C as BackendDataContainer>::Backend as ArrayBackend>::Owned as IntoFheScalars<Id>>::Scalar;
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: ReductionArrayBackend,
+    <C::Backend as ArrayBackend>::Owned: IntoFheScalars<Id>,
+{
+    /// Returns the sum of all the elements, wrapping around on overflow
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty
+    pub fn sum(&self) -> Scalar<C, Id> {
+        C::Backend::sum(self.as_tensor_slice())
+            .into_fhe_scalars()
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the product of all the elements, wrapping around on overflow
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty
+    pub fn product(&self) -> Scalar<C, Id> {
+        C::Backend::product(self.as_tensor_slice())
+            .into_fhe_scalars()
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the minimum of the elements, along with the index of its first occurrence
+    ///
+    /// The index is the position in the flattened array, encrypted using the same type
+    /// as the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty, or if the indices cannot be represented
+    /// by the type of the elements
+    pub fn min(&self) -> (Scalar<C, Id>, Scalar<C, Id>) {
+        let mut result = C::Backend::min(self.as_tensor_slice()).into_fhe_scalars();
+        let index = result.pop().unwrap();
+        (result.pop().unwrap(), index)
+    }
+
+    /// Returns the maximum of the elements, along with the index of its first occurrence
+    ///
+    /// The index is the position in the flattened array, encrypted using the same type
+    /// as the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty, or if the indices cannot be represented
+    /// by the type of the elements
+    pub fn max(&self) -> (Scalar<C, Id>, Scalar<C, Id>) {
+        let mut result = C::Backend::max(self.as_tensor_slice()).into_fhe_scalars();
+        let index = result.pop().unwrap();
+        (result.pop().unwrap(), index)
+    }
+}
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: ReductionArrayBackend,
+{
+    /// Returns the sums of the elements along the given axis
+    ///
+    /// The axis is removed from the shape of the result,
+    /// summing a 1-dimensional array gives an array of one element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds, or if it has a length of 0
+    pub fn sum_axis(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        let result = C::Backend::sum_axis(self.as_tensor_slice(), axis);
+        let mut shape = self.shape().to_vec();
+        shape.remove(axis);
+        if shape.is_empty() {
+            shape.push(1);
+        }
+        FheArrayBase::new(result, shape)
+    }
+}
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+{
+    /// Returns the sum of the products of the elements with the clear ones
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays do not have the same shape, or are empty
+    pub fn dot<Clear>(&self, rhs: &ClearArray<Clear>) -> Scalar<C, Id>
+    where
+        Clear: Copy,
+        C::Backend: ClearReductionArrayBackend<Clear>,
+        <C::Backend as ArrayBackend>::Owned: IntoFheScalars<Id>,
+    {
+        assert!(
+            self.has_same_shape(rhs),
+            "Array operands do not have the same shape"
+        );
+        let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
+        C::Backend::dot(self.as_tensor_slice(), rhs_slice)
+            .into_fhe_scalars()
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the matrix product of a 2-dimensional array with a clear one
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays are not 2-dimensional,
+    /// if their shapes are not compatible, or if their inner dimension is 0
+    pub fn matmul<Clear>(
+        &self,
+        rhs: &ClearArray<Clear>,
+    ) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>
+    where
+        Clear: Copy,
+        C::Backend: ClearReductionArrayBackend<Clear>,
+    {
+        let (lhs_shape, rhs_shape) = (self.shape(), rhs.shape());
+        assert!(
+            lhs_shape.len() == 2 && rhs_shape.len() == 2,
+            "matmul is only supported for 2-dimensional arrays"
+        );
+        assert_eq!(
+            lhs_shape[1], rhs_shape[0],
+            "Cannot multiply a {lhs_shape:?} matrix with a {rhs_shape:?} matrix, \
+            the inner dimensions do not match"
+        );
+        // The elements of the result would be empty sums, and there is no element to take
+        // the number of blocks of their encryptions of 0 from
+        assert_ne!(
+            lhs_shape[1], 0,
+            "Cannot multiply a {lhs_shape:?} matrix with a {rhs_shape:?} matrix, \
+            the inner dimension is 0"
+        );
+        let resulting_shape = vec![lhs_shape[0], rhs_shape[1]];
+
+        let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
+        let result = C::Backend::matmul(self.as_tensor_slice(), rhs_slice);
+        FheArrayBase::new(result, resulting_shape)
+    }
+}
@@ -0,0 +1,77 @@
+use super::traits::{IntoFheScalars, SortingArrayBackend};
+use crate::high_level_api::array::{ArrayBackend, BackendDataContainer, FheArrayBase};
+use crate::Seed;
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: SortingArrayBackend,
+{
+    /// Returns the elements of the array sorted in ascending order
+    ///
+    /// The array is flattened, so the result is always 1-dimensional.
+    pub fn sort(&self) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        let result = C::Backend::sort(self.as_tensor_slice());
+        let len = result.len();
+        FheArrayBase::new(result, vec![len])
+    }
+
+    /// Returns the encrypted indices that sort the flattened array in ascending order
+    ///
+    /// The sort is stable: equal elements keep their relative order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices cannot be represented by the type of the elements
+    pub fn argsort(&self) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        let result = C::Backend::argsort(self.as_tensor_slice());
+        let len = result.len();
+        FheArrayBase::new(result, vec![len])
+    }
+
+    /// Returns the `k` greatest elements of the flattened array, in descending order
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of elements
+    pub fn top_k(&self, k: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        let result = C::Backend::top_k(self.as_tensor_slice(), k);
+        FheArrayBase::new(result, vec![k])
+    }
+
+    /// Returns the elements of the array in a random order, derived from the given seed
+    ///
+    /// The array is flattened, so the result is always 1-dimensional. The permutation is
+    /// generated and applied obliviously, it is not known by the server. It is uniform, except
+    /// with probability at most `2^-statistical_security_bits`.
+    ///
+    /// The output is deterministic: the same seed and server key give the same permutation.
+    pub fn shuffle(
+        &self,
+        seed: Seed,
+        statistical_security_bits: u64,
+    ) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        let result = C::Backend::shuffle(self.as_tensor_slice(), seed, statistical_security_bits);
+        let len = result.len();
+        FheArrayBase::new(result, vec![len])
+    }
+
+    /// Returns the median of the elements of the array
+    ///
+    /// When the number of elements is even, the lower of the two middle
+    /// elements is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty
+    pub fn median(&self) -> <<C::Backend as ArrayBackend>::Owned as IntoFheScalars<Id>>::Scalar
+    where
+        <C::Backend as ArrayBackend>::Owned: IntoFheScalars<Id>,
+    {
+        C::Backend::median(self.as_tensor_slice())
+            .into_fhe_scalars()
+            .pop()
+            .unwrap()
+    }
+}
@@ -2,18 +2,21 @@ mod booleans;
 mod signed;
 mod unsigned;
 
-use crate::{generate_keys, set_server_key, ClientKey, ConfigBuilder, FheId};
+use crate::{generate_keys, set_server_key, ClientKey, ConfigBuilder, FheId, FheUint16};
 #[cfg(feature = "gpu")]
 use crate::{Config, CudaServerKey};
 use rand::distributions::{Distribution, Standard};
 use rand::random;
 use std::fmt::Debug;
 
-use crate::array::traits::IOwnedArray;
+use crate::array::traits::{
+    ClearReductionArrayBackend, EncryptedIndexArrayBackend, FromFheScalars, IOwnedArray,
+    IntoFheScalars, ReductionArrayBackend, SortingArrayBackend,
+};
 use crate::array::ClearArray;
 use crate::high_level_api::array::{FheBackendArray, FheBackendArraySlice};
-use crate::prelude::{FheDecrypt, FheTryEncrypt};
-use std::ops::{BitAnd, BitOr, BitXor};
+use crate::prelude::{FheDecrypt, FheEncrypt, FheTryEncrypt};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul};
 
 #[cfg(feature = "gpu")]
 pub(crate) fn generate_cuda_keys<C: Into<Config>>(config: C) -> (ClientKey, CudaServerKey) {
@@ -293,3 +296,175 @@ where
         assert_eq!(result, expected_result);
     }
 }
+
+fn sorting_test_case<Id, Backend, Clear>(ck: &ClientKey)
+where
+    Id: FheId,
+    Backend: SortingArrayBackend,
+    Backend::Owned: IntoFheScalars<Id>,
+    <Backend::Owned as IntoFheScalars<Id>>::Scalar: FheDecrypt<Clear>,
+    Standard: Distribution<Clear>,
+    Clear: Copy + Ord + From<u8> + Debug,
+    FheBackendArray<Backend, Id>:
+        for<'a> FheTryEncrypt<&'a [Clear], ClientKey> + FheDecrypt<Vec<Clear>>,
+{
+    // The last value is a duplicate, to check that ties keep their order
+    let num_values = 5;
+    let mut clears = draw_random_values::<Clear>(num_values - 1);
+    clears.push(clears[1]);
+
+    let mut expected_sorted = clears.clone();
+    expected_sorted.sort();
+
+    let mut expected_indices = (0..num_values).collect::<Vec<_>>();
+    expected_indices.sort_by_key(|&i| clears[i]);
+    let expected_indices = expected_indices
+        .into_iter()
+        .map(|i| Clear::from(i as u8))
+        .collect::<Vec<_>>();
+
+    let array = FheBackendArray::<Backend, Id>::try_encrypt(&clears, ck).unwrap();
+
+    let sorted = array.sort();
+    assert_eq!(sorted.shape(), &[num_values]);
+    let decrypted: Vec<Clear> = sorted.decrypt(ck);
+    assert_eq!(decrypted, expected_sorted);
+
+    let indices: Vec<Clear> = array.argsort().decrypt(ck);
+    assert_eq!(indices, expected_indices);
+
+    let k = 3;
+    let top: Vec<Clear> = array.top_k(k).decrypt(ck);
+    let expected_top = expected_sorted
+        .iter()
+        .rev()
+        .take(k)
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(top, expected_top);
+
+    let median: Clear = array.median().decrypt(ck);
+    assert_eq!(median, expected_sorted[(num_values - 1) / 2]);
+
+    // The shuffled elements are the same, in an order that only depends on the seed
+    let mut permutations = Vec::new();
+    for seed in 0..4 {
+        let shuffled = array.shuffle(crate::Seed(seed), 16);
+        assert_eq!(shuffled.shape(), &[num_values]);
+        let decrypted: Vec<Clear> = shuffled.decrypt(ck);
+
+        let mut sorted = decrypted.clone();
+        sorted.sort();
+        assert_eq!(sorted, expected_sorted);
+
+        permutations.push(decrypted);
+    }
+    let decrypted: Vec<Clear> = array.shuffle(crate::Seed(0), 16).decrypt(ck);
+    assert_eq!(decrypted, permutations[0]);
+
+    // With one duplicate, there are 60 distinct orders, 4 uniform shuffles
+    // all give the same one with probability 1/60^3
+    permutations.sort();
+    permutations.dedup();
+    assert!(permutations.len() > 1);
+
+    // Sorting a slice only sorts the elements it spans
+    let range = 1..4;
+    let slice = array.slice(&[range.clone()]);
+    let mut expected_sorted = clears[range].to_vec();
+    expected_sorted.sort();
+    let decrypted: Vec<Clear> = slice.sort().decrypt(ck);
+    assert_eq!(decrypted, expected_sorted);
+}
+
+fn reductions_test_case<Id, Backend, Clear>(ck: &ClientKey)
+where
+    Id: FheId,
+    Backend: ReductionArrayBackend + ClearReductionArrayBackend<Clear>,
+    Backend::Owned: IntoFheScalars<Id>,
+    <Backend::Owned as IntoFheScalars<Id>>::Scalar: FheDecrypt<Clear>,
+    Clear: Copy + Ord + From<u8> + Add<Output = Clear> + Mul<Output = Clear> + Debug,
+    FheBackendArray<Backend, Id>: for<'a> FheTryEncrypt<&'a [Clear], ClientKey>,
+{
+    // Small values so that the product does not overflow,
+    // the maximum is duplicated to check that the first index is returned
+    let mut clears = (0..4)
+        .map(|_| Clear::from(random::<u8>() % 8 + 1))
+        .collect::<Vec<_>>();
+    clears.push(Clear::from(9));
+    clears.push(Clear::from(9));
+    let num_values = clears.len();
+
+    let array = FheBackendArray::<Backend, Id>::try_encrypt(&clears, ck).unwrap();
+
+    let sum: Clear = array.sum().decrypt(ck);
+    let expected_sum = clears.iter().copied().reduce(|a, b| a + b).unwrap();
+    assert_eq!(sum, expected_sum);
+
+    let product: Clear = array.product().decrypt(ck);
+    let expected_product = clears.iter().copied().reduce(|a, b| a * b).unwrap();
+    assert_eq!(product, expected_product);
+
+    let (min, argmin) = array.min();
+    let expected_min = *clears.iter().min().unwrap();
+    let expected_argmin = clears.iter().position(|&v| v == expected_min).unwrap();
+    assert_eq!(min.decrypt(ck), expected_min);
+    assert_eq!(argmin.decrypt(ck), Clear::from(expected_argmin as u8));
+
+    let (max, argmax) = array.max();
+    assert_eq!(max.decrypt(ck), Clear::from(9));
+    assert_eq!(argmax.decrypt(ck), Clear::from(num_values as u8 - 2));
+
+    let clear_rhs = (0..num_values)
+        .map(|_| Clear::from(random::<u8>() % 8))
+        .collect::<Vec<_>>();
+    let rhs = ClearArray::new(clear_rhs.clone(), vec![num_values]);
+    let dot: Clear = array.dot(&rhs).decrypt(ck);
+    let expected_dot = clears
+        .iter()
+        .zip(clear_rhs.iter())
+        .map(|(&lhs, &rhs)| lhs * rhs)
+        .reduce(|a, b| a + b)
+        .unwrap();
+    assert_eq!(dot, expected_dot);
+}
+
+fn encrypted_index_test_case<Id, Backend, Clear>(ck: &ClientKey)
+where
+    Id: FheId,
+    Backend: EncryptedIndexArrayBackend,
+    Backend::Owned: FromFheScalars<Id>,
+    <Backend::Owned as IntoFheScalars<Id>>::Scalar:
+        FheDecrypt<Clear> + FheEncrypt<Clear, ClientKey>,
+    Standard: Distribution<Clear>,
+    Clear: Copy + Eq + Default + Debug,
+    FheBackendArray<Backend, Id>:
+        for<'a> FheTryEncrypt<&'a [Clear], ClientKey> + FheDecrypt<Vec<Clear>>,
+{
+    // Enough values for the accesses to go through the tree of selections
+    let num_values = 20;
+    let mut clears = draw_random_values::<Clear>(num_values);
+    let mut array = FheBackendArray::<Backend, Id>::try_encrypt(&clears, ck).unwrap();
+
+    let i = random::<usize>() % num_values;
+    let index = FheUint16::encrypt(i as u16, ck);
+    let value: Clear = array.get_encrypted(&index).decrypt(ck);
+    assert_eq!(value, clears[i]);
+
+    // Out of bounds reads give 0
+    let index = FheUint16::encrypt(num_values as u16, ck);
+    let value: Clear = array.get_encrypted(&index).decrypt(ck);
+    assert_eq!(value, Clear::default());
+
+    let i = random::<usize>() % num_values;
+    let clear_value = random::<Clear>();
+    let index = FheUint16::encrypt(i as u16, ck);
+    let value = <<Backend::Owned as IntoFheScalars<Id>>::Scalar as FheEncrypt<_, _>>::encrypt(
+        clear_value,
+        ck,
+    );
+    array.set_encrypted(&index, &value);
+    clears[i] = clear_value;
+    let result: Vec<Clear> = array.decrypt(ck);
+    assert_eq!(result, clears);
+}
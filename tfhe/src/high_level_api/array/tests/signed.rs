@@ -85,3 +85,63 @@ fn test_cpu_dyn_bitand_scalar_slice() {
     let ck = super::setup_default_cpu();
     super::bitand_scalar_slice_test_case::<crate::FheInt32Array, i32>(&ck);
 }
+
+#[test]
+fn test_cpu_only_sorting() {
+    let ck = super::setup_default_cpu();
+    super::sorting_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::cpu::integers::CpuIntArrayBackend,
+        i32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_sorting() {
+    let ck = super::setup_default_cpu();
+    super::sorting_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::dynamic::DynIntBackend,
+        i32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_only_reductions() {
+    let ck = super::setup_default_cpu();
+    super::reductions_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::cpu::integers::CpuIntArrayBackend,
+        i32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_reductions() {
+    let ck = super::setup_default_cpu();
+    super::reductions_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::dynamic::DynIntBackend,
+        i32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_only_encrypted_index() {
+    let ck = super::setup_default_cpu();
+    super::encrypted_index_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::cpu::integers::CpuIntArrayBackend,
+        i32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_encrypted_index() {
+    let ck = super::setup_default_cpu();
+    super::encrypted_index_test_case::<
+        crate::FheInt32Id,
+        crate::high_level_api::array::dynamic::DynIntBackend,
+        i32,
+    >(&ck);
+}
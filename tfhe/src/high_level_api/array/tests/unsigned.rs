@@ -91,6 +91,66 @@ fn test_cpu_dyn_bitand_scalar_slice() {
     super::bitand_scalar_slice_test_case::<crate::FheUint32Array, u32>(&ck);
 }
 
+#[test]
+fn test_cpu_only_sorting() {
+    let ck = super::setup_default_cpu();
+    super::sorting_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::cpu::integers::CpuUintArrayBackend,
+        u32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_sorting() {
+    let ck = super::setup_default_cpu();
+    super::sorting_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::dynamic::DynUintBackend,
+        u32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_only_reductions() {
+    let ck = super::setup_default_cpu();
+    super::reductions_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::cpu::integers::CpuUintArrayBackend,
+        u32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_reductions() {
+    let ck = super::setup_default_cpu();
+    super::reductions_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::dynamic::DynUintBackend,
+        u32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_only_encrypted_index() {
+    let ck = super::setup_default_cpu();
+    super::encrypted_index_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::cpu::integers::CpuUintArrayBackend,
+        u32,
+    >(&ck);
+}
+
+#[test]
+fn test_cpu_dyn_encrypted_index() {
+    let ck = super::setup_default_cpu();
+    super::encrypted_index_test_case::<
+        crate::FheUint32Id,
+        crate::high_level_api::array::dynamic::DynUintBackend,
+        u32,
+    >(&ck);
+}
+
 #[test]
 fn test_single_dimension() {
     let config = ConfigBuilder::default().build();
@@ -176,3 +236,55 @@ fn test_2_dimension() {
     let r: Vec<u32> = r.decrypt(&cks);
     assert_eq!(r, vec![20, 31, 44, 55]);
 }
+
+#[test]
+fn test_2_dimension_reductions() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    // [[0, 1, 2],
+    //  [3, 4, 5]]
+    let clear_xs = (0..6u32).collect::<Vec<_>>();
+    let xs = FheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 3]), &cks).unwrap();
+
+    let sums = xs.sum_axis(0);
+    assert_eq!(sums.shape(), &[3]);
+    let sums: Vec<u32> = sums.decrypt(&cks);
+    assert_eq!(sums, vec![3, 5, 7]);
+
+    let sums = xs.sum_axis(1);
+    assert_eq!(sums.shape(), &[2]);
+    let sums: Vec<u32> = sums.decrypt(&cks);
+    assert_eq!(sums, vec![3, 12]);
+
+    // [[1, 2],
+    //  [3, 4],
+    //  [5, 6]]
+    let clear_ys = ClearArray::new((1..=6u32).collect::<Vec<_>>(), vec![3, 2]);
+    let zs = xs.matmul(&clear_ys);
+    assert_eq!(zs.shape(), &[2, 2]);
+    let zs: Vec<u32> = zs.decrypt(&cks);
+    assert_eq!(zs, vec![13, 16, 40, 52]);
+
+    // Reductions on a slice only use the elements it spans
+    let xss = xs.slice(&[0..2, 1..3]);
+    let sums: Vec<u32> = xss.sum_axis(1).decrypt(&cks);
+    assert_eq!(sums, vec![3, 9]);
+    let sum: u32 = xss.sum().decrypt(&cks);
+    assert_eq!(sum, 12);
+}
+
+#[test]
+#[should_panic(expected = "the inner dimension is 0")]
+fn test_matmul_empty_inner_dimension() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let xs = FheUint32Array::try_encrypt((&[] as &[u32], vec![2, 0]), &cks).unwrap();
+    let clear_ys = ClearArray::new(Vec::<u32>::new(), vec![0, 2]);
+    let _ = xs.matmul(&clear_ys);
+}
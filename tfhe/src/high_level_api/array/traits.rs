@@ -1,4 +1,6 @@
 use crate::array::stride::{DynDimensions, ParStridedIter, ParStridedIterMut, StridedIter};
+use crate::integer::RadixCiphertext;
+use crate::Seed;
 use std::ops::RangeBounds;
 
 pub struct TensorSlice<'a, Slc> {
@@ -150,6 +152,102 @@ pub trait ClearBitwiseArrayBackend<Clear>: ArrayBackend {
     ) -> Self::Owned;
 }
 
+/// Trait for backends that can reorder the elements of an array
+///
+/// The elements are taken in the iteration order of the slice,
+/// results are always 1-dimensional
+pub trait SortingArrayBackend: ArrayBackend {
+    /// Returns the elements sorted in ascending order
+    fn sort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the indices that sort the elements in ascending order
+    ///
+    /// The sort is stable, and the indices are encrypted using the same
+    /// type as the elements
+    fn argsort(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the `k` greatest elements in descending order
+    fn top_k(slice: TensorSlice<'_, Self::Slice<'_>>, k: usize) -> Self::Owned;
+
+    /// Returns the lower median of the elements, as a container of one element
+    fn median(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the elements in an oblivious random order derived from the seed
+    fn shuffle(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        seed: Seed,
+        statistical_security_bits: u64,
+    ) -> Self::Owned;
+}
+
+/// Trait for backends that can reduce the elements of an array
+pub trait ReductionArrayBackend: ArrayBackend {
+    /// Returns the sum of the elements, as a container of one element
+    fn sum(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the sums along the given axis, in row-major order
+    fn sum_axis(slice: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+
+    /// Returns the product of the elements, as a container of one element
+    fn product(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the minimum followed by the index of its first occurrence
+    fn min(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the maximum followed by the index of its first occurrence
+    fn max(slice: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+}
+
+/// Trait for backends that can reduce the elements of an array with clear values
+pub trait ClearReductionArrayBackend<Clear>: ArrayBackend {
+    /// Returns the sum of the element-wise products, as a container of one element
+    fn dot(lhs: TensorSlice<'_, Self::Slice<'_>>, rhs: TensorSlice<'_, &'_ [Clear]>)
+        -> Self::Owned;
+
+    /// Returns the matrix product of two 2-dimensional slices, in row-major order
+    fn matmul(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned;
+}
+
+/// Trait for backends that can access elements at an encrypted index
+///
+/// The index is a position in the iteration order of the slice,
+/// it is never revealed: all the elements take part in each access
+pub trait EncryptedIndexArrayBackend: ArrayBackend {
+    /// Returns the element at the `index`, as a container of one element
+    ///
+    /// Out of bounds indices give an encryption of 0
+    fn get_at_index(
+        slice: TensorSlice<'_, Self::Slice<'_>>,
+        index: &RadixCiphertext,
+    ) -> Self::Owned;
+
+    /// Replaces the element at the `index` by the only element of `value`
+    ///
+    /// Out of bounds indices leave the slice unchanged
+    fn set_at_index(
+        slice: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &RadixCiphertext,
+        value: Self::Slice<'_>,
+    );
+}
+
+/// Trait for owned containers whose elements can be taken out
+/// as scalar Fhe values (e.g. [crate::FheUint] for unsigned integers)
+pub trait IntoFheScalars<Id> {
+    type Scalar;
+
+    fn into_fhe_scalars(self) -> Vec<Self::Scalar>;
+}
+
+/// Trait for owned containers that can be created from scalar Fhe values,
+/// this is the reverse of [IntoFheScalars]
+pub trait FromFheScalars<Id>: IntoFheScalars<Id> {
+    fn from_fhe_scalars(scalars: &[Self::Scalar]) -> Self;
+}
+
 /// Internal trait to abstract how container store data for the
 /// associated backend
 ///
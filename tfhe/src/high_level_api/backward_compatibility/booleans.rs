@@ -4,7 +4,7 @@ use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 use crate::high_level_api::booleans::{
     InnerBoolean, InnerBooleanVersionOwned, InnerCompressedFheBool,
 };
-use crate::{CompressedFheBool, FheBool, Tag};
+use crate::{CompressedFheBool, FheBool, SquashedNoiseFheBool, Tag};
 use std::convert::Infallible;
 
 // Manual impl
@@ -63,3 +63,8 @@ pub enum CompressedFheBoolVersions {
     V0(CompressedFheBoolV0),
     V1(CompressedFheBool),
 }
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheBoolVersions {
+    V0(SquashedNoiseFheBool),
+}
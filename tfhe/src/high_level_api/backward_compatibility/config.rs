@@ -1,8 +1,13 @@
 use tfhe_versionable::VersionsDispatch;
 
-use crate::Config;
+use crate::{Config, HashBackend};
 
 #[derive(VersionsDispatch)]
 pub enum ConfigVersions {
     V0(Config),
 }
+
+#[derive(VersionsDispatch)]
+pub enum HashBackendVersions {
+    V0(HashBackend),
+}
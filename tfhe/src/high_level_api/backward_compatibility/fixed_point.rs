@@ -0,0 +1,13 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::high_level_api::fixed_point::{FheFixed, FheFixedId, FheUFixed, FheUFixedId};
+
+#[derive(VersionsDispatch)]
+pub enum FheUFixedVersions<Id: FheUFixedId> {
+    V0(FheUFixed<Id>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FheFixedVersions<Id: FheFixedId> {
+    V0(FheFixed<Id>),
+}
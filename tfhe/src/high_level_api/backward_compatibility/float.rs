@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::high_level_api::float::FheFloat32;
+
+#[derive(VersionsDispatch)]
+pub enum FheFloat32Versions {
+    V0(FheFloat32),
+}
@@ -220,3 +220,13 @@ pub enum CompressedFheUintVersions<Id: FheUintId> {
     V0(CompressedFheUintV0<Id>),
     V1(CompressedFheUint<Id>),
 }
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheUintVersions {
+    V0(SquashedNoiseFheUint),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheIntVersions {
+    V0(SquashedNoiseFheInt),
+}
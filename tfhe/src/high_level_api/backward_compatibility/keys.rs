@@ -1,5 +1,5 @@
 use crate::high_level_api::keys::*;
-use crate::Tag;
+use crate::{HashBackend, Tag};
 use std::convert::Infallible;
 use tfhe_versionable::deprecation::{Deprecable, Deprecated};
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
@@ -140,10 +140,37 @@ pub enum CompressedCompactPublicKeyVersions {
     V1(CompressedCompactPublicKey),
 }
 
+#[derive(Version)]
+#[allow(clippy::struct_field_names)]
+pub(crate) struct IntegerConfigV0 {
+    pub(crate) block_parameters: crate::shortint::PBSParameters,
+    pub(crate) dedicated_compact_public_key_parameters: Option<(
+        crate::shortint::parameters::CompactPublicKeyEncryptionParameters,
+        crate::shortint::parameters::ShortintKeySwitchingParameters,
+    )>,
+    pub(crate) compression_parameters:
+        Option<crate::shortint::parameters::list_compression::CompressionParameters>,
+}
+
+impl Upgrade<IntegerConfig> for IntegerConfigV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerConfig, Self::Error> {
+        Ok(IntegerConfig {
+            block_parameters: self.block_parameters,
+            dedicated_compact_public_key_parameters: self.dedicated_compact_public_key_parameters,
+            compression_parameters: self.compression_parameters,
+            noise_squashing_parameters: None,
+            hash_backend: HashBackend::default(),
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 #[allow(unused)]
 pub(crate) enum IntegerConfigVersions {
-    V0(IntegerConfig),
+    V0(IntegerConfigV0),
+    V1(IntegerConfig),
 }
 
 impl Deprecable for IntegerClientKey {
@@ -158,11 +185,11 @@ pub(crate) struct IntegerClientKeyV2 {
     pub(crate) compression_key: Option<crate::shortint::list_compression::CompressionPrivateKeys>,
 }
 
-impl Upgrade<IntegerClientKey> for IntegerClientKeyV2 {
+impl Upgrade<IntegerClientKeyV3> for IntegerClientKeyV2 {
     type Error = Infallible;
 
-    fn upgrade(self) -> Result<IntegerClientKey, Self::Error> {
-        Ok(IntegerClientKey {
+    fn upgrade(self) -> Result<IntegerClientKeyV3, Self::Error> {
+        Ok(IntegerClientKeyV3 {
             key: self.key,
             dedicated_compact_private_key: self.dedicated_compact_private_key,
             compression_key: self
@@ -172,13 +199,35 @@ impl Upgrade<IntegerClientKey> for IntegerClientKeyV2 {
     }
 }
 
+#[derive(Version)]
+pub(crate) struct IntegerClientKeyV3 {
+    pub(crate) key: crate::integer::ClientKey,
+    pub(crate) dedicated_compact_private_key: Option<CompactPrivateKey>,
+    pub(crate) compression_key: Option<crate::integer::compression_keys::CompressionPrivateKeys>,
+}
+
+impl Upgrade<IntegerClientKey> for IntegerClientKeyV3 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerClientKey, Self::Error> {
+        Ok(IntegerClientKey {
+            key: self.key,
+            dedicated_compact_private_key: self.dedicated_compact_private_key,
+            compression_key: self.compression_key,
+            noise_squashing_private_key: None,
+            hash_backend: HashBackend::default(),
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 #[allow(unused)]
 pub(crate) enum IntegerClientKeyVersions {
     V0(Deprecated<IntegerClientKey>),
     V1(Deprecated<IntegerClientKey>),
     V2(IntegerClientKeyV2),
-    V3(IntegerClientKey),
+    V3(IntegerClientKeyV3),
+    V4(IntegerClientKey),
 }
 
 impl Deprecable for IntegerServerKey {
@@ -186,13 +235,38 @@ impl Deprecable for IntegerServerKey {
     const MIN_SUPPORTED_APP_VERSION: &'static str = "TFHE-rs v0.10";
 }
 
+#[derive(Version)]
+pub struct IntegerServerKeyV4 {
+    pub(crate) key: crate::integer::ServerKey,
+    pub(crate) cpk_key_switching_key_material:
+        Option<crate::integer::key_switching_key::KeySwitchingKeyMaterial>,
+    pub(crate) compression_key: Option<crate::integer::compression_keys::CompressionKey>,
+    pub(crate) decompression_key: Option<crate::integer::compression_keys::DecompressionKey>,
+}
+
+impl Upgrade<IntegerServerKey> for IntegerServerKeyV4 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerServerKey, Self::Error> {
+        Ok(IntegerServerKey {
+            key: self.key,
+            cpk_key_switching_key_material: self.cpk_key_switching_key_material,
+            compression_key: self.compression_key,
+            decompression_key: self.decompression_key,
+            noise_squashing_key: None,
+            hash_backend: HashBackend::default(),
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 pub enum IntegerServerKeyVersions {
     V0(Deprecated<IntegerServerKey>),
     V1(Deprecated<IntegerServerKey>),
     V2(Deprecated<IntegerServerKey>),
     V3(Deprecated<IntegerServerKey>),
-    V4(IntegerServerKey),
+    V4(IntegerServerKeyV4),
+    V5(IntegerServerKey),
 }
 
 impl Deprecable for IntegerCompressedServerKey {
@@ -200,11 +274,37 @@ impl Deprecable for IntegerCompressedServerKey {
     const MIN_SUPPORTED_APP_VERSION: &'static str = "TFHE-rs v0.10";
 }
 
+#[derive(Version)]
+pub struct IntegerCompressedServerKeyV2 {
+    pub(crate) key: crate::integer::CompressedServerKey,
+    pub(crate) cpk_key_switching_key_material:
+        Option<crate::integer::key_switching_key::CompressedKeySwitchingKeyMaterial>,
+    pub(crate) compression_key: Option<crate::integer::compression_keys::CompressedCompressionKey>,
+    pub(crate) decompression_key:
+        Option<crate::integer::compression_keys::CompressedDecompressionKey>,
+}
+
+impl Upgrade<IntegerCompressedServerKey> for IntegerCompressedServerKeyV2 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerCompressedServerKey, Self::Error> {
+        Ok(IntegerCompressedServerKey {
+            key: self.key,
+            cpk_key_switching_key_material: self.cpk_key_switching_key_material,
+            compression_key: self.compression_key,
+            decompression_key: self.decompression_key,
+            noise_squashing_key: None,
+            hash_backend: HashBackend::default(),
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 pub enum IntegerCompressedServerKeyVersions {
     V0(Deprecated<IntegerCompressedServerKey>),
     V1(Deprecated<IntegerCompressedServerKey>),
-    V2(IntegerCompressedServerKey),
+    V2(IntegerCompressedServerKeyV2),
+    V3(IntegerCompressedServerKey),
 }
 
 #[derive(VersionsDispatch)]
@@ -6,6 +6,8 @@ pub mod booleans;
 pub mod compact_list;
 pub mod compressed_ciphertext_list;
 pub mod config;
+pub mod fixed_point;
+pub mod float;
 pub mod integers;
 pub mod keys;
 #[cfg(feature = "strings")]
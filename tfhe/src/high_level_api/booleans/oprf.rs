@@ -54,4 +54,50 @@ impl FheBool {
         });
         Self::new(ciphertext, tag)
     }
+
+    /// Generates an encrypted boolean
+    /// which is true with the given `probability` using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability is rounded to a multiple of `2^-precision_bits` (at most 53).
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let ct_res = FheBool::generate_oblivious_pseudo_random_bernoulli(Seed(0), 0.25, 16);
+    ///
+    /// let dec_result: bool = ct_res.decrypt(&client_key);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bernoulli(
+        seed: Seed,
+        probability: f64,
+        precision_bits: u64,
+    ) -> Self {
+        let (ciphertext, tag) = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_bernoulli(
+                        seed,
+                        probability,
+                        precision_bits,
+                    );
+                (InnerBoolean::Cpu(ct), key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!(
+                    "Cuda devices do not support generate_oblivious_pseudo_random_bernoulli yet"
+                );
+            }
+        });
+        Self::new(ciphertext, tag)
+    }
 }
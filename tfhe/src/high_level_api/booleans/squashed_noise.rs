@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::booleans::SquashedNoiseFheBoolVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{FheDecrypt, SquashNoise, Tagged};
+use crate::integer::ciphertext::SquashedNoiseBooleanBlock;
+use crate::named::Named;
+use crate::{ClientKey, FheBool, Tag};
+
+/// A [FheBool] whose noise has been squashed
+///
+/// No homomorphic operations are possible on this type, it can only be decrypted.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::{
+///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+/// };
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+///
+/// let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheBool::encrypt(true, &client_key);
+/// let squashed = a.squash_noise().unwrap();
+///
+/// let decrypted: bool = squashed.decrypt(&client_key);
+/// assert!(decrypted);
+/// ```
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheBoolVersions)]
+pub struct SquashedNoiseFheBool {
+    pub(in crate::high_level_api) inner: SquashedNoiseBooleanBlock,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl Named for SquashedNoiseFheBool {
+    const NAME: &'static str = "high_level_api::SquashedNoiseFheBool";
+}
+
+impl SquashedNoiseFheBool {
+    pub fn from_raw_parts(inner: SquashedNoiseBooleanBlock, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseBooleanBlock, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+}
+
+impl Tagged for SquashedNoiseFheBool {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl FheDecrypt<bool> for SquashedNoiseFheBool {
+    /// Decrypts the squashed ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [ClientKey] does not have a noise squashing private key,
+    /// see [ConfigBuilder::enable_noise_squashing](crate::ConfigBuilder::enable_noise_squashing)
+    fn decrypt(&self, key: &ClientKey) -> bool {
+        key.key
+            .noise_squashing_private_key
+            .as_ref()
+            .expect("No noise squashing private key in ClientKey")
+            .decrypt_bool(&self.inner)
+    }
+}
+
+impl SquashNoise for FheBool {
+    type Output = SquashedNoiseFheBool;
+
+    fn squash_noise(&self) -> crate::Result<Self::Output> {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(server_key) => {
+                let noise_squashing_key = server_key.key.noise_squashing_key()?;
+
+                Ok(SquashedNoiseFheBool {
+                    inner: noise_squashing_key.squash_boolean_block_noise(
+                        server_key.key.pbs_key(),
+                        &self.ciphertext.on_cpu(),
+                    )?,
+                    tag: server_key.tag.clone(),
+                })
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::error!(
+                "Cuda devices do not support noise squashing yet"
+            )),
+        })
+    }
+}
@@ -6,6 +6,9 @@ use crate::backward_compatibility::compact_list::ProvenCompactCiphertextListVers
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::commons::math::random::{Deserialize, Serialize};
 use crate::core_crypto::prelude::Numeric;
+use crate::high_level_api::fixed_point::{
+    quantize_signed, quantize_unsigned, FheFixedId, FheUFixedId,
+};
 use crate::high_level_api::global_state;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::Tagged;
@@ -132,6 +135,8 @@ impl crate::FheTypes {
             }
             DataKind::Boolean => Self::Bool,
             DataKind::String { .. } => Self::AsciiString,
+            // Fixed-point numbers have no FheTypes variant
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => return None,
         })
     }
 }
@@ -280,6 +285,11 @@ mod zk {
             self.inner.verify(crs, &pk.key.key, metadata)
         }
 
+        /// Verifies the proof of the list and expands it
+        ///
+        /// The proof only guarantees that each encrypted block is in the message space, it does
+        /// not guarantee that the strings of the list are valid ASCII strings. Use
+        /// [FheAsciiString::is_valid_ascii](crate::FheAsciiString::is_valid_ascii) to check them.
         pub fn verify_and_expand(
             &self,
             crs: &CompactPkeCrs,
@@ -515,6 +525,58 @@ impl CompactCiphertextListBuilder {
         Ok(self)
     }
 
+    /// Pushes a clear value that will be expanded as a [FheUFixed](crate::FheUFixed)
+    ///
+    /// Returns an error if the value cannot be represented by the fixed-point type.
+    pub fn push_ufixed<Id>(&mut self, value: f64) -> crate::Result<&mut Self>
+    where
+        Id: FheUFixedId,
+    {
+        let raw = quantize_unsigned(value, Id::num_bits(), Id::num_fractional_bits())?;
+        self.push_fixed_point(raw, Id::num_bits(), Id::num_fractional_bits())
+    }
+
+    /// Pushes a clear value that will be expanded as a [FheFixed](crate::FheFixed)
+    ///
+    /// Returns an error if the value cannot be represented by the fixed-point type.
+    pub fn push_fixed<Id>(&mut self, value: f64) -> crate::Result<&mut Self>
+    where
+        Id: FheFixedId,
+    {
+        let raw = quantize_signed(value, Id::num_bits(), Id::num_fractional_bits())?;
+        self.push_fixed_point(raw, Id::num_bits(), Id::num_fractional_bits())
+    }
+
+    fn push_fixed_point<T>(
+        &mut self,
+        raw: T,
+        num_bits: usize,
+        num_fractional_bits: usize,
+    ) -> crate::Result<&mut Self>
+    where
+        T: HlCompactable + Numeric,
+    {
+        self.push_with_num_bits(raw, num_bits)?;
+
+        // The value is compacted as an integer, its kind is then changed so that it can only be
+        // expanded as a fixed-point number with the same number of fractional bits
+        let num_fractional_bits = num_fractional_bits as u32;
+        if let Some(kind) = self.inner.info.last_mut() {
+            *kind = match *kind {
+                DataKind::Unsigned(num_blocks) => DataKind::UnsignedFixed {
+                    num_blocks,
+                    num_fractional_bits,
+                },
+                DataKind::Signed(num_blocks) => DataKind::SignedFixed {
+                    num_blocks,
+                    num_fractional_bits,
+                },
+                kind => kind,
+            };
+        }
+        Ok(self)
+    }
+
     pub fn build(&self) -> CompactCiphertextList {
         CompactCiphertextList {
             inner: self.inner.build(),
@@ -881,4 +943,85 @@ mod tests {
             assert!(expander.get::<FheAsciiString>(0).is_err());
         }
     }
+
+    #[cfg(all(feature = "strings", feature = "zk-pok"))]
+    #[test]
+    fn test_proven_compact_list_with_strings() {
+        use crate::FheAsciiString;
+
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        )
+        .use_dedicated_compact_public_key_parameters((
+            PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        ))
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let pk = crate::CompactPublicKey::new(&ck);
+        let sks = crate::ServerKey::new(&ck);
+
+        set_server_key(sks);
+
+        let crs = CompactPkeCrs::from_config(config, 64).unwrap();
+        let metadata = [b's', b't', b'r'];
+
+        let string1 = ClearString::new("The quick brown fox".to_string());
+        let string2 = ClearString::new("jumps over the lazy dog".to_string());
+
+        let compact_list = ProvenCompactCiphertextList::builder(&pk)
+            .push(&string1)
+            .push_string_with_padding(&string2, 5)
+            .build_with_proof_packed(&crs, &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        let expander = compact_list
+            .verify_and_expand(&crs, &pk, &metadata)
+            .unwrap();
+
+        let a: FheAsciiString = expander.get(0).unwrap().unwrap();
+        let b: FheAsciiString = expander.get(1).unwrap().unwrap();
+        assert_eq!(&a.decrypt(&ck), string1.str());
+        assert_eq!(&b.decrypt(&ck), string2.str());
+
+        assert!(a.is_valid_ascii().decrypt(&ck));
+        assert!(b.is_valid_ascii().decrypt(&ck));
+
+        // A malicious client can encrypt chars greater than 127 and nulls that are not in the
+        // padding, the proof cannot detect it so the strings must be checked
+        let malicious: [(&[u8], bool); 4] = [
+            (&[b'a' | 0x80, b'b'], false),
+            (&[b'a', 0, b'c'], true),
+            (&[b'a', b'b', 0], false),
+            // Valid, but built by hand
+            (&[b'a', b'b', 0, 0], true),
+        ];
+        let mut builder = ProvenCompactCiphertextList::builder(&pk);
+        for (bytes, padded) in malicious {
+            for &byte in bytes {
+                builder.inner.push(byte);
+            }
+            builder
+                .inner
+                .info
+                .truncate(builder.inner.info.len() - bytes.len());
+            builder.inner.info.push(DataKind::String {
+                n_chars: bytes.len() as u32,
+                padded,
+            });
+        }
+        let compact_list = builder
+            .build_with_proof_packed(&crs, &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        let expander = compact_list
+            .verify_and_expand(&crs, &pk, &metadata)
+            .unwrap();
+
+        for (i, expected) in [false, false, false, true].into_iter().enumerate() {
+            let string: FheAsciiString = expander.get(i).unwrap().unwrap();
+            assert_eq!(string.is_valid_ascii().decrypt(&ck), expected);
+        }
+    }
 }
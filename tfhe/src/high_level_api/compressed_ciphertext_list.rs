@@ -12,6 +12,7 @@ use crate::core_crypto::commons::math::random::{Deserialize, Serialize};
 use crate::core_crypto::gpu::CudaStreams;
 use crate::high_level_api::booleans::InnerBoolean;
 use crate::high_level_api::errors::UninitializedServerKey;
+use crate::high_level_api::fixed_point::{FheFixed, FheFixedId, FheUFixed, FheUFixedId};
 use crate::high_level_api::global_state::device_of_internal_keys;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
@@ -62,6 +63,42 @@ impl<Id: FheIntId> HlCompressible for FheInt<Id> {
         }
     }
 }
+macro_rules! impl_hl_compressible_for_fixed_point (
+    ($fixed:ident, $id_trait:ident, $kind:ident, $integers_mod:ident) => {
+        impl<Id: $id_trait> HlCompressible for $fixed<Id> {
+            fn compress_into(self, messages: &mut Vec<(ToBeCompressed, DataKind)>) {
+                let num_fractional_bits = Id::num_fractional_bits() as u32;
+                match self.ciphertext {
+                    crate::high_level_api::integers::$integers_mod::RadixCiphertext::Cpu(
+                        cpu_radix,
+                    ) => {
+                        let blocks = cpu_radix.blocks;
+                        let kind = DataKind::$kind {
+                            num_blocks: blocks.len(),
+                            num_fractional_bits,
+                        };
+                        messages.push((ToBeCompressed::Cpu(blocks), kind));
+                    }
+                    #[cfg(feature = "gpu")]
+                    crate::high_level_api::integers::$integers_mod::RadixCiphertext::Cuda(
+                        gpu_radix,
+                    ) => {
+                        let blocks = gpu_radix.ciphertext;
+                        let kind = DataKind::$kind {
+                            num_blocks: blocks.info.blocks.len(),
+                            num_fractional_bits,
+                        };
+                        messages.push((ToBeCompressed::Cuda(blocks), kind));
+                    }
+                }
+            }
+        }
+    }
+);
+
+impl_hl_compressible_for_fixed_point!(FheUFixed, FheUFixedId, UnsignedFixed, unsigned);
+impl_hl_compressible_for_fixed_point!(FheFixed, FheFixedId, SignedFixed, signed);
+
 impl HlCompressible for FheBool {
     fn compress_into(self, messages: &mut Vec<(ToBeCompressed, DataKind)>) {
         match self.ciphertext {
@@ -80,6 +117,8 @@ impl HlCompressible for FheBool {
 
 impl<Id: FheUintId> HlExpandable for FheUint<Id> {}
 impl<Id: FheIntId> HlExpandable for FheInt<Id> {}
+impl<Id: FheUFixedId> HlExpandable for FheUFixed<Id> {}
+impl<Id: FheFixedId> HlExpandable for FheFixed<Id> {}
 impl HlExpandable for FheBool {}
 
 #[cfg(not(feature = "gpu"))]
@@ -519,6 +558,7 @@ impl CompressedCiphertextList {
 #[cfg(feature = "gpu")]
 pub mod gpu {
     use crate::core_crypto::gpu::CudaStreams;
+    use crate::high_level_api::fixed_point::{FheFixed, FheFixedId, FheUFixed, FheUFixedId};
     use crate::high_level_api::integers::{FheIntId, FheUintId};
     use crate::integer::ciphertext::DataKind;
     use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
@@ -613,6 +653,12 @@ pub mod gpu {
                     "Tried to expand a FheUint{} while a FheString is stored in this slot",
                     Id::num_bits()
                 )),
+                DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                    Err(crate::error!(
+                        "Tried to expand a FheUint{} while a fixed-point number is stored in this slot",
+                        Id::num_bits()
+                    ))
+                }
             }
         }
     }
@@ -657,10 +703,34 @@ pub mod gpu {
                     "Tried to expand a FheInt{} while a FheString is stored in this slot",
                     Id::num_bits()
                 )),
+                DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                    Err(crate::error!(
+                        "Tried to expand a FheInt{} while a fixed-point number is stored in this slot",
+                        Id::num_bits()
+                    ))
+                }
             }
         }
     }
 
+    impl<Id: FheUFixedId> CudaExpandable for FheUFixed<Id> {
+        fn from_expanded_blocks(
+            _blocks: CudaRadixCiphertext,
+            _kind: DataKind,
+        ) -> crate::Result<Self> {
+            Err(crate::error!("gpu does not support fixed-point numbers"))
+        }
+    }
+
+    impl<Id: FheFixedId> CudaExpandable for FheFixed<Id> {
+        fn from_expanded_blocks(
+            _blocks: CudaRadixCiphertext,
+            _kind: DataKind,
+        ) -> crate::Result<Self> {
+            Err(crate::error!("gpu does not support fixed-point numbers"))
+        }
+    }
+
     impl CudaExpandable for FheBool {
         fn from_expanded_blocks(
             blocks: CudaRadixCiphertext,
@@ -691,6 +761,11 @@ pub mod gpu {
                 DataKind::String { .. } => Err(crate::error!(
                     "Tried to expand a FheBool while a FheString is stored in this slot"
                 )),
+                DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                    Err(crate::error!(
+                    "Tried to expand a FheBool while a fixed-point number is stored in this slot"
+                ))
+                }
             }
         }
     }
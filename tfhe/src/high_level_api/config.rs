@@ -1,8 +1,9 @@
 use tfhe_versionable::Versionize;
 
-use crate::backward_compatibility::config::ConfigVersions;
+use crate::backward_compatibility::config::{ConfigVersions, HashBackendVersions};
 use crate::high_level_api::keys::IntegerConfig;
 use crate::shortint::parameters::list_compression::CompressionParameters;
+use crate::shortint::parameters::NoiseSquashingParameters;
 
 /// The config type
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
@@ -20,6 +21,23 @@ impl Config {
     }
 }
 
+/// Selects how the SHA-256 compression function of the strings hash functions is evaluated
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize,
+)]
+#[versionize(HashBackendVersions)]
+pub enum HashBackend {
+    /// Each 32 bits word is a radix ciphertext and the compression uses the integer operations
+    /// (rotations, bitwise operations and additions)
+    #[default]
+    Radix,
+    /// Each bit of a word is a separate block, rotations and shifts are free and several bits
+    /// are packed in a block to evaluate the boolean functions and the adders with few PBS
+    ///
+    /// This requires parameters with at least 2 bits of message and 2 bits of carry.
+    Boolean,
+}
+
 /// The builder to create your config
 ///
 /// The configuration is needed to select parameters you wish to use for these types
@@ -48,6 +66,24 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn enable_noise_squashing(
+        mut self,
+        noise_squashing_parameters: NoiseSquashingParameters,
+    ) -> Self {
+        self.config
+            .inner
+            .enable_noise_squashing(noise_squashing_parameters);
+
+        self
+    }
+
+    /// Selects the backend used by the hash functions of the server key
+    pub fn use_hash_backend(mut self, hash_backend: HashBackend) -> Self {
+        self.config.inner.hash_backend = hash_backend;
+
+        self
+    }
+
     pub fn with_custom_parameters<P>(block_parameters: P) -> Self
     where
         P: Into<crate::shortint::PBSParameters>,
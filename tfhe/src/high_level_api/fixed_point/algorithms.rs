@@ -0,0 +1,134 @@
+//! Fixed-point arithmetic on the integer radix representation
+//!
+//! These work for both signed and unsigned radix ciphertexts,
+//! the sign only changes how blocks are extended and how right shifts are done.
+
+use super::RoundingMode;
+use crate::integer::{IntegerRadixCiphertext, ServerKey};
+
+/// Casts the ciphertext to the target type and number of blocks
+///
+/// Blocks are added by sign or zero extension, depending on the source type,
+/// and removed by truncation (i.e. the value wraps around).
+pub(super) fn cast<T, U>(sks: &ServerKey, ct: U, target_num_blocks: usize) -> T
+where
+    T: IntegerRadixCiphertext,
+    U: IntegerRadixCiphertext,
+{
+    let blocks = if T::IS_SIGNED {
+        sks.cast_to_signed(ct, target_num_blocks).into_blocks()
+    } else {
+        sks.cast_to_unsigned(ct, target_num_blocks).into_blocks()
+    };
+    T::from_blocks(blocks)
+}
+
+/// Multiplies two fixed-point numbers having `num_fractional_bits` fractional bits
+///
+/// The full product is computed on twice the number of blocks, then
+/// the extra fractional bits are removed according to the rounding mode.
+/// Integer bits that do not fit in the output wrap around.
+pub(super) fn mul<T>(
+    sks: &ServerKey,
+    lhs: &T,
+    rhs: &T,
+    num_fractional_bits: usize,
+    rounding_mode: RoundingMode,
+) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    let num_blocks = lhs.blocks().len();
+    let (lhs, rhs) = rayon::join(
+        || cast::<T, T>(sks, lhs.clone(), 2 * num_blocks),
+        || cast::<T, T>(sks, rhs.clone(), 2 * num_blocks),
+    );
+
+    let mut product = sks.mul_parallelized(&lhs, &rhs);
+    if rounding_mode == RoundingMode::Nearest && num_fractional_bits > 0 {
+        sks.scalar_add_assign_parallelized(&mut product, 1u128 << (num_fractional_bits - 1));
+    }
+    sks.scalar_right_shift_assign_parallelized(&mut product, num_fractional_bits as u64);
+
+    cast(sks, product, num_blocks)
+}
+
+/// Returns the number of blocks needed to shift a ciphertext
+/// by `num_fractional_bits` to the left without losing bits
+pub(super) fn num_blocks_for_division(
+    sks: &ServerKey,
+    num_blocks: usize,
+    num_fractional_bits: usize,
+) -> usize {
+    let bits_per_block = sks.message_modulus().0.ilog2() as usize;
+    num_blocks + num_fractional_bits.div_ceil(bits_per_block)
+}
+
+/// Returns the numerator of a fixed-point division, that is the input
+/// shifted left by `num_fractional_bits` on `target_num_blocks` blocks
+pub(super) fn division_numerator<T>(
+    sks: &ServerKey,
+    ct: &T,
+    target_num_blocks: usize,
+    num_fractional_bits: usize,
+) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    let mut numerator = cast::<T, T>(sks, ct.clone(), target_num_blocks);
+    sks.scalar_left_shift_assign_parallelized(&mut numerator, num_fractional_bits as u64);
+    numerator
+}
+
+/// Divides two fixed-point numbers having `num_fractional_bits` fractional bits
+///
+/// The quotient is truncated towards zero.
+pub(super) fn div<T>(sks: &ServerKey, lhs: &T, rhs: &T, num_fractional_bits: usize) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    let num_blocks = lhs.blocks().len();
+    let target_num_blocks = num_blocks_for_division(sks, num_blocks, num_fractional_bits);
+    let (numerator, divisor) = rayon::join(
+        || division_numerator(sks, lhs, target_num_blocks, num_fractional_bits),
+        || cast::<T, T>(sks, rhs.clone(), target_num_blocks),
+    );
+
+    let quotient = sks.div_parallelized(&numerator, &divisor);
+    cast(sks, quotient, num_blocks)
+}
+
+/// Converts an integer into a fixed-point number of `num_blocks` blocks
+///
+/// The integer bits that do not fit wrap around.
+pub(super) fn from_integer<T, U>(
+    sks: &ServerKey,
+    ct: U,
+    num_blocks: usize,
+    num_fractional_bits: usize,
+) -> T
+where
+    T: IntegerRadixCiphertext,
+    U: IntegerRadixCiphertext,
+{
+    let mut result = cast::<T, U>(sks, ct, num_blocks);
+    sks.scalar_left_shift_assign_parallelized(&mut result, num_fractional_bits as u64);
+    result
+}
+
+/// Converts a fixed-point number into an integer of `num_blocks` blocks
+///
+/// The fractional bits are discarded, which rounds towards negative infinity.
+pub(super) fn to_integer<T, U>(
+    sks: &ServerKey,
+    ct: &T,
+    num_blocks: usize,
+    num_fractional_bits: usize,
+) -> U
+where
+    T: IntegerRadixCiphertext,
+    U: IntegerRadixCiphertext,
+{
+    let shifted = sks.scalar_right_shift_parallelized(ct, num_fractional_bits as u64);
+    cast(sks, shifted, num_blocks)
+}
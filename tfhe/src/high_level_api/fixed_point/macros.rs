@@ -0,0 +1,411 @@
+//! Implementations shared by [FheUFixed](super::FheUFixed) and [FheFixed](super::FheFixed)
+//!
+//! Both types wrap a radix ciphertext and only differ by its signedness,
+//! so everything that does not depend on the sign is generated here.
+
+macro_rules! impl_fixed_point_common (
+    (
+        type: $fixed:ident,
+        id_trait: $id_trait:ident,
+        name: $name:literal,
+        hl_radix: $hl_radix:ty,
+        radix: $radix:ty,
+        clear: $clear:ty,
+        quantize: $quantize:ident,
+        encrypt: $encrypt:ident,
+        decrypt: $decrypt:ident,
+        example: $example:ident,
+        division_by_zero: $division_by_zero:literal
+        $(,)?
+    ) => {
+        impl<Id: $id_trait> Named for $fixed<Id> {
+            const NAME: &'static str = $name;
+        }
+
+        impl<Id: $id_trait> Tagged for $fixed<Id> {
+            fn tag(&self) -> &Tag {
+                &self.tag
+            }
+
+            fn tag_mut(&mut self) -> &mut Tag {
+                &mut self.tag
+            }
+        }
+
+        impl<Id: $id_trait> $fixed<Id> {
+            pub(in crate::high_level_api) fn new<T>(ciphertext: T, tag: Tag) -> Self
+            where
+                T: Into<$hl_radix>,
+            {
+                Self {
+                    ciphertext: ciphertext.into(),
+                    id: Id::default(),
+                    tag,
+                }
+            }
+
+            pub fn into_raw_parts(self) -> ($radix, Id, Tag) {
+                let Self {
+                    ciphertext,
+                    id,
+                    tag,
+                } = self;
+
+                (ciphertext.into_cpu(), id, tag)
+            }
+
+            pub fn from_raw_parts(ciphertext: $radix, id: Id, tag: Tag) -> Self {
+                Self {
+                    ciphertext: ciphertext.into(),
+                    id,
+                    tag,
+                }
+            }
+
+            /// Returns the total number of bits
+            pub fn num_bits() -> usize {
+                Id::num_bits()
+            }
+
+            pub fn num_integer_bits() -> usize {
+                Id::num_integer_bits()
+            }
+
+            pub fn num_fractional_bits() -> usize {
+                Id::num_fractional_bits()
+            }
+
+            /// Returns the device where the ciphertext is currently on
+            pub fn current_device(&self) -> Device {
+                self.ciphertext.current_device()
+            }
+
+            /// Moves (in-place) the ciphertext to the device of the current
+            /// thread-local server key
+            ///
+            /// Does nothing if the ciphertext is already in the desired device
+            /// or if no server key is set
+            pub fn move_to_current_device(&mut self) {
+                self.ciphertext.move_to_device_of_server_key_if_set();
+            }
+
+            #[doc = concat!("Multiplies two [", stringify!($fixed), "], using the given rounding mode")]
+            /// for the fractional bits that do not fit in the result.
+            ///
+            /// The `*` operator uses [RoundingMode::Floor].
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use tfhe::prelude::*;
+            #[doc = concat!("use tfhe::{generate_keys, set_server_key, ConfigBuilder, ", stringify!($example), ", RoundingMode};")]
+            ///
+            /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+            /// set_server_key(server_key);
+            ///
+            /// // 0.01171875 = 3 / 2^8
+            #[doc = concat!("let a = ", stringify!($example), "::encrypt(0.01171875f64, &client_key);")]
+            #[doc = concat!("let b = ", stringify!($example), "::encrypt(0.5f64, &client_key);")]
+            ///
+            /// let floor: f64 = a.mul_with_rounding(&b, RoundingMode::Floor).decrypt(&client_key);
+            /// assert_eq!(floor, 1.0 / 256.0);
+            ///
+            /// let nearest: f64 = a.mul_with_rounding(&b, RoundingMode::Nearest).decrypt(&client_key);
+            /// assert_eq!(nearest, 2.0 / 256.0);
+            /// ```
+            pub fn mul_with_rounding(&self, rhs: &Self, rounding_mode: RoundingMode) -> Self {
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let result = algorithms::mul(
+                            cpu_key.pbs_key(),
+                            &*self.ciphertext.on_cpu(),
+                            &*rhs.ciphertext.on_cpu(),
+                            Id::num_fractional_bits(),
+                            rounding_mode,
+                        );
+                        Self::new(result, cpu_key.tag.clone())
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!("gpu does not support fixed-point multiplication");
+                    }
+                })
+            }
+        }
+
+        impl<Id> FheTryEncrypt<f64, ClientKey> for $fixed<Id>
+        where
+            Id: $id_trait,
+        {
+            type Error = crate::Error;
+
+            /// Encrypts the value, rounded to the nearest representable value
+            ///
+            /// Returns an error if the value is out of the range of the type.
+            fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+                let raw = $quantize(value, Id::num_bits(), Id::num_fractional_bits())?;
+                let cpu_ciphertext = key
+                    .key
+                    .key
+                    .$encrypt(raw, Id::num_blocks(key.message_modulus()));
+                let mut ciphertext = Self::new(cpu_ciphertext, key.tag.clone());
+
+                ciphertext.move_to_current_device();
+
+                Ok(ciphertext)
+            }
+        }
+
+        impl<Id> FheTryTrivialEncrypt<f64> for $fixed<Id>
+        where
+            Id: $id_trait,
+        {
+            type Error = crate::Error;
+
+            fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+                let raw = $quantize(value, Id::num_bits(), Id::num_fractional_bits())?;
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let ciphertext: $radix = cpu_key
+                            .pbs_key()
+                            .create_trivial_radix(raw, Id::num_blocks(cpu_key.message_modulus()));
+                        Ok(Self::new(ciphertext, cpu_key.tag.clone()))
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!("gpu does not support fixed-point numbers");
+                    }
+                })
+            }
+        }
+
+        impl<Id> FheDecrypt<f64> for $fixed<Id>
+        where
+            Id: $id_trait,
+        {
+            /// Decrypts the value
+            ///
+            /// # Panics
+            ///
+            /// Panics if the type has more than 64 bits
+            fn decrypt(&self, key: &ClientKey) -> f64 {
+                assert!(
+                    Id::num_bits() <= 64,
+                    "Decrypting fixed-point numbers of more than 64 bits is not supported"
+                );
+                let raw: $clear = key.key.key.$decrypt(&self.ciphertext.on_cpu());
+                raw as f64 / 2f64.powi(Id::num_fractional_bits() as i32)
+            }
+        }
+
+        fixed_point_impl_operation!(
+            $fixed, $id_trait,
+            #[doc = concat!("Adds two [", stringify!($fixed), "]")]
+            ///
+            /// The operation is modular, i.e on overflow it wraps around.
+            rust_trait: Add(add),
+            rust_trait_assign: AddAssign(add_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey, lhs, rhs| sks.add_parallelized(lhs, rhs)
+            },
+        );
+
+        fixed_point_impl_operation!(
+            $fixed, $id_trait,
+            #[doc = concat!("Subtracts two [", stringify!($fixed), "]")]
+            ///
+            /// The operation is modular, i.e on overflow it wraps around.
+            rust_trait: Sub(sub),
+            rust_trait_assign: SubAssign(sub_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey, lhs, rhs| sks.sub_parallelized(lhs, rhs)
+            },
+        );
+
+        fixed_point_impl_operation!(
+            $fixed, $id_trait,
+            #[doc = concat!("Multiplies two [", stringify!($fixed), "]")]
+            ///
+            /// Fractional bits that do not fit are discarded ([RoundingMode::Floor]),
+            #[doc = concat!("see [", stringify!($fixed), "::mul_with_rounding] to choose the rounding.")]
+            /// Integer bits that do not fit wrap around.
+            rust_trait: Mul(mul),
+            rust_trait_assign: MulAssign(mul_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey, lhs, rhs| algorithms::mul(
+                    sks,
+                    lhs,
+                    rhs,
+                    Id::num_fractional_bits(),
+                    RoundingMode::Floor,
+                )
+            },
+        );
+
+        fixed_point_impl_operation!(
+            $fixed, $id_trait,
+            #[doc = concat!("Divides two [", stringify!($fixed), "]")]
+            ///
+            #[doc = concat!("The quotient is truncated towards zero, ", $division_by_zero, ".")]
+            rust_trait: Div(div),
+            rust_trait_assign: DivAssign(div_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey, lhs, rhs| algorithms::div(
+                    sks,
+                    lhs,
+                    rhs,
+                    Id::num_fractional_bits(),
+                )
+            },
+        );
+
+        fixed_point_impl_comparison!($fixed, $id_trait, FheEq, FheBool, {
+            eq => eq_parallelized,
+            ne => ne_parallelized,
+        });
+
+        fixed_point_impl_comparison!($fixed, $id_trait, FheOrd, FheBool, {
+            lt => lt_parallelized,
+            le => le_parallelized,
+            gt => gt_parallelized,
+            ge => ge_parallelized,
+        });
+
+        fixed_point_impl_comparison!($fixed, $id_trait, FheMin, Self, {
+            min => min_parallelized,
+        });
+
+        fixed_point_impl_comparison!($fixed, $id_trait, FheMax, Self, {
+            max => max_parallelized,
+        });
+    }
+);
+
+macro_rules! fixed_point_impl_operation (
+    (
+        $fixed:ident, $id_trait:ident,
+        $(#[$outer:meta])*
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        rust_trait_assign: $rust_trait_assign_name:ident($rust_trait_assign_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<Id, B> $rust_trait_name<B> for $fixed<Id>
+        where
+            Id: $id_trait,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<Id, B> $rust_trait_name<B> for &$fixed<Id>
+        where
+            Id: $id_trait,
+            B: Borrow<$fixed<Id>>,
+        {
+            type Output = $fixed<Id>;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let result = $closure(
+                            cpu_key.pbs_key(),
+                            &*self.ciphertext.on_cpu(),
+                            &*rhs.borrow().ciphertext.on_cpu(),
+                        );
+                        $fixed::new(result, cpu_key.tag.clone())
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!("gpu does not support fixed-point numbers");
+                    }
+                })
+            }
+        }
+
+        impl<Id, B> $rust_trait_assign_name<B> for $fixed<Id>
+        where
+            Id: $id_trait,
+            B: Borrow<Self>,
+        {
+            fn $rust_trait_assign_method(&mut self, rhs: B) {
+                *self = <&Self as $rust_trait_name<B>>::$rust_trait_method(&*self, rhs);
+            }
+        }
+    }
+);
+
+// Implements a trait whose methods take `&self` and `&Self`, by calling the
+// integer server key method of the same name on the inner ciphertexts
+macro_rules! fixed_point_impl_comparison (
+    (
+        $fixed:ident, $id_trait:ident, $trait_name:ident, $output:ident, {
+            $($method:ident => $integer_method:ident),* $(,)?
+        }
+    ) => {
+        fixed_point_impl_comparison!(@output $output, $fixed, $id_trait, $trait_name, {
+            $($method => $integer_method),*
+        });
+    };
+    (@output FheBool, $fixed:ident, $id_trait:ident, $trait_name:ident, {
+        $($method:ident => $integer_method:ident),*
+    }) => {
+        impl<Id> $trait_name<&Self> for $fixed<Id>
+        where
+            Id: $id_trait,
+        {
+            $(
+                fn $method(&self, rhs: &Self) -> FheBool {
+                    global_state::with_internal_keys(|key| match key {
+                        InternalServerKey::Cpu(cpu_key) => {
+                            let inner_result = cpu_key.pbs_key().$integer_method(
+                                &*self.ciphertext.on_cpu(),
+                                &*rhs.ciphertext.on_cpu(),
+                            );
+                            FheBool::new(inner_result, cpu_key.tag.clone())
+                        }
+                        #[cfg(feature = "gpu")]
+                        InternalServerKey::Cuda(_) => {
+                            panic!("gpu does not support fixed-point numbers");
+                        }
+                    })
+                }
+            )*
+        }
+    };
+    (@output Self, $fixed:ident, $id_trait:ident, $trait_name:ident, {
+        $($method:ident => $integer_method:ident),*
+    }) => {
+        impl<Id> $trait_name<&Self> for $fixed<Id>
+        where
+            Id: $id_trait,
+        {
+            type Output = Self;
+
+            $(
+                fn $method(&self, rhs: &Self) -> Self::Output {
+                    global_state::with_internal_keys(|key| match key {
+                        InternalServerKey::Cpu(cpu_key) => {
+                            let inner_result = cpu_key.pbs_key().$integer_method(
+                                &*self.ciphertext.on_cpu(),
+                                &*rhs.ciphertext.on_cpu(),
+                            );
+                            Self::new(inner_result, cpu_key.tag.clone())
+                        }
+                        #[cfg(feature = "gpu")]
+                        InternalServerKey::Cuda(_) => {
+                            panic!("gpu does not support fixed-point numbers");
+                        }
+                    })
+                }
+            )*
+        }
+    };
+);
@@ -0,0 +1,96 @@
+//! Encrypted fixed-point numbers
+//!
+//! A fixed-point number with `I` integer bits and `F` fractional bits
+//! is stored as an integer radix ciphertext of `I + F` bits which
+//! encrypts `round(value * 2^F)`.
+//!
+//! - [FheUFixed] is the unsigned variant (e.g. [FheUFixedU8F8](crate::FheUFixedU8F8))
+//! - [FheFixed] is the signed variant, its integer bits include the sign bit (e.g.
+//!   [FheFixedI8F8](crate::FheFixedI8F8))
+pub use signed::FheFixed;
+pub use static_::*;
+pub use unsigned::FheUFixed;
+
+use crate::high_level_api::integers::IntegerId;
+
+#[macro_use]
+mod macros;
+mod algorithms;
+mod signed;
+mod static_;
+mod unsigned;
+
+#[cfg(test)]
+mod tests;
+
+/// Trait to mark ID type for fixed-point numbers
+///
+/// [IntegerId::num_bits] is the total number of bits (integer bits + fractional bits).
+pub trait FixedPointId: IntegerId {
+    fn num_fractional_bits() -> usize;
+
+    fn num_integer_bits() -> usize {
+        Self::num_bits() - Self::num_fractional_bits()
+    }
+}
+
+pub trait FheUFixedId: FixedPointId {}
+
+pub trait FheFixedId: FixedPointId {}
+
+/// Rounding applied when an operation produces more fractional bits
+/// than the type can hold (e.g. multiplication)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discards the extra fractional bits, which rounds towards negative infinity
+    #[default]
+    Floor,
+    /// Rounds to the nearest representable value, ties are rounded up
+    Nearest,
+}
+
+fn check_num_bits(num_bits: usize) -> crate::Result<()> {
+    if num_bits > 64 {
+        return Err(crate::error!(
+            "Encrypting clear values in fixed-point numbers of more than 64 bits is not supported"
+        ));
+    }
+    Ok(())
+}
+
+/// Converts the value into the integer that represents it in an unsigned fixed-point number
+pub(crate) fn quantize_unsigned(
+    value: f64,
+    num_bits: usize,
+    num_fractional_bits: usize,
+) -> crate::Result<u64> {
+    check_num_bits(num_bits)?;
+    let scaled = (value * 2f64.powi(num_fractional_bits as i32)).round();
+    if !scaled.is_finite() || scaled < 0.0 || scaled >= 2f64.powi(num_bits as i32) {
+        return Err(crate::error!(
+            "{value} cannot be represented by an unsigned fixed-point number with \
+            {} integer bits and {num_fractional_bits} fractional bits",
+            num_bits - num_fractional_bits
+        ));
+    }
+    Ok(scaled as u64)
+}
+
+/// Converts the value into the integer that represents it in a signed fixed-point number
+pub(crate) fn quantize_signed(
+    value: f64,
+    num_bits: usize,
+    num_fractional_bits: usize,
+) -> crate::Result<i64> {
+    check_num_bits(num_bits)?;
+    let scaled = (value * 2f64.powi(num_fractional_bits as i32)).round();
+    let bound = 2f64.powi(num_bits as i32 - 1);
+    if !scaled.is_finite() || scaled < -bound || scaled >= bound {
+        return Err(crate::error!(
+            "{value} cannot be represented by a signed fixed-point number with \
+            {} integer bits and {num_fractional_bits} fractional bits",
+            num_bits - num_fractional_bits
+        ));
+    }
+    Ok(scaled as i64)
+}
@@ -0,0 +1,267 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use tfhe_versionable::Versionize;
+
+use super::{algorithms, quantize_signed, FheFixedId, RoundingMode};
+use crate::backward_compatibility::fixed_point::FheFixedVersions;
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::signed::RadixCiphertext;
+use crate::high_level_api::integers::FheIntId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTryEncrypt, FheTryTrivialEncrypt, Tagged,
+};
+use crate::high_level_api::Device;
+use crate::named::Named;
+use crate::{ClientKey, FheBool, FheInt, Tag};
+
+/// A generic FHE signed fixed-point number
+///
+/// The Id controls the number of integer bits and fractional bits,
+/// you will need to use one of this type specialization (e.g. [FheFixedI8F8],
+/// [FheFixedI16F16]).
+///
+/// The integer bits include the sign bit, values are stored in two's complement.
+///
+/// Arithmetic operations wrap around on overflow, like [FheInt].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFixedI8F8::encrypt(-1.5f64, &client_key);
+/// let b = FheFixedI8F8::encrypt(2.25f64, &client_key);
+///
+/// let c = &a * &b;
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, -3.375);
+/// ```
+///
+/// [FheFixedI8F8]: crate::FheFixedI8F8
+/// [FheFixedI16F16]: crate::FheFixedI16F16
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(FheFixedVersions)]
+pub struct FheFixed<Id: FheFixedId> {
+    pub(in crate::high_level_api) ciphertext: RadixCiphertext,
+    pub(in crate::high_level_api) id: Id,
+    pub(crate) tag: Tag,
+}
+
+impl_fixed_point_common!(
+    type: FheFixed,
+    id_trait: FheFixedId,
+    name: "high_level_api::FheFixed",
+    hl_radix: RadixCiphertext,
+    radix: crate::integer::SignedRadixCiphertext,
+    clear: i64,
+    quantize: quantize_signed,
+    encrypt: encrypt_signed_radix,
+    decrypt: decrypt_signed_radix,
+    example: FheFixedI8F8,
+    division_by_zero: "the result of a division by zero is not specified",
+);
+
+impl<Id: FheFixedId> FheFixed<Id> {
+    /// Divides by a clear value
+    ///
+    /// The divisor is first converted to the fixed-point representation of this type,
+    /// the quotient is truncated towards zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the divisor cannot be represented by this type or is zero
+    /// once converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::encrypt(-7.5f64, &client_key);
+    ///
+    /// let c = a.scalar_div(2.5).unwrap();
+    /// let decrypted: f64 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, -3.0);
+    ///
+    /// assert!(a.scalar_div(0.0).is_err());
+    /// ```
+    pub fn scalar_div(&self, divisor: f64) -> crate::Result<Self> {
+        let divisor = quantize_signed(divisor, Id::num_bits(), Id::num_fractional_bits())?;
+        if divisor == 0 {
+            return Err(crate::error!("Fixed-point division by zero"));
+        }
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let num_fractional_bits = Id::num_fractional_bits();
+                let ct = self.ciphertext.on_cpu();
+                let num_blocks = ct.blocks.len();
+                let target_num_blocks =
+                    algorithms::num_blocks_for_division(sks, num_blocks, num_fractional_bits);
+
+                let numerator = algorithms::division_numerator(
+                    sks,
+                    &*ct,
+                    target_num_blocks,
+                    num_fractional_bits,
+                );
+                let quotient = sks.signed_scalar_div_parallelized(&numerator, i128::from(divisor));
+                let result: crate::integer::SignedRadixCiphertext =
+                    algorithms::cast(sks, quotient, num_blocks);
+                Ok(Self::new(result, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point division");
+            }
+        })
+    }
+}
+
+impl<Id> Neg for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<Id> Neg for &FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = FheFixed<Id>;
+
+    /// Computes the negation of a [FheFixed]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::encrypt(3.25f64, &client_key);
+    ///
+    /// let result = -&a;
+    /// let decrypted: f64 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -3.25);
+    /// ```
+    fn neg(self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .neg_parallelized(&*self.ciphertext.on_cpu());
+                FheFixed::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point numbers");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheInt<FromId>> for FheFixed<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheFixedId,
+{
+    /// Cast a FheInt to a FheFixed
+    ///
+    /// Integer bits that do not fit wrap around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-42i16, &client_key);
+    /// let b = FheFixedI8F8::cast_from(a);
+    ///
+    /// let decrypted: f64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -42.0);
+    /// ```
+    fn cast_from(input: FheInt<FromId>) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted: crate::integer::SignedRadixCiphertext = algorithms::from_integer(
+                    cpu_key.pbs_key(),
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                    IntoId::num_fractional_bits(),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point numbers");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheFixed<FromId>> for FheInt<IntoId>
+where
+    FromId: FheFixedId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheFixed to a FheInt
+    ///
+    /// The fractional bits are discarded, which rounds towards negative infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::encrypt(-42.75f64, &client_key);
+    /// let b = FheInt16::cast_from(a);
+    ///
+    /// let decrypted: i16 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -43);
+    /// ```
+    fn cast_from(input: FheFixed<FromId>) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted: crate::integer::SignedRadixCiphertext = algorithms::to_integer(
+                    cpu_key.pbs_key(),
+                    &*input.ciphertext.on_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                    FromId::num_fractional_bits(),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point numbers");
+            }
+        })
+    }
+}
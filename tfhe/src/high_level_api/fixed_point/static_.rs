@@ -0,0 +1,149 @@
+use super::{FheFixed, FheFixedId, FheUFixed, FheUFixedId, FixedPointId};
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::traits::FheId;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::NotVersioned;
+
+macro_rules! static_fixed_point_type {
+    (
+        $(#[$outer:meta])*
+        Unsigned {
+            num_integer_bits: $num_integer_bits:literal,
+            num_fractional_bits: $num_fractional_bits:literal,
+        }
+    ) => {
+        ::paste::paste! {
+            #[doc = concat!(
+                "Id for the [FheUFixedU", stringify!($num_integer_bits),
+                "F", stringify!($num_fractional_bits), "] data type."
+            )]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, NotVersioned)]
+            pub struct [<FheUFixedU $num_integer_bits F $num_fractional_bits Id>];
+
+            impl IntegerId for [<FheUFixedU $num_integer_bits F $num_fractional_bits Id>] {
+                fn num_bits() -> usize {
+                    $num_integer_bits + $num_fractional_bits
+                }
+            }
+
+            impl FixedPointId for [<FheUFixedU $num_integer_bits F $num_fractional_bits Id>] {
+                fn num_fractional_bits() -> usize {
+                    $num_fractional_bits
+                }
+            }
+
+            impl FheId for [<FheUFixedU $num_integer_bits F $num_fractional_bits Id>] { }
+
+            impl FheUFixedId for [<FheUFixedU $num_integer_bits F $num_fractional_bits Id>] { }
+
+            #[doc = concat!(
+                "An unsigned fixed-point type with ", stringify!($num_integer_bits),
+                " integer bits and ", stringify!($num_fractional_bits), " fractional bits"
+            )]
+            #[doc = ""]
+            #[doc = "See [FheUFixed]"]
+            $(#[$outer])*
+            pub type [<FheUFixedU $num_integer_bits F $num_fractional_bits>] =
+                FheUFixed<[<FheUFixedU $num_integer_bits F $num_fractional_bits Id>]>;
+        }
+    };
+    (
+        $(#[$outer:meta])*
+        Signed {
+            num_integer_bits: $num_integer_bits:literal,
+            num_fractional_bits: $num_fractional_bits:literal,
+        }
+    ) => {
+        ::paste::paste! {
+            #[doc = concat!(
+                "Id for the [FheFixedI", stringify!($num_integer_bits),
+                "F", stringify!($num_fractional_bits), "] data type."
+            )]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, NotVersioned)]
+            pub struct [<FheFixedI $num_integer_bits F $num_fractional_bits Id>];
+
+            impl IntegerId for [<FheFixedI $num_integer_bits F $num_fractional_bits Id>] {
+                fn num_bits() -> usize {
+                    $num_integer_bits + $num_fractional_bits
+                }
+            }
+
+            impl FixedPointId for [<FheFixedI $num_integer_bits F $num_fractional_bits Id>] {
+                fn num_fractional_bits() -> usize {
+                    $num_fractional_bits
+                }
+            }
+
+            impl FheId for [<FheFixedI $num_integer_bits F $num_fractional_bits Id>] { }
+
+            impl FheFixedId for [<FheFixedI $num_integer_bits F $num_fractional_bits Id>] { }
+
+            #[doc = concat!(
+                "A signed fixed-point type with ", stringify!($num_integer_bits),
+                " integer bits (including the sign bit) and ",
+                stringify!($num_fractional_bits), " fractional bits"
+            )]
+            #[doc = ""]
+            #[doc = "See [FheFixed]"]
+            $(#[$outer])*
+            pub type [<FheFixedI $num_integer_bits F $num_fractional_bits>] =
+                FheFixed<[<FheFixedI $num_integer_bits F $num_fractional_bits Id>]>;
+        }
+    };
+}
+
+static_fixed_point_type! {
+    Unsigned {
+        num_integer_bits: 4,
+        num_fractional_bits: 4,
+    }
+}
+
+static_fixed_point_type! {
+    Unsigned {
+        num_integer_bits: 8,
+        num_fractional_bits: 8,
+    }
+}
+
+static_fixed_point_type! {
+    Unsigned {
+        num_integer_bits: 16,
+        num_fractional_bits: 16,
+    }
+}
+
+static_fixed_point_type! {
+    Unsigned {
+        num_integer_bits: 32,
+        num_fractional_bits: 32,
+    }
+}
+
+static_fixed_point_type! {
+    Signed {
+        num_integer_bits: 4,
+        num_fractional_bits: 4,
+    }
+}
+
+static_fixed_point_type! {
+    Signed {
+        num_integer_bits: 8,
+        num_fractional_bits: 8,
+    }
+}
+
+static_fixed_point_type! {
+    Signed {
+        num_integer_bits: 16,
+        num_fractional_bits: 16,
+    }
+}
+
+static_fixed_point_type! {
+    Signed {
+        num_integer_bits: 32,
+        num_fractional_bits: 32,
+    }
+}
@@ -0,0 +1,255 @@
+use crate::high_level_api::tests::setup_default_cpu;
+use crate::prelude::*;
+use crate::shortint::parameters::{
+    COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+};
+use crate::{
+    set_server_key, ClientKey, CompactCiphertextList, CompactPublicKey, CompressedCiphertextList,
+    CompressedCiphertextListBuilder, CompressedServerKey, ConfigBuilder, FheFixedI4F4,
+    FheFixedI4F4Id, FheInt8, FheUFixedU4F4, FheUFixedU4F4Id, FheUint8, RoundingMode,
+};
+
+#[test]
+fn test_ufixed_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let a = FheUFixedU4F4::encrypt(3.25f64, &client_key);
+    let b = FheUFixedU4F4::encrypt(1.5f64, &client_key);
+
+    let decrypted: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, 4.75);
+
+    let decrypted: f64 = (&a - &b).decrypt(&client_key);
+    assert_eq!(decrypted, 1.75);
+
+    // 1.5 - 3.25 wraps around
+    let decrypted: f64 = (&b - &a).decrypt(&client_key);
+    assert_eq!(decrypted, 16.0 - 1.75);
+
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, 4.875);
+
+    // 3.25 / 1.5 = 2.1666..., truncated to 2.125
+    let decrypted: f64 = (&a / &b).decrypt(&client_key);
+    assert_eq!(decrypted, 2.125);
+
+    let decrypted: f64 = a.scalar_div(0.5).unwrap().decrypt(&client_key);
+    assert_eq!(decrypted, 6.5);
+    assert!(a.scalar_div(0.0).is_err());
+    assert!(a.scalar_div(0.01).is_err());
+
+    let mut c = a;
+    c += &b;
+    c *= &b;
+    let decrypted: f64 = c.decrypt(&client_key);
+    // 4.75 * 1.5 = 7.125
+    assert_eq!(decrypted, 7.125);
+}
+
+#[test]
+fn test_ufixed_mul_rounding() {
+    let client_key = setup_default_cpu();
+
+    // 0.1875 * 0.5 = 0.09375 = 1.5 / 16
+    let a = FheUFixedU4F4::encrypt(0.1875f64, &client_key);
+    let b = FheUFixedU4F4::encrypt(0.5f64, &client_key);
+
+    let floor: f64 = a
+        .mul_with_rounding(&b, RoundingMode::Floor)
+        .decrypt(&client_key);
+    assert_eq!(floor, 1.0 / 16.0);
+
+    let nearest: f64 = a
+        .mul_with_rounding(&b, RoundingMode::Nearest)
+        .decrypt(&client_key);
+    assert_eq!(nearest, 2.0 / 16.0);
+}
+
+#[test]
+fn test_ufixed_comparisons_and_casts() {
+    let client_key = setup_default_cpu();
+
+    let a = FheUFixedU4F4::encrypt(3.25f64, &client_key);
+    let b = FheUFixedU4F4::encrypt(3.5f64, &client_key);
+
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(a.le(&b).decrypt(&client_key));
+    assert!(!a.gt(&b).decrypt(&client_key));
+    assert!(!a.ge(&b).decrypt(&client_key));
+    assert!(!a.eq(&b).decrypt(&client_key));
+    assert!(a.ne(&b).decrypt(&client_key));
+
+    let decrypted: f64 = a.min(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 3.25);
+    let decrypted: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 3.5);
+
+    let integer = FheUint8::cast_from(b);
+    let decrypted: u8 = integer.decrypt(&client_key);
+    assert_eq!(decrypted, 3);
+
+    let fixed = FheUFixedU4F4::cast_from(integer);
+    let decrypted: f64 = fixed.decrypt(&client_key);
+    assert_eq!(decrypted, 3.0);
+
+    assert!(FheUFixedU4F4::try_encrypt(16.0f64, &client_key).is_err());
+    assert!(FheUFixedU4F4::try_encrypt(-1.0f64, &client_key).is_err());
+    assert!(FheUFixedU4F4::try_encrypt(f64::NAN, &client_key).is_err());
+
+    // Values are rounded to the nearest representable number
+    let a = FheUFixedU4F4::encrypt(0.05f64, &client_key);
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 1.0 / 16.0);
+}
+
+#[test]
+fn test_fixed_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let a = FheFixedI4F4::encrypt(-3.25f64, &client_key);
+    let b = FheFixedI4F4::encrypt(1.5f64, &client_key);
+
+    let decrypted: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, -1.75);
+
+    let decrypted: f64 = (&a - &b).decrypt(&client_key);
+    assert_eq!(decrypted, -4.75);
+
+    // -4.875 is exact
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, -4.875);
+
+    // -3.25 / 1.5 = -2.1666..., truncated to -2.125
+    let decrypted: f64 = (&a / &b).decrypt(&client_key);
+    assert_eq!(decrypted, -2.125);
+
+    let decrypted: f64 = a.scalar_div(-0.5).unwrap().decrypt(&client_key);
+    assert_eq!(decrypted, 6.5);
+
+    let decrypted: f64 = (-&a).decrypt(&client_key);
+    assert_eq!(decrypted, 3.25);
+
+    // -0.1875 * 0.5 = -0.09375 = -1.5 / 16
+    let a = FheFixedI4F4::encrypt(-0.1875f64, &client_key);
+    let b = FheFixedI4F4::encrypt(0.5f64, &client_key);
+    let floor: f64 = a
+        .mul_with_rounding(&b, RoundingMode::Floor)
+        .decrypt(&client_key);
+    assert_eq!(floor, -2.0 / 16.0);
+    let nearest: f64 = a
+        .mul_with_rounding(&b, RoundingMode::Nearest)
+        .decrypt(&client_key);
+    assert_eq!(nearest, -1.0 / 16.0);
+}
+
+#[test]
+fn test_fixed_comparisons_and_casts() {
+    let client_key = setup_default_cpu();
+
+    let a = FheFixedI4F4::encrypt(-3.25f64, &client_key);
+    let b = FheFixedI4F4::encrypt(0.5f64, &client_key);
+
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(!a.gt(&b).decrypt(&client_key));
+    assert!(a.eq(&a).decrypt(&client_key));
+
+    let decrypted: f64 = a.min(&b).decrypt(&client_key);
+    assert_eq!(decrypted, -3.25);
+    let decrypted: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 0.5);
+
+    // Conversion to integer rounds towards negative infinity
+    let integer = FheInt8::cast_from(a);
+    let decrypted: i8 = integer.decrypt(&client_key);
+    assert_eq!(decrypted, -4);
+
+    let fixed = FheFixedI4F4::cast_from(integer);
+    let decrypted: f64 = fixed.decrypt(&client_key);
+    assert_eq!(decrypted, -4.0);
+
+    assert!(FheFixedI4F4::try_encrypt(8.0f64, &client_key).is_err());
+    assert!(FheFixedI4F4::try_encrypt(-8.0f64, &client_key).is_ok());
+    assert!(FheFixedI4F4::try_encrypt(-8.1f64, &client_key).is_err());
+}
+
+#[test]
+fn test_fixed_point_compressed_list() {
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+    let client_key = ClientKey::generate(config);
+    set_server_key(CompressedServerKey::new(&client_key).decompress());
+
+    let a = FheUFixedU4F4::encrypt(3.25f64, &client_key);
+    let b = FheFixedI4F4::encrypt(-1.75f64, &client_key);
+    let c = FheUint8::encrypt(52u8, &client_key);
+    let d = FheInt8::encrypt(-28i8, &client_key);
+
+    let compressed_list = CompressedCiphertextListBuilder::new()
+        .push(a)
+        .push(b)
+        .push(c)
+        .push(d)
+        .build()
+        .unwrap();
+
+    let serialized = bincode::serialize(&compressed_list).unwrap();
+    let compressed_list: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+    let a: FheUFixedU4F4 = compressed_list.get(0).unwrap().unwrap();
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 3.25);
+
+    let b: FheFixedI4F4 = compressed_list.get(1).unwrap().unwrap();
+    let decrypted: f64 = b.decrypt(&client_key);
+    assert_eq!(decrypted, -1.75);
+
+    assert!(compressed_list.get::<FheFixedI4F4>(0).is_err());
+    assert!(compressed_list.get::<FheUFixedU4F4>(1).is_err());
+
+    // Integers of the same size are not fixed-point numbers, and the other way around
+    assert!(compressed_list.get::<FheUint8>(0).is_err());
+    assert!(compressed_list.get::<FheInt8>(1).is_err());
+    assert!(compressed_list.get::<FheUFixedU4F4>(2).is_err());
+    assert!(compressed_list.get::<FheFixedI4F4>(3).is_err());
+}
+
+#[test]
+fn test_fixed_point_compact_list() {
+    let client_key = setup_default_cpu();
+    let pk = CompactPublicKey::new(&client_key);
+
+    let compact_list = CompactCiphertextList::builder(&pk)
+        .push_ufixed::<FheUFixedU4F4Id>(3.25)
+        .unwrap()
+        .push_fixed::<FheFixedI4F4Id>(-1.75)
+        .unwrap()
+        .push(52u8)
+        .push(-28i8)
+        .build_packed();
+
+    assert!(CompactCiphertextList::builder(&pk)
+        .push_ufixed::<FheUFixedU4F4Id>(-1.0)
+        .is_err());
+
+    let expander = compact_list.expand().unwrap();
+
+    let a: FheUFixedU4F4 = expander.get(0).unwrap().unwrap();
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 3.25);
+
+    let b: FheFixedI4F4 = expander.get(1).unwrap().unwrap();
+    let decrypted: f64 = b.decrypt(&client_key);
+    assert_eq!(decrypted, -1.75);
+
+    assert!(expander.get::<FheUint8>(0).is_err());
+    assert!(expander.get::<FheInt8>(1).is_err());
+    assert!(expander.get::<FheUFixedU4F4>(2).is_err());
+    assert!(expander.get::<FheFixedI4F4>(3).is_err());
+
+    let c: FheUint8 = expander.get(2).unwrap().unwrap();
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 52);
+}
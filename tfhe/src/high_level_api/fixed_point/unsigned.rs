@@ -0,0 +1,215 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use tfhe_versionable::Versionize;
+
+use super::{algorithms, quantize_unsigned, FheUFixedId, RoundingMode};
+use crate::backward_compatibility::fixed_point::FheUFixedVersions;
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::unsigned::RadixCiphertext;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTryEncrypt, FheTryTrivialEncrypt, Tagged,
+};
+use crate::high_level_api::Device;
+use crate::named::Named;
+use crate::{ClientKey, FheBool, FheUint, Tag};
+
+/// A generic FHE unsigned fixed-point number
+///
+/// The Id controls the number of integer bits and fractional bits,
+/// you will need to use one of this type specialization (e.g. [FheUFixedU8F8],
+/// [FheUFixedU16F16]).
+///
+/// Arithmetic operations wrap around on overflow, like [FheUint].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUFixedU8F8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUFixedU8F8::encrypt(1.5f64, &client_key);
+/// let b = FheUFixedU8F8::encrypt(2.25f64, &client_key);
+///
+/// let c = &a * &b;
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 3.375);
+/// ```
+///
+/// [FheUFixedU8F8]: crate::FheUFixedU8F8
+/// [FheUFixedU16F16]: crate::FheUFixedU16F16
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(FheUFixedVersions)]
+pub struct FheUFixed<Id: FheUFixedId> {
+    pub(in crate::high_level_api) ciphertext: RadixCiphertext,
+    pub(in crate::high_level_api) id: Id,
+    pub(crate) tag: Tag,
+}
+
+impl_fixed_point_common!(
+    type: FheUFixed,
+    id_trait: FheUFixedId,
+    name: "high_level_api::FheUFixed",
+    hl_radix: RadixCiphertext,
+    radix: crate::integer::RadixCiphertext,
+    clear: u64,
+    quantize: quantize_unsigned,
+    encrypt: encrypt_radix,
+    decrypt: decrypt_radix,
+    example: FheUFixedU8F8,
+    division_by_zero: "dividing by zero gives the maximum value of the type",
+);
+
+impl<Id: FheUFixedId> FheUFixed<Id> {
+    /// Divides by a clear value
+    ///
+    /// The divisor is first converted to the fixed-point representation of this type,
+    /// the quotient is truncated towards zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the divisor cannot be represented by this type or is zero
+    /// once converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUFixedU8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUFixedU8F8::encrypt(7.5f64, &client_key);
+    ///
+    /// let c = a.scalar_div(2.5).unwrap();
+    /// let decrypted: f64 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3.0);
+    ///
+    /// assert!(a.scalar_div(0.0).is_err());
+    /// ```
+    pub fn scalar_div(&self, divisor: f64) -> crate::Result<Self> {
+        let divisor = quantize_unsigned(divisor, Id::num_bits(), Id::num_fractional_bits())?;
+        if divisor == 0 {
+            return Err(crate::error!("Fixed-point division by zero"));
+        }
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let num_fractional_bits = Id::num_fractional_bits();
+                let ct = self.ciphertext.on_cpu();
+                let num_blocks = ct.blocks.len();
+                let target_num_blocks =
+                    algorithms::num_blocks_for_division(sks, num_blocks, num_fractional_bits);
+
+                let numerator = algorithms::division_numerator(
+                    sks,
+                    &*ct,
+                    target_num_blocks,
+                    num_fractional_bits,
+                );
+                let quotient = sks.scalar_div_parallelized(&numerator, u128::from(divisor));
+                let result: crate::integer::RadixCiphertext =
+                    algorithms::cast(sks, quotient, num_blocks);
+                Ok(Self::new(result, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point division");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheUint<FromId>> for FheUFixed<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheUFixedId,
+{
+    /// Cast a FheUint to a FheUFixed
+    ///
+    /// Integer bits that do not fit wrap around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUFixedU8F8, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(42u16, &client_key);
+    /// let b = FheUFixedU8F8::cast_from(a);
+    ///
+    /// let decrypted: f64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42.0);
+    /// ```
+    fn cast_from(input: FheUint<FromId>) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted: crate::integer::RadixCiphertext = algorithms::from_integer(
+                    cpu_key.pbs_key(),
+                    input.ciphertext.into_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                    IntoId::num_fractional_bits(),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point numbers");
+            }
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheUFixed<FromId>> for FheUint<IntoId>
+where
+    FromId: FheUFixedId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheUFixed to a FheUint
+    ///
+    /// The fractional bits are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUFixedU8F8, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUFixedU8F8::encrypt(42.75f64, &client_key);
+    /// let b = FheUint16::cast_from(a);
+    ///
+    /// let decrypted: u16 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    fn cast_from(input: FheUFixed<FromId>) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casted: crate::integer::RadixCiphertext = algorithms::to_integer(
+                    cpu_key.pbs_key(),
+                    &*input.ciphertext.on_cpu(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                    FromId::num_fractional_bits(),
+                );
+                Self::new(casted, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support fixed-point numbers");
+            }
+        })
+    }
+}
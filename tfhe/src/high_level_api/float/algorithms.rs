@@ -0,0 +1,639 @@
+//! Floating-point arithmetic on sign / exponent / mantissa ciphertexts
+//!
+//! The mantissa stores the implicit leading bit explicitly, so a finite value is
+//! `(-1)^sign * mantissa * 2^(max(exponent, 1) - EXPONENT_BIAS - (MANTISSA_BITS - 1))`
+//! for normal and subnormal numbers alike.
+//!
+//! Every operation computes an exact (or sticky) intermediate mantissa, then goes
+//! through [normalize_round_pack] which renormalizes it, rounds it to nearest-even and
+//! handles subnormal results and overflows. Special inputs (NaN, infinities, zeros)
+//! are handled at the end by selecting constant results.
+
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+/// Number of bits of the stored exponent
+pub(super) const EXPONENT_BITS: usize = 8;
+/// Number of bits of the stored mantissa, including the implicit leading bit
+pub(super) const MANTISSA_BITS: usize = 24;
+
+const EXPONENT_BIAS: u64 = 127;
+const EXPONENT_MAX: u64 = (1 << EXPONENT_BITS) - 1;
+const IMPLICIT_BIT: u64 = 1 << (MANTISSA_BITS - 1);
+const QUIET_NAN_MANTISSA: u64 = IMPLICIT_BIT | (IMPLICIT_BIT >> 1);
+
+/// Number of bits used for intermediate exponent computations, large enough
+/// for the biased exponents of products and quotients to never wrap around
+const INTERMEDIATE_EXPONENT_BITS: usize = 12;
+
+/// Offsets applied to intermediate exponents so that they stay positive
+const MUL_EXPONENT_OFFSET: u64 = 2 * (EXPONENT_BIAS + MANTISSA_BITS as u64 - 1);
+const DIV_EXPONENT_OFFSET: u64 = 300;
+/// Number of bits the dividend is shifted by before the integer division,
+/// so that the quotient has more bits than the mantissa plus guard and sticky bits.
+const DIV_EXTRA_BITS: usize = MANTISSA_BITS + 2;
+/// Number of bits the mantissas are shifted by before being aligned for an addition
+const ADD_EXTRA_BITS: usize = MANTISSA_BITS + 3;
+
+pub(super) struct Float {
+    pub(super) sign: BooleanBlock,
+    pub(super) exponent: RadixCiphertext,
+    pub(super) mantissa: RadixCiphertext,
+}
+
+#[derive(Copy, Clone)]
+pub(super) struct FloatRef<'a> {
+    pub(super) sign: &'a BooleanBlock,
+    pub(super) exponent: &'a RadixCiphertext,
+    pub(super) mantissa: &'a RadixCiphertext,
+}
+
+/// Encrypted class of a float, each flag is exclusive of the others
+struct Classes {
+    zero: BooleanBlock,
+    inf: BooleanBlock,
+    nan: BooleanBlock,
+}
+
+pub(super) fn num_blocks(sks: &ServerKey, num_bits: usize) -> usize {
+    num_bits.div_ceil(sks.message_modulus().0.ilog2() as usize)
+}
+
+/// Splits the bits of a clear `f32` into its sign, exponent and mantissa
+/// (with the implicit bit made explicit)
+pub(super) fn decompose(value: f32) -> (bool, u64, u64) {
+    let bits = u64::from(value.to_bits());
+    let sign = (bits >> 31) == 1;
+    let exponent = (bits >> (MANTISSA_BITS - 1)) & EXPONENT_MAX;
+    let mut mantissa = bits & (IMPLICIT_BIT - 1);
+    if exponent != 0 {
+        mantissa |= IMPLICIT_BIT;
+    }
+    (sign, exponent, mantissa)
+}
+
+/// Inverse of [decompose]
+pub(super) fn recompose(sign: bool, exponent: u64, mantissa: u64) -> f32 {
+    let bits = (u64::from(sign) << 31)
+        | ((exponent & EXPONENT_MAX) << (MANTISSA_BITS - 1))
+        | (mantissa & (IMPLICIT_BIT - 1));
+    f32::from_bits(bits as u32)
+}
+
+fn trivial(sks: &ServerKey, value: u64, num_blocks: usize) -> RadixCiphertext {
+    sks.create_trivial_radix(value, num_blocks)
+}
+
+fn cast(sks: &ServerKey, ct: RadixCiphertext, num_blocks: usize) -> RadixCiphertext {
+    sks.cast_to_unsigned(ct, num_blocks)
+}
+
+fn not(sks: &ServerKey, b: &BooleanBlock) -> BooleanBlock {
+    sks.boolean_bitnot(b)
+}
+
+fn and(sks: &ServerKey, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+    sks.boolean_bitand(a, b)
+}
+
+fn or(sks: &ServerKey, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+    sks.boolean_bitor(a, b)
+}
+
+fn xor(sks: &ServerKey, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+    sks.boolean_bitxor(a, b)
+}
+
+/// Returns `if condition { a } else { b }`
+fn select_bool(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    a: &BooleanBlock,
+    b: &BooleanBlock,
+) -> BooleanBlock {
+    let diff = xor(sks, a, b);
+    xor(sks, b, &and(sks, condition, &diff))
+}
+
+fn classify(sks: &ServerKey, x: FloatRef<'_>) -> Classes {
+    let ((is_max_exponent, is_implicit_bit_only), is_zero) = rayon::join(
+        || {
+            rayon::join(
+                || sks.scalar_eq_parallelized(x.exponent, EXPONENT_MAX),
+                || sks.scalar_eq_parallelized(x.mantissa, IMPLICIT_BIT),
+            )
+        },
+        || sks.scalar_eq_parallelized(x.mantissa, 0u64),
+    );
+    let (is_inf, is_nan) = rayon::join(
+        || and(sks, &is_max_exponent, &is_implicit_bit_only),
+        || and(sks, &is_max_exponent, &not(sks, &is_implicit_bit_only)),
+    );
+    Classes {
+        zero: is_zero,
+        inf: is_inf,
+        nan: is_nan,
+    }
+}
+
+/// Returns the exponent as used in computations, i.e. `max(exponent, 1)`,
+/// on [INTERMEDIATE_EXPONENT_BITS] bits
+fn effective_exponent(sks: &ServerKey, exponent: &RadixCiphertext) -> RadixCiphertext {
+    let exponent = cast(
+        sks,
+        exponent.clone(),
+        num_blocks(sks, INTERMEDIATE_EXPONENT_BITS),
+    );
+    sks.scalar_max_parallelized(&exponent, 1u64)
+}
+
+/// Returns `exponent * 2^MANTISSA_BITS + mantissa`, which orders values by their
+/// absolute value
+fn magnitude(sks: &ServerKey, x: FloatRef<'_>) -> RadixCiphertext {
+    let num_blocks = num_blocks(sks, EXPONENT_BITS + MANTISSA_BITS);
+    let (exponent, mantissa) = rayon::join(
+        || {
+            let exponent = cast(sks, x.exponent.clone(), num_blocks);
+            sks.scalar_left_shift_parallelized(&exponent, MANTISSA_BITS as u64)
+        },
+        || cast(sks, x.mantissa.clone(), num_blocks),
+    );
+    sks.add_parallelized(&exponent, &mantissa)
+}
+
+/// Replaces the exponent and mantissa of `result` by the given constants where
+/// `condition` is true
+fn override_with(
+    sks: &ServerKey,
+    result: Float,
+    condition: &BooleanBlock,
+    exponent: u64,
+    mantissa: u64,
+) -> Float {
+    let (new_exponent, new_mantissa) = rayon::join(
+        || {
+            let constant = trivial(sks, exponent, result.exponent.blocks.len());
+            sks.cmux_parallelized(condition, &constant, &result.exponent)
+        },
+        || {
+            let constant = trivial(sks, mantissa, result.mantissa.blocks.len());
+            sks.cmux_parallelized(condition, &constant, &result.mantissa)
+        },
+    );
+    Float {
+        sign: result.sign,
+        exponent: new_exponent,
+        mantissa: new_mantissa,
+    }
+}
+
+/// Shifts `ct` to the right, setting the least significant bit of the result
+/// if any of the bits shifted out was set
+fn sticky_right_shift(
+    sks: &ServerKey,
+    ct: &RadixCiphertext,
+    shift: &RadixCiphertext,
+) -> RadixCiphertext {
+    let shifted = sks.right_shift_parallelized(ct, shift);
+    let restored = sks.left_shift_parallelized(&shifted, shift);
+    let lost_bits = sks.ne_parallelized(&restored, ct);
+    let lost_bits = lost_bits.into_radix(shifted.blocks.len(), sks);
+    sks.bitor_parallelized(&shifted, &lost_bits)
+}
+
+/// Rounds the intermediate result `mantissa * 2^(exponent - exponent_offset)`
+/// to the nearest float, ties to even
+///
+/// - `mantissa` can have any number of bits, as long as there are at least two more than
+///   [MANTISSA_BITS] (for the guard and sticky bits) and at most 64
+/// - `exponent` is on [INTERMEDIATE_EXPONENT_BITS] bits
+fn normalize_round_pack(
+    sks: &ServerKey,
+    sign: BooleanBlock,
+    mantissa: &RadixCiphertext,
+    exponent: &RadixCiphertext,
+    exponent_offset: u64,
+) -> Float {
+    let bits_per_block = sks.message_modulus().0.ilog2() as usize;
+    let num_bits = mantissa.blocks.len() * bits_per_block;
+    assert!(
+        (MANTISSA_BITS + 2..=64).contains(&num_bits),
+        "Internal error: invalid intermediate mantissa size"
+    );
+    let exponent_blocks = exponent.blocks.len();
+    // Once the mantissa is normalized, the biased exponent of the result is
+    // `exponent + num_bits - leading_zeros - subnormal_threshold`
+    let subnormal_threshold = exponent_offset - (EXPONENT_BIAS - 1);
+
+    // Normalize so that the most significant bit is set (unless the mantissa is 0)
+    let leading_zeros = cast(
+        sks,
+        sks.leading_zeros_parallelized(mantissa),
+        exponent_blocks,
+    );
+    let (normalized, exponent) = rayon::join(
+        || sks.left_shift_parallelized(mantissa, &leading_zeros),
+        || {
+            let exponent = sks.scalar_add_parallelized(exponent, num_bits as u64);
+            sks.sub_parallelized(&exponent, &leading_zeros)
+        },
+    );
+
+    // Results too small to be normal are shifted right so that their exponent is 1
+    let is_subnormal = sks.scalar_le_parallelized(&exponent, subnormal_threshold);
+    let (shift, exponent) = rayon::join(
+        || {
+            let shift = sks.neg_parallelized(&exponent);
+            let shift = sks.scalar_add_parallelized(&shift, subnormal_threshold + 1);
+            let shift = sks.scalar_min_parallelized(&shift, num_bits as u64 - 1);
+            sks.cmux_parallelized(&is_subnormal, &shift, &trivial(sks, 0, exponent_blocks))
+        },
+        || {
+            let exponent = sks.scalar_sub_parallelized(&exponent, subnormal_threshold);
+            sks.cmux_parallelized(&is_subnormal, &trivial(sks, 1, exponent_blocks), &exponent)
+        },
+    );
+    let shifted = sticky_right_shift(sks, &normalized, &shift);
+
+    // Round to nearest, ties to even
+    let num_dropped_bits = num_bits - MANTISSA_BITS;
+    let guard_bit = 1u64 << (num_dropped_bits - 1);
+    let rounded_blocks = num_blocks(sks, MANTISSA_BITS + 1);
+    let (mut rounded, (is_guard_bit_set, is_sticky_bit_set)) = rayon::join(
+        || {
+            let rounded = sks.scalar_right_shift_parallelized(&shifted, num_dropped_bits as u64);
+            cast(sks, rounded, rounded_blocks)
+        },
+        || {
+            rayon::join(
+                || {
+                    let guard = sks.scalar_bitand_parallelized(&shifted, guard_bit);
+                    sks.scalar_ne_parallelized(&guard, 0u64)
+                },
+                || {
+                    let sticky = sks.scalar_bitand_parallelized(&shifted, guard_bit - 1);
+                    sks.scalar_ne_parallelized(&sticky, 0u64)
+                },
+            )
+        },
+    );
+    let lsb = sks.scalar_bitand_parallelized(&rounded, 1u64);
+    let is_lsb_set = sks.scalar_ne_parallelized(&lsb, 0u64);
+    let round_up = and(
+        sks,
+        &is_guard_bit_set,
+        &or(sks, &is_sticky_bit_set, &is_lsb_set),
+    );
+    rounded = sks.add_parallelized(&rounded, &round_up.into_radix(rounded_blocks, sks));
+
+    // Rounding may carry into a new bit
+    let has_carry = sks.scalar_ge_parallelized(&rounded, 1u64 << MANTISSA_BITS);
+    let (rounded, exponent) = rayon::join(
+        || {
+            sks.cmux_parallelized(
+                &has_carry,
+                &trivial(sks, IMPLICIT_BIT, rounded_blocks),
+                &rounded,
+            )
+        },
+        || {
+            sks.add_parallelized(
+                &exponent,
+                &has_carry.clone().into_radix(exponent_blocks, sks),
+            )
+        },
+    );
+
+    let (is_normal, has_overflowed) = rayon::join(
+        || sks.scalar_ge_parallelized(&rounded, IMPLICIT_BIT),
+        || sks.scalar_ge_parallelized(&exponent, EXPONENT_MAX),
+    );
+    let exponent = sks.cmux_parallelized(&is_normal, &exponent, &trivial(sks, 0, exponent_blocks));
+
+    let result = Float {
+        sign,
+        exponent: cast(sks, exponent, num_blocks(sks, EXPONENT_BITS)),
+        mantissa: cast(sks, rounded, num_blocks(sks, MANTISSA_BITS)),
+    };
+    override_with(sks, result, &has_overflowed, EXPONENT_MAX, IMPLICIT_BIT)
+}
+
+pub(super) fn neg(sks: &ServerKey, x: FloatRef<'_>) -> Float {
+    Float {
+        sign: not(sks, x.sign),
+        exponent: x.exponent.clone(),
+        mantissa: x.mantissa.clone(),
+    }
+}
+
+pub(super) fn add(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> Float {
+    let ((lhs_classes, rhs_classes), (lhs_magnitude, rhs_magnitude)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || rayon::join(|| magnitude(sks, lhs), || magnitude(sks, rhs)),
+    );
+
+    // Order operands so that |big| >= |small|
+    let swap = sks.lt_parallelized(&lhs_magnitude, &rhs_magnitude);
+    let select = |a: &RadixCiphertext, b: &RadixCiphertext| sks.cmux_parallelized(&swap, b, a);
+    let ((big_exponent, small_exponent), (big_mantissa, small_mantissa)) = rayon::join(
+        || {
+            rayon::join(
+                || select(lhs.exponent, rhs.exponent),
+                || select(rhs.exponent, lhs.exponent),
+            )
+        },
+        || {
+            rayon::join(
+                || select(lhs.mantissa, rhs.mantissa),
+                || select(rhs.mantissa, lhs.mantissa),
+            )
+        },
+    );
+    let (big_sign, is_effective_sub) = rayon::join(
+        || select_bool(sks, &swap, rhs.sign, lhs.sign),
+        || xor(sks, lhs.sign, rhs.sign),
+    );
+
+    let (big_exponent, small_exponent) = rayon::join(
+        || effective_exponent(sks, &big_exponent),
+        || effective_exponent(sks, &small_exponent),
+    );
+
+    // Align the small mantissa on the big one
+    let wide_blocks = num_blocks(sks, 2 * MANTISSA_BITS + 4);
+    let wide_bits = wide_blocks * sks.message_modulus().0.ilog2() as usize;
+    let (big_mantissa, small_mantissa) = rayon::join(
+        || {
+            let m = cast(sks, big_mantissa, wide_blocks);
+            sks.scalar_left_shift_parallelized(&m, ADD_EXTRA_BITS as u64)
+        },
+        || {
+            let m = cast(sks, small_mantissa, wide_blocks);
+            sks.scalar_left_shift_parallelized(&m, ADD_EXTRA_BITS as u64)
+        },
+    );
+    let shift = sks.sub_parallelized(&big_exponent, &small_exponent);
+    let shift = sks.scalar_min_parallelized(&shift, wide_bits as u64 - 1);
+    let small_mantissa = sticky_right_shift(sks, &small_mantissa, &shift);
+
+    let (sum, difference) = rayon::join(
+        || sks.add_parallelized(&big_mantissa, &small_mantissa),
+        || sks.sub_parallelized(&big_mantissa, &small_mantissa),
+    );
+    let mantissa = sks.cmux_parallelized(&is_effective_sub, &difference, &sum);
+
+    // An exact cancellation gives +0
+    let is_zero = sks.scalar_eq_parallelized(&mantissa, 0u64);
+    let is_positive_zero = and(sks, &is_effective_sub, &is_zero);
+    let sign = and(sks, &big_sign, &not(sks, &is_positive_zero));
+
+    let result = normalize_round_pack(
+        sks,
+        sign,
+        &mantissa,
+        &big_exponent,
+        EXPONENT_BIAS + (MANTISSA_BITS as u64 - 1) + ADD_EXTRA_BITS as u64,
+    );
+
+    let is_inf = or(sks, &lhs_classes.inf, &rhs_classes.inf);
+    let is_nan = or(
+        sks,
+        &or(sks, &lhs_classes.nan, &rhs_classes.nan),
+        &and(
+            sks,
+            &and(sks, &lhs_classes.inf, &rhs_classes.inf),
+            &is_effective_sub,
+        ),
+    );
+    // When there is an infinity, it is the operand with the biggest magnitude,
+    // so the result already has its sign
+    let result = override_with(sks, result, &is_inf, EXPONENT_MAX, IMPLICIT_BIT);
+    override_with(sks, result, &is_nan, EXPONENT_MAX, QUIET_NAN_MANTISSA)
+}
+
+pub(super) fn sub(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> Float {
+    let rhs_sign = not(sks, rhs.sign);
+    add(
+        sks,
+        lhs,
+        FloatRef {
+            sign: &rhs_sign,
+            ..rhs
+        },
+    )
+}
+
+pub(super) fn mul(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> Float {
+    let ((lhs_classes, rhs_classes), (sign, (exponent, mantissa))) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            rayon::join(
+                || xor(sks, lhs.sign, rhs.sign),
+                || {
+                    rayon::join(
+                        || {
+                            let (lhs_exponent, rhs_exponent) = rayon::join(
+                                || effective_exponent(sks, lhs.exponent),
+                                || effective_exponent(sks, rhs.exponent),
+                            );
+                            sks.add_parallelized(&lhs_exponent, &rhs_exponent)
+                        },
+                        || {
+                            let wide_blocks = num_blocks(sks, 2 * MANTISSA_BITS);
+                            let (lhs_mantissa, rhs_mantissa) = rayon::join(
+                                || cast(sks, lhs.mantissa.clone(), wide_blocks),
+                                || cast(sks, rhs.mantissa.clone(), wide_blocks),
+                            );
+                            sks.mul_parallelized(&lhs_mantissa, &rhs_mantissa)
+                        },
+                    )
+                },
+            )
+        },
+    );
+
+    let result = normalize_round_pack(sks, sign, &mantissa, &exponent, MUL_EXPONENT_OFFSET);
+
+    let is_inf = or(sks, &lhs_classes.inf, &rhs_classes.inf);
+    let is_nan = or(
+        sks,
+        &or(sks, &lhs_classes.nan, &rhs_classes.nan),
+        &or(
+            sks,
+            &and(sks, &lhs_classes.inf, &rhs_classes.zero),
+            &and(sks, &lhs_classes.zero, &rhs_classes.inf),
+        ),
+    );
+    let result = override_with(sks, result, &is_inf, EXPONENT_MAX, IMPLICIT_BIT);
+    override_with(sks, result, &is_nan, EXPONENT_MAX, QUIET_NAN_MANTISSA)
+}
+
+pub(super) fn div(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> Float {
+    let exponent_blocks = num_blocks(sks, INTERMEDIATE_EXPONENT_BITS);
+
+    // Subnormal mantissas are normalized, so that the quotient always has enough bits
+    let normalize = |x: FloatRef<'_>| {
+        let leading_zeros = cast(
+            sks,
+            sks.leading_zeros_parallelized(x.mantissa),
+            exponent_blocks,
+        );
+        let mantissa = sks.left_shift_parallelized(x.mantissa, &leading_zeros);
+        (mantissa, leading_zeros)
+    };
+
+    let ((lhs_classes, rhs_classes), (sign, ((lhs_normalized, rhs_normalized), exponents))) =
+        rayon::join(
+            || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+            || {
+                rayon::join(
+                    || xor(sks, lhs.sign, rhs.sign),
+                    || {
+                        rayon::join(
+                            || rayon::join(|| normalize(lhs), || normalize(rhs)),
+                            || {
+                                rayon::join(
+                                    || effective_exponent(sks, lhs.exponent),
+                                    || effective_exponent(sks, rhs.exponent),
+                                )
+                            },
+                        )
+                    },
+                )
+            },
+        );
+    let (lhs_mantissa, lhs_leading_zeros) = lhs_normalized;
+    let (rhs_mantissa, rhs_leading_zeros) = rhs_normalized;
+    let (lhs_exponent, rhs_exponent) = exponents;
+
+    // exponent = lhs_exponent - lhs_leading_zeros - (rhs_exponent - rhs_leading_zeros)
+    //          + DIV_EXPONENT_OFFSET
+    let exponent = rayon::join(
+        || sks.add_parallelized(&lhs_exponent, &rhs_leading_zeros),
+        || sks.add_parallelized(&rhs_exponent, &lhs_leading_zeros),
+    );
+    let exponent = sks.sub_parallelized(&exponent.0, &exponent.1);
+    let exponent = sks.scalar_add_parallelized(&exponent, DIV_EXPONENT_OFFSET);
+
+    let mantissa_bits = lhs_mantissa.blocks.len() * sks.message_modulus().0.ilog2() as usize;
+    let wide_blocks = num_blocks(sks, mantissa_bits + DIV_EXTRA_BITS);
+    let (numerator, divisor) = rayon::join(
+        || {
+            let numerator = cast(sks, lhs_mantissa, wide_blocks);
+            sks.scalar_left_shift_parallelized(&numerator, DIV_EXTRA_BITS as u64)
+        },
+        || cast(sks, rhs_mantissa, wide_blocks),
+    );
+    let (quotient, remainder) = sks.div_rem_parallelized(&numerator, &divisor);
+
+    // The quotient is in ]2^(DIV_EXTRA_BITS - 1), 2^(DIV_EXTRA_BITS + 1)[,
+    // the remainder is folded in the sticky bit
+    let quotient = cast(sks, quotient, num_blocks(sks, DIV_EXTRA_BITS + 2));
+    let is_inexact = sks.scalar_ne_parallelized(&remainder, 0u64);
+    let quotient = sks.bitor_parallelized(
+        &quotient,
+        &is_inexact.into_radix(quotient.blocks.len(), sks),
+    );
+
+    let result = normalize_round_pack(
+        sks,
+        sign,
+        &quotient,
+        &exponent,
+        DIV_EXPONENT_OFFSET + DIV_EXTRA_BITS as u64,
+    );
+
+    let is_zero = or(sks, &lhs_classes.zero, &rhs_classes.inf);
+    let is_inf = or(sks, &lhs_classes.inf, &rhs_classes.zero);
+    let is_nan = or(
+        sks,
+        &or(sks, &lhs_classes.nan, &rhs_classes.nan),
+        &or(
+            sks,
+            &and(sks, &lhs_classes.zero, &rhs_classes.zero),
+            &and(sks, &lhs_classes.inf, &rhs_classes.inf),
+        ),
+    );
+    let result = override_with(sks, result, &is_zero, 0, 0);
+    let result = override_with(sks, result, &is_inf, EXPONENT_MAX, IMPLICIT_BIT);
+    override_with(sks, result, &is_nan, EXPONENT_MAX, QUIET_NAN_MANTISSA)
+}
+
+pub(super) fn is_nan(sks: &ServerKey, x: FloatRef<'_>) -> BooleanBlock {
+    classify(sks, x).nan
+}
+
+/// Returns `lhs == rhs`, with the IEEE 754 semantic
+/// (`+0 == -0` and NaN is not equal to anything)
+pub(super) fn eq(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> BooleanBlock {
+    let ((lhs_classes, rhs_classes), (is_same_magnitude, is_same_sign)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            rayon::join(
+                || {
+                    let (lhs_magnitude, rhs_magnitude) =
+                        rayon::join(|| magnitude(sks, lhs), || magnitude(sks, rhs));
+                    sks.eq_parallelized(&lhs_magnitude, &rhs_magnitude)
+                },
+                || not(sks, &xor(sks, lhs.sign, rhs.sign)),
+            )
+        },
+    );
+
+    let are_both_zero = and(sks, &lhs_classes.zero, &rhs_classes.zero);
+    let any_nan = or(sks, &lhs_classes.nan, &rhs_classes.nan);
+    let is_eq = or(
+        sks,
+        &and(sks, &is_same_magnitude, &is_same_sign),
+        &are_both_zero,
+    );
+    and(sks, &is_eq, &not(sks, &any_nan))
+}
+
+/// Returns `lhs < rhs`, with the IEEE 754 semantic
+/// (`-0 < +0` is false and comparisons with NaN are false)
+pub(super) fn lt(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> BooleanBlock {
+    let ((lhs_classes, rhs_classes), (is_magnitude_lt, is_magnitude_gt)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            let (lhs_magnitude, rhs_magnitude) =
+                rayon::join(|| magnitude(sks, lhs), || magnitude(sks, rhs));
+            rayon::join(
+                || sks.lt_parallelized(&lhs_magnitude, &rhs_magnitude),
+                || sks.gt_parallelized(&lhs_magnitude, &rhs_magnitude),
+            )
+        },
+    );
+
+    let lhs_positive = not(sks, lhs.sign);
+    let rhs_positive = not(sks, rhs.sign);
+    let are_both_zero = and(sks, &lhs_classes.zero, &rhs_classes.zero);
+
+    // both positive: |lhs| < |rhs|
+    let both_positive = and(
+        sks,
+        &and(sks, &lhs_positive, &rhs_positive),
+        &is_magnitude_lt,
+    );
+    // lhs negative, rhs positive: true unless both are zeros
+    let negative_positive = and(
+        sks,
+        &and(sks, lhs.sign, &rhs_positive),
+        &not(sks, &are_both_zero),
+    );
+    // both negative: |lhs| > |rhs|
+    let both_negative = and(sks, &and(sks, lhs.sign, rhs.sign), &is_magnitude_gt);
+
+    let is_lt = or(
+        sks,
+        &or(sks, &both_positive, &negative_positive),
+        &both_negative,
+    );
+    let any_nan = or(sks, &lhs_classes.nan, &rhs_classes.nan);
+    and(sks, &is_lt, &not(sks, &any_nan))
+}
+
+/// Returns `lhs <= rhs`, with the IEEE 754 semantic
+pub(super) fn le(sks: &ServerKey, lhs: FloatRef<'_>, rhs: FloatRef<'_>) -> BooleanBlock {
+    let (is_lt, is_eq) = rayon::join(|| lt(sks, lhs, rhs), || eq(sks, lhs, rhs));
+    or(sks, &is_lt, &is_eq)
+}
@@ -0,0 +1,402 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use tfhe_versionable::Versionize;
+
+use super::algorithms::{self, Float, FloatRef, EXPONENT_BITS, MANTISSA_BITS};
+use crate::backward_compatibility::float::FheFloat32Versions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheOrd, FheTryEncrypt, FheTryTrivialEncrypt, Tagged,
+};
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::named::Named;
+use crate::{ClientKey, FheBool, Tag};
+
+/// An FHE single precision floating-point number
+///
+/// The number is encrypted as its sign, its biased exponent (8 bits) and its mantissa
+/// (24 bits, the implicit leading bit is stored), and follows the IEEE 754 `binary32`
+/// semantic: results are rounded to nearest, ties to even, and subnormal numbers,
+/// signed zeros, infinities and NaN are supported.
+///
+/// NaN payloads are not preserved, operations producing a NaN return a quiet NaN.
+///
+/// This type is only supported on CPU.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFloat32::encrypt(1.5f32, &client_key);
+/// let b = FheFloat32::encrypt(-2.25f32, &client_key);
+///
+/// let c = &a * &b;
+/// let decrypted: f32 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, -3.375);
+/// ```
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(FheFloat32Versions)]
+pub struct FheFloat32 {
+    pub(in crate::high_level_api) sign: BooleanBlock,
+    pub(in crate::high_level_api) exponent: RadixCiphertext,
+    pub(in crate::high_level_api) mantissa: RadixCiphertext,
+    pub(crate) tag: Tag,
+}
+
+impl Named for FheFloat32 {
+    const NAME: &'static str = "high_level_api::FheFloat32";
+}
+
+impl Tagged for FheFloat32 {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl FheFloat32 {
+    fn from_float(float: Float, tag: Tag) -> Self {
+        let Float {
+            sign,
+            exponent,
+            mantissa,
+        } = float;
+        Self {
+            sign,
+            exponent,
+            mantissa,
+            tag,
+        }
+    }
+
+    fn as_float_ref(&self) -> FloatRef<'_> {
+        FloatRef {
+            sign: &self.sign,
+            exponent: &self.exponent,
+            mantissa: &self.mantissa,
+        }
+    }
+
+    /// Returns the sign, the biased exponent and the mantissa
+    /// (with its implicit leading bit) ciphertexts
+    pub fn into_raw_parts(self) -> (BooleanBlock, RadixCiphertext, RadixCiphertext, Tag) {
+        let Self {
+            sign,
+            exponent,
+            mantissa,
+            tag,
+        } = self;
+        (sign, exponent, mantissa, tag)
+    }
+
+    /// Creates a FheFloat32 from its parts
+    ///
+    /// The mantissa must have its implicit leading bit set for normal numbers,
+    /// infinities and NaN (i.e. when the exponent is not 0), the result of
+    /// operations on invalid inputs is not specified.
+    pub fn from_raw_parts(
+        sign: BooleanBlock,
+        exponent: RadixCiphertext,
+        mantissa: RadixCiphertext,
+        tag: Tag,
+    ) -> Self {
+        Self {
+            sign,
+            exponent,
+            mantissa,
+            tag,
+        }
+    }
+
+    /// Returns a FheBool that encrypts `true` if the value is NaN
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(f32::INFINITY, &client_key);
+    /// let b = FheFloat32::encrypt(0.0f32, &client_key);
+    ///
+    /// assert!(!a.is_nan().decrypt(&client_key));
+    /// assert!((&a * &b).is_nan().decrypt(&client_key));
+    /// ```
+    pub fn is_nan(&self) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = algorithms::is_nan(cpu_key.pbs_key(), self.as_float_ref());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support floating-point numbers");
+            }
+        })
+    }
+}
+
+impl FheTryEncrypt<f32, ClientKey> for FheFloat32 {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: f32, key: &ClientKey) -> Result<Self, Self::Error> {
+        let (sign, exponent, mantissa) = algorithms::decompose(value);
+        let bits_per_block = key.message_modulus().0.ilog2() as usize;
+        let integer_key = &key.key.key;
+        Ok(Self {
+            sign: integer_key.encrypt_bool(sign),
+            exponent: integer_key.encrypt_radix(exponent, EXPONENT_BITS.div_ceil(bits_per_block)),
+            mantissa: integer_key.encrypt_radix(mantissa, MANTISSA_BITS.div_ceil(bits_per_block)),
+            tag: key.tag.clone(),
+        })
+    }
+}
+
+impl FheTryTrivialEncrypt<f32> for FheFloat32 {
+    type Error = crate::Error;
+
+    fn try_encrypt_trivial(value: f32) -> Result<Self, Self::Error> {
+        let (sign, exponent, mantissa) = algorithms::decompose(value);
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                Ok(Self {
+                    sign: sks.create_trivial_boolean_block(sign),
+                    exponent: sks
+                        .create_trivial_radix(exponent, algorithms::num_blocks(sks, EXPONENT_BITS)),
+                    mantissa: sks
+                        .create_trivial_radix(mantissa, algorithms::num_blocks(sks, MANTISSA_BITS)),
+                    tag: cpu_key.tag.clone(),
+                })
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support floating-point numbers");
+            }
+        })
+    }
+}
+
+impl FheDecrypt<f32> for FheFloat32 {
+    fn decrypt(&self, key: &ClientKey) -> f32 {
+        let integer_key = &key.key.key;
+        let sign = integer_key.decrypt_bool(&self.sign);
+        let exponent: u64 = integer_key.decrypt_radix(&self.exponent);
+        let mantissa: u64 = integer_key.decrypt_radix(&self.mantissa);
+        algorithms::recompose(sign, exponent, mantissa)
+    }
+}
+
+macro_rules! float_impl_operation (
+    (
+        $(#[$outer:meta])*
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        rust_trait_assign: $rust_trait_assign_name:ident($rust_trait_assign_method:ident),
+        implem: $implem:path
+        $(,)?
+    ) => {
+        impl<B> $rust_trait_name<B> for FheFloat32
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<B> $rust_trait_name<B> for &FheFloat32
+        where
+            B: Borrow<FheFloat32>,
+        {
+            type Output = FheFloat32;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                global_state::with_internal_keys(|key| match key {
+                    InternalServerKey::Cpu(cpu_key) => {
+                        let result = $implem(
+                            cpu_key.pbs_key(),
+                            self.as_float_ref(),
+                            rhs.borrow().as_float_ref(),
+                        );
+                        FheFloat32::from_float(result, cpu_key.tag.clone())
+                    }
+                    #[cfg(feature = "gpu")]
+                    InternalServerKey::Cuda(_) => {
+                        panic!("gpu does not support floating-point numbers");
+                    }
+                })
+            }
+        }
+
+        impl<B> $rust_trait_assign_name<B> for FheFloat32
+        where
+            B: Borrow<Self>,
+        {
+            fn $rust_trait_assign_method(&mut self, rhs: B) {
+                *self = <&Self as $rust_trait_name<B>>::$rust_trait_method(&*self, rhs);
+            }
+        }
+    }
+);
+
+float_impl_operation!(
+    /// Adds two [FheFloat32]
+    ///
+    /// The result is rounded to nearest, ties to even.
+    rust_trait: Add(add),
+    rust_trait_assign: AddAssign(add_assign),
+    implem: algorithms::add,
+);
+
+float_impl_operation!(
+    /// Subtracts two [FheFloat32]
+    ///
+    /// The result is rounded to nearest, ties to even.
+    rust_trait: Sub(sub),
+    rust_trait_assign: SubAssign(sub_assign),
+    implem: algorithms::sub,
+);
+
+float_impl_operation!(
+    /// Multiplies two [FheFloat32]
+    ///
+    /// The result is rounded to nearest, ties to even.
+    rust_trait: Mul(mul),
+    rust_trait_assign: MulAssign(mul_assign),
+    implem: algorithms::mul,
+);
+
+float_impl_operation!(
+    /// Divides two [FheFloat32]
+    ///
+    /// The result is rounded to nearest, ties to even.
+    /// Dividing a non-zero number by zero gives an infinity, `0 / 0` gives NaN.
+    rust_trait: Div(div),
+    rust_trait_assign: DivAssign(div_assign),
+    implem: algorithms::div,
+);
+
+impl Neg for FheFloat32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl Neg for &FheFloat32 {
+    type Output = FheFloat32;
+
+    /// Flips the sign of a [FheFloat32]
+    fn neg(self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = algorithms::neg(cpu_key.pbs_key(), self.as_float_ref());
+                FheFloat32::from_float(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support floating-point numbers");
+            }
+        })
+    }
+}
+
+impl FheFloat32 {
+    fn compare(
+        &self,
+        rhs: &Self,
+        func: impl FnOnce(&crate::integer::ServerKey, FloatRef<'_>, FloatRef<'_>) -> BooleanBlock,
+    ) -> FheBool {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = func(cpu_key.pbs_key(), self.as_float_ref(), rhs.as_float_ref());
+                FheBool::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support floating-point numbers");
+            }
+        })
+    }
+}
+
+impl FheEq<&Self> for FheFloat32 {
+    /// Test for equality between two [FheFloat32]
+    ///
+    /// `+0` and `-0` are equal, NaN is not equal to any value, including itself.
+    fn eq(&self, rhs: &Self) -> FheBool {
+        self.compare(rhs, algorithms::eq)
+    }
+
+    /// Test for difference between two [FheFloat32]
+    ///
+    /// NaN is different from any value, including itself.
+    fn ne(&self, rhs: &Self) -> FheBool {
+        !self.eq(rhs)
+    }
+}
+
+impl FheOrd<&Self> for FheFloat32 {
+    /// Test for less than between two [FheFloat32]
+    ///
+    /// Comparisons involving NaN are always false.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(-1.25f32, &client_key);
+    /// let b = FheFloat32::encrypt(0.5f32, &client_key);
+    ///
+    /// let result = a.lt(&b);
+    ///
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    fn lt(&self, rhs: &Self) -> FheBool {
+        self.compare(rhs, algorithms::lt)
+    }
+
+    /// Test for less than or equal between two [FheFloat32]
+    ///
+    /// Comparisons involving NaN are always false.
+    fn le(&self, rhs: &Self) -> FheBool {
+        self.compare(rhs, algorithms::le)
+    }
+
+    /// Test for greater than between two [FheFloat32]
+    ///
+    /// Comparisons involving NaN are always false.
+    fn gt(&self, rhs: &Self) -> FheBool {
+        rhs.compare(self, algorithms::lt)
+    }
+
+    /// Test for greater than or equal between two [FheFloat32]
+    ///
+    /// Comparisons involving NaN are always false.
+    fn ge(&self, rhs: &Self) -> FheBool {
+        rhs.compare(self, algorithms::le)
+    }
+}
@@ -0,0 +1,13 @@
+//! Encrypted floating-point numbers
+//!
+//! [FheFloat32] follows the IEEE 754 `binary32` format, its sign, exponent
+//! and mantissa are each encrypted in their own ciphertext, and operations are
+//! implemented with the integer server key primitives (leading zeros count, shifts by
+//! encrypted amounts, cmux).
+pub use float32::FheFloat32;
+
+mod algorithms;
+mod float32;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,173 @@
+use crate::high_level_api::tests::setup_default_cpu;
+use crate::prelude::*;
+use crate::{ClientKey, FheFloat32};
+
+fn assert_same_float(actual: f32, expected: f32, context: &str) {
+    if expected.is_nan() {
+        assert!(actual.is_nan(), "{context}: expected NaN, got {actual}");
+    } else {
+        assert_eq!(
+            actual.to_bits(),
+            expected.to_bits(),
+            "{context}: expected {expected:e}, got {actual:e}"
+        );
+    }
+}
+
+fn check_binary_op(
+    client_key: &ClientKey,
+    cases: &[(f32, f32)],
+    name: &str,
+    clear_op: impl Fn(f32, f32) -> f32,
+    encrypted_op: impl Fn(&FheFloat32, &FheFloat32) -> FheFloat32,
+) {
+    for &(clear_a, clear_b) in cases {
+        let a = FheFloat32::encrypt(clear_a, client_key);
+        let b = FheFloat32::encrypt(clear_b, client_key);
+
+        let decrypted: f32 = encrypted_op(&a, &b).decrypt(client_key);
+        assert_same_float(
+            decrypted,
+            clear_op(clear_a, clear_b),
+            &format!("{clear_a:e} {name} {clear_b:e}"),
+        );
+    }
+}
+
+#[test]
+fn test_float32_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    for clear in [
+        0.0f32,
+        -0.0,
+        1.5,
+        -3.75e-20,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::from_bits(1),
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ] {
+        let encrypted = FheFloat32::encrypt(clear, &client_key);
+        let decrypted: f32 = encrypted.decrypt(&client_key);
+        assert_same_float(decrypted, clear, "encrypt/decrypt");
+    }
+}
+
+#[test]
+fn test_float32_add_sub() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (1.5f32, 2.25f32),
+        // Exact cancellation gives +0
+        (1.0, -1.0),
+        (-0.0, -0.0),
+        // Tie, rounded to even
+        (1.0, f32::EPSILON / 2.0),
+        (1.0 + f32::EPSILON, f32::EPSILON / 2.0),
+        // Subnormal operands
+        (f32::from_bits(3), f32::from_bits(5)),
+        (f32::MIN_POSITIVE, -f32::from_bits(1)),
+        // Overflow
+        (f32::MAX, f32::MAX),
+        (f32::INFINITY, -1.0),
+        (f32::INFINITY, f32::NEG_INFINITY),
+        (f32::NAN, 1.0),
+    ];
+    check_binary_op(&client_key, &cases, "+", |a, b| a + b, |a, b| a + b);
+
+    let cases = [(0.1f32, 0.3f32), (-2.5e10, 1.25e-3)];
+    check_binary_op(&client_key, &cases, "-", |a, b| a - b, |a, b| a - b);
+
+    let a = FheFloat32::encrypt(2.5f32, &client_key);
+    let decrypted: f32 = (-a).decrypt(&client_key);
+    assert_same_float(decrypted, -2.5, "neg");
+}
+
+#[test]
+fn test_float32_mul() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (1.5f32, -2.25f32),
+        (1.0 + f32::EPSILON, 1.0 + f32::EPSILON),
+        (3.0e-3, 7.1e4),
+        // Subnormal result
+        (1.0e-30, 1.0e-10),
+        (-0.0, 5.0),
+        (f32::MAX, 2.0),
+        (f32::INFINITY, 0.0),
+    ];
+    check_binary_op(&client_key, &cases, "*", |a, b| a * b, |a, b| a * b);
+}
+
+#[test]
+fn test_float32_div() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (1.0f32, 3.0f32),
+        (-7.5, 2.5),
+        // Subnormal operand
+        (f32::from_bits(7), 1.0e-3),
+        (1.0, 0.0),
+        (0.0, 0.0),
+    ];
+    check_binary_op(&client_key, &cases, "/", |a, b| a / b, |a, b| a / b);
+}
+
+#[test]
+fn test_float32_comparisons() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (-0.0f32, 0.0f32),
+        (-1.5, -2.0),
+        (1.0e-40, 1.0e-39),
+        (f32::NAN, 1.0),
+    ];
+    for (clear_a, clear_b) in cases {
+        let a = FheFloat32::encrypt(clear_a, &client_key);
+        let b = FheFloat32::encrypt(clear_b, &client_key);
+
+        let context = format!("{clear_a:e} vs {clear_b:e}");
+        assert_eq!(
+            a.eq(&b).decrypt(&client_key),
+            clear_a == clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.ne(&b).decrypt(&client_key),
+            clear_a != clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.lt(&b).decrypt(&client_key),
+            clear_a < clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.le(&b).decrypt(&client_key),
+            clear_a <= clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.gt(&b).decrypt(&client_key),
+            clear_a > clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.ge(&b).decrypt(&client_key),
+            clear_a >= clear_b,
+            "{context}"
+        );
+        assert_eq!(
+            a.is_nan().decrypt(&client_key),
+            clear_a.is_nan(),
+            "{context}"
+        );
+    }
+}
@@ -41,8 +41,8 @@ pub(in crate::high_level_api) use unsigned::{
 // These are pub-exported so that their doc can appear in generated rust docs
 use crate::high_level_api::traits::FheId;
 use crate::shortint::MessageModulus;
-pub use signed::{CompressedFheInt, FheInt};
-pub use unsigned::{CompressedFheUint, FheUint};
+pub use signed::{CompressedFheInt, FheInt, SquashedNoiseFheInt};
+pub use unsigned::{CompressedFheUint, FheUint, SquashedNoiseFheUint};
 
 pub mod oprf;
 pub(super) mod signed;
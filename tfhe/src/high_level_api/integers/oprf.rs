@@ -101,6 +101,111 @@ impl<Id: FheUintId> FheUint<Id> {
             }),
         })
     }
+
+    /// Generates an encrypted unsigned integer
+    /// taken uniformly in `[0, excluded_upper_bound[` using the given seed.
+    /// The encrypted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// Unless `excluded_upper_bound` is a power of two, the statistical distance of the
+    /// distribution to the uniform distribution is at most `2^-statistical_security_bits`.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let excluded_upper_bound = 100;
+    ///
+    /// let ct_res =
+    ///     FheUint8::generate_oblivious_pseudo_random_in_range(Seed(0), excluded_upper_bound, 40);
+    ///
+    /// let dec_result: u16 = ct_res.decrypt(&client_key);
+    /// assert!(dec_result < excluded_upper_bound as u16);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_in_range(
+        seed: Seed,
+        excluded_upper_bound: u64,
+        statistical_security_bits: u64,
+    ) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+                        seed,
+                        excluded_upper_bound,
+                        statistical_security_bits,
+                        Id::num_blocks(key.message_modulus()) as u64,
+                    );
+
+                Self::new(ct, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support generate_oblivious_pseudo_random_in_range yet");
+            }
+        })
+    }
+
+    /// Generates an encrypted unsigned integer
+    /// taken uniformly in `[0, excluded_upper_bound[` using the given seed,
+    /// where the bound is encrypted.
+    /// The encrypted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// If the bound encrypts 0, the result encrypts 0. The statistical distance of the
+    /// distribution to the uniform distribution is at most `2^-statistical_security_bits`.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::{FheDecrypt, FheEncrypt};
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let excluded_upper_bound = FheUint8::encrypt(100u8, &client_key);
+    ///
+    /// let ct_res = FheUint8::generate_oblivious_pseudo_random_in_encrypted_range(
+    ///     Seed(0),
+    ///     &excluded_upper_bound,
+    ///     40,
+    /// );
+    ///
+    /// let dec_result: u8 = ct_res.decrypt(&client_key);
+    /// assert!(dec_result < 100);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_in_encrypted_range(
+        seed: Seed,
+        excluded_upper_bound: &Self,
+        statistical_security_bits: u64,
+    ) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_unsigned_integer_in_encrypted_range(
+                        seed,
+                        &excluded_upper_bound.ciphertext.on_cpu(),
+                        statistical_security_bits,
+                    );
+
+                Self::new(ct, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!(
+                    "Cuda devices do not support generate_oblivious_pseudo_random_in_encrypted_range yet"
+                );
+            }
+        })
+    }
 }
 
 impl<Id: FheIntId> FheInt<Id> {
@@ -201,4 +306,102 @@ impl<Id: FheIntId> FheInt<Id> {
             }),
         })
     }
+
+    /// Generates an encrypted signed integer following the discrete Laplace distribution
+    /// centered on 0 of the given `scale`, using the given seed.
+    /// The encrypted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability of `x` is proportional to `exp(-|x| / scale)`, it is rounded to a multiple
+    /// of `2^-precision_bits` (at most 53). The cost grows linearly with the number of values that
+    /// can be generated, that is with `scale` and `precision_bits`.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let ct_res = FheInt8::generate_oblivious_pseudo_random_discrete_laplace(Seed(0), 2.0, 8);
+    ///
+    /// let dec_result: i8 = ct_res.decrypt(&client_key);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_discrete_laplace(
+        seed: Seed,
+        scale: f64,
+        precision_bits: u64,
+    ) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_discrete_laplace(
+                        seed,
+                        scale,
+                        precision_bits,
+                        Id::num_blocks(key.message_modulus()) as u64,
+                    );
+
+                Self::new(ct, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!(
+                    "Cuda devices do not support generate_oblivious_pseudo_random_discrete_laplace yet"
+                );
+            }
+        })
+    }
+
+    /// Generates an encrypted signed integer following the discrete Gaussian distribution
+    /// centered on 0 of the given `std_dev`, using the given seed.
+    /// The encrypted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability of `x` is proportional to `exp(-x^2 / (2 * std_dev^2))`, it is rounded to
+    /// a multiple of `2^-precision_bits` (at most 53). The cost grows linearly with the number of
+    /// values that can be generated, that is with `std_dev` and `precision_bits`.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let ct_res = FheInt8::generate_oblivious_pseudo_random_discrete_gaussian(Seed(0), 3.0, 8);
+    ///
+    /// let dec_result: i8 = ct_res.decrypt(&client_key);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_discrete_gaussian(
+        seed: Seed,
+        std_dev: f64,
+        precision_bits: u64,
+    ) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let ct = key
+                    .pbs_key()
+                    .par_generate_oblivious_pseudo_random_discrete_gaussian(
+                        seed,
+                        std_dev,
+                        precision_bits,
+                        Id::num_blocks(key.message_modulus()) as u64,
+                    );
+
+                Self::new(ct, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!(
+                    "Cuda devices do not support generate_oblivious_pseudo_random_discrete_gaussian yet"
+                );
+            }
+        })
+    }
 }
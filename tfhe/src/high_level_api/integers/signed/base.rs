@@ -626,6 +626,52 @@ where
         })
     }
 
+    /// Returns the integer square root of the number, rounded down.
+    ///
+    /// Also returns a boolean flag that is true if the result is valid (i.e self was >= 0)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(1000i16, &client_key);
+    ///
+    /// let (result, is_ok) = a.checked_isqrt();
+    ///
+    /// let is_ok = is_ok.decrypt(&client_key);
+    /// assert!(is_ok);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 31);
+    ///
+    /// let a = FheInt16::encrypt(-4i16, &client_key);
+    ///
+    /// let (_result, is_ok) = a.checked_isqrt();
+    /// assert!(!is_ok.decrypt(&client_key)); // result is meaningless
+    /// ```
+    pub fn checked_isqrt(&self) -> (Self, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, is_ok) = cpu_key
+                    .pbs_key()
+                    .checked_isqrt_parallelized(&self.ciphertext.on_cpu());
+                (
+                    Self::new(result, cpu_key.tag.clone()),
+                    FheBool::new(is_ok, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_isqrt yet");
+            }
+        })
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
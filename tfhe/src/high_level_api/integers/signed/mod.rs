@@ -6,6 +6,7 @@ mod inner;
 mod ops;
 mod overflowing_ops;
 mod scalar_ops;
+mod squashed_noise;
 mod static_;
 #[cfg(test)]
 mod tests;
@@ -14,6 +15,7 @@ pub use base::{FheInt, FheIntId};
 pub use compressed::CompressedFheInt;
 pub(in crate::high_level_api) use compressed::CompressedSignedRadixCiphertext;
 pub(in crate::high_level_api) use inner::{RadixCiphertext, RadixCiphertextVersionOwned};
+pub use squashed_noise::SquashedNoiseFheInt;
 
 expand_pub_use_fhe_type!(
     pub use static_{
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::integers::SquashedNoiseFheIntVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheIntId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{FheDecrypt, SquashNoise, Tagged};
+use crate::integer::ciphertext::SquashedNoiseSignedRadixCiphertext;
+use crate::integer::client_key::RecomposableSignedInteger;
+use crate::named::Named;
+use crate::{ClientKey, FheInt, Tag};
+
+/// A [FheInt] whose noise has been squashed
+///
+/// Squashing the noise makes the ciphertext use a bigger modulus
+/// with a much lower noise level, which makes it suitable for
+/// decryption protocols that require it (e.g. threshold decryption).
+///
+/// No homomorphic operations are possible on this type, it can only be decrypted.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::{
+///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+/// };
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+///
+/// let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheInt8::encrypt(-101i8, &client_key);
+/// let squashed = a.squash_noise().unwrap();
+///
+/// let decrypted: i8 = squashed.decrypt(&client_key);
+/// assert_eq!(decrypted, -101i8);
+/// ```
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheIntVersions)]
+pub struct SquashedNoiseFheInt {
+    pub(in crate::high_level_api) inner: SquashedNoiseSignedRadixCiphertext,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl Named for SquashedNoiseFheInt {
+    const NAME: &'static str = "high_level_api::SquashedNoiseFheInt";
+}
+
+impl SquashedNoiseFheInt {
+    pub fn from_raw_parts(inner: SquashedNoiseSignedRadixCiphertext, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseSignedRadixCiphertext, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    /// Returns the number of bits of the clear value
+    pub fn num_bits(&self) -> usize {
+        self.inner.original_block_count() * self.inner.message_modulus().0.ilog2() as usize
+    }
+}
+
+impl Tagged for SquashedNoiseFheInt {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl<Clear> FheDecrypt<Clear> for SquashedNoiseFheInt
+where
+    Clear: RecomposableSignedInteger,
+{
+    /// Decrypts the squashed ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [ClientKey] does not have a noise squashing private key,
+    /// see [ConfigBuilder::enable_noise_squashing](crate::ConfigBuilder::enable_noise_squashing)
+    fn decrypt(&self, key: &ClientKey) -> Clear {
+        key.key
+            .noise_squashing_private_key
+            .as_ref()
+            .expect("No noise squashing private key in ClientKey")
+            .decrypt_signed_radix(&self.inner)
+    }
+}
+
+impl<Id> SquashNoise for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = SquashedNoiseFheInt;
+
+    fn squash_noise(&self) -> crate::Result<Self::Output> {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(server_key) => {
+                let noise_squashing_key = server_key.key.noise_squashing_key()?;
+
+                Ok(SquashedNoiseFheInt {
+                    inner: noise_squashing_key.squash_signed_radix_ciphertext_noise(
+                        server_key.key.pbs_key(),
+                        &self.ciphertext.on_cpu(),
+                    )?,
+                    tag: server_key.tag.clone(),
+                })
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::error!(
+                "Cuda devices do not support noise squashing yet"
+            )),
+        })
+    }
+}
@@ -179,6 +179,12 @@ fn test_ilog2() {
     super::test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_checked_isqrt() {
+    let client_key = setup_default_cpu();
+    super::test_case_checked_isqrt(&client_key);
+}
+
 #[test]
 fn test_leading_trailing_zeros_ones() {
     let client_key = setup_default_cpu();
@@ -453,3 +453,24 @@ fn test_case_ilog2(cks: &ClientKey) {
         assert!(!is_ok);
     }
 }
+
+fn test_case_checked_isqrt(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for clear_a in [0i32, i32::MAX, rng.gen_range(1..=i32::MAX)] {
+        let a = FheInt32::try_encrypt(clear_a, cks).unwrap();
+
+        let (isqrt, is_ok) = a.checked_isqrt();
+        let isqrt: i32 = isqrt.decrypt(cks);
+        let is_ok = is_ok.decrypt(cks);
+        assert!(is_ok);
+        assert_eq!(isqrt, clear_a.isqrt());
+    }
+
+    for clear_a in [-1i32, i32::MIN] {
+        let a = FheInt32::try_encrypt(clear_a, cks).unwrap();
+
+        let (_isqrt, is_ok) = a.checked_isqrt();
+        let is_ok = is_ok.decrypt(cks);
+        assert!(!is_ok);
+    }
+}
@@ -746,6 +746,72 @@ where
         })
     }
 
+    /// Returns the integer square root of the number, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 31);
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .isqrt_parallelized(&self.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support isqrt yet");
+            }
+        })
+    }
+
+    /// Returns the high half of the full product of `self` and `rhs`,
+    /// that is `(self * rhs) >> Self::num_bits()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(50_000u16, &client_key);
+    /// let b = FheUint16::encrypt(3_000u16, &client_key);
+    ///
+    /// let result = a.mul_high(&b);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((50_000u32 * 3_000u32) >> 16) as u16);
+    /// ```
+    pub fn mul_high(&self, rhs: &Self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&self.ciphertext.on_cpu(), &rhs.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet");
+            }
+        })
+    }
+
     /// `match` an input value to an output value
     ///
     /// - Input values are not required to span all possible values that `self` could hold. And the
@@ -942,6 +1008,48 @@ where
     }
 }
 
+macro_rules! impl_widening_mul {
+    ($($id:ident => $wide_type:ident),* $(,)?) => {
+        $(
+            impl FheUint<super::$id> {
+                #[doc = concat!(
+                    "Returns the full product of `self` and `rhs` as a [",
+                    stringify!($wide_type),
+                    "](super::",
+                    stringify!($wide_type),
+                    "), so the result never overflows."
+                )]
+                ///
+                /// This is cheaper than casting both operands to the wider type
+                /// before multiplying them.
+                pub fn widening_mul(&self, rhs: &Self) -> super::$wide_type {
+                    global_state::with_internal_keys(|key| match key {
+                        InternalServerKey::Cpu(cpu_key) => {
+                            let result = cpu_key.pbs_key().widening_mul_parallelized(
+                                &self.ciphertext.on_cpu(),
+                                &rhs.ciphertext.on_cpu(),
+                            );
+                            super::$wide_type::new(result, cpu_key.tag.clone())
+                        }
+                        #[cfg(feature = "gpu")]
+                        InternalServerKey::Cuda(_) => {
+                            panic!("Cuda devices do not support widening_mul yet");
+                        }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_mul!(
+    FheUint8Id => FheUint16,
+    FheUint16Id => FheUint32,
+    FheUint32Id => FheUint64,
+    FheUint64Id => FheUint128,
+    FheUint128Id => FheUint256,
+);
+
 impl<Id> TryFrom<crate::integer::RadixCiphertext> for FheUint<Id>
 where
     Id: FheUintId,
@@ -18,6 +18,7 @@ expand_pub_use_fhe_type!(
 );
 
 pub use compressed::CompressedFheUint;
+pub use squashed_noise::SquashedNoiseFheUint;
 
 pub(in crate::high_level_api) use compressed::CompressedRadixCiphertext;
 pub(in crate::high_level_api) use inner::{RadixCiphertext, RadixCiphertextVersionOwned};
@@ -28,8 +29,10 @@ mod static_;
 
 mod encrypt;
 mod inner;
+mod modular_ops;
 mod ops;
 mod overflowing_ops;
 pub(crate) mod scalar_ops;
+mod squashed_noise;
 #[cfg(test)]
 pub(crate) mod tests;
@@ -0,0 +1,387 @@
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::server_key::{Reciprocable, ScalarMultiplier};
+use crate::prelude::{AddMod, MulMod, PowMod};
+use crate::FheUint;
+
+impl<Id> AddMod<Self, Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `(self + rhs) % modulus`
+    ///
+    /// Both `self` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(150u8, &client_key);
+    /// let modulus = FheUint8::encrypt(251u8, &client_key);
+    ///
+    /// let result = (&a).add_mod(&b, &modulus);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, 99);
+    /// ```
+    fn add_mod(self, rhs: Self, modulus: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().add_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
+                    &modulus.ciphertext.on_cpu(),
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support add_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id> AddMod<&Self, &Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Computes `(self + rhs) % modulus`
+    ///
+    /// See [AddMod] for `&FheUint`
+    fn add_mod(self, rhs: &Self, modulus: &Self) -> Self::Output {
+        <&Self as AddMod<&Self, &Self>>::add_mod(&self, rhs, modulus)
+    }
+}
+
+impl<Id, Clear> AddMod<Self, Clear> for &FheUint<Id>
+where
+    Id: FheUintId,
+    Clear: UnsignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = Clear>,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `(self + rhs) % modulus`, where the modulus is a clear value
+    ///
+    /// Both `self` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(150u8, &client_key);
+    ///
+    /// let result = (&a).add_mod(&b, 251u8);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, 99);
+    /// ```
+    fn add_mod(self, rhs: Self, modulus: Clear) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_add_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support add_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id, Clear> AddMod<&Self, Clear> for FheUint<Id>
+where
+    Id: FheUintId,
+    Clear: UnsignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = Clear>,
+{
+    type Output = Self;
+
+    /// Computes `(self + rhs) % modulus`, where the modulus is a clear value
+    ///
+    /// See [AddMod] for `&FheUint`
+    fn add_mod(self, rhs: &Self, modulus: Clear) -> Self::Output {
+        <&Self as AddMod<&Self, Clear>>::add_mod(&self, rhs, modulus)
+    }
+}
+
+impl<Id> MulMod<Self, Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `(self * rhs) % modulus`
+    ///
+    /// The product is computed without overflow, so `self` and `rhs` do not need
+    /// to be reduced.
+    ///
+    /// If the modulus is 0, the result is the wrapping product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(150u8, &client_key);
+    /// let modulus = FheUint8::encrypt(251u8, &client_key);
+    ///
+    /// let result = (&a).mul_mod(&b, &modulus);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result as u32, (200u32 * 150u32) % 251);
+    /// ```
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mul_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
+                    &modulus.ciphertext.on_cpu(),
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id> MulMod<&Self, &Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Computes `(self * rhs) % modulus`
+    ///
+    /// See [MulMod] for `&FheUint`
+    fn mul_mod(self, rhs: &Self, modulus: &Self) -> Self::Output {
+        <&Self as MulMod<&Self, &Self>>::mul_mod(&self, rhs, modulus)
+    }
+}
+
+impl<Id, Clear> MulMod<Self, Clear> for &FheUint<Id>
+where
+    Id: FheUintId,
+    Clear: Reciprocable,
+    Clear::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `(self * rhs) % modulus`, where the modulus is a clear value
+    ///
+    /// The product is computed without overflow, so `self` and `rhs` do not need
+    /// to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is 0, or if the clear type has less bits than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(150u8, &client_key);
+    ///
+    /// let result = (&a).mul_mod(&b, 251u8);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result as u32, (200u32 * 150u32) % 251);
+    /// ```
+    fn mul_mod(self, rhs: Self, modulus: Clear) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_mul_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id, Clear> MulMod<&Self, Clear> for FheUint<Id>
+where
+    Id: FheUintId,
+    Clear: Reciprocable,
+    Clear::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+{
+    type Output = Self;
+
+    /// Computes `(self * rhs) % modulus`, where the modulus is a clear value
+    ///
+    /// See [MulMod] for `&FheUint`
+    fn mul_mod(self, rhs: &Self, modulus: Clear) -> Self::Output {
+        <&Self as MulMod<&Self, Clear>>::mul_mod(&self, rhs, modulus)
+    }
+}
+
+impl<Id, ExponentId> PowMod<&FheUint<ExponentId>, Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `self.pow(exponent) % modulus`
+    ///
+    /// If the modulus is 0, the result is the wrapping power.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint4, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let base = FheUint8::encrypt(7u8, &client_key);
+    /// let exponent = FheUint4::encrypt(3u8, &client_key);
+    /// let modulus = FheUint8::encrypt(13u8, &client_key);
+    ///
+    /// let result = (&base).pow_mod(&exponent, &modulus);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, 5); // 343 % 13
+    /// ```
+    fn pow_mod(self, exponent: &FheUint<ExponentId>, modulus: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().pow_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &exponent.ciphertext.on_cpu(),
+                    &modulus.ciphertext.on_cpu(),
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id, ExponentId> PowMod<&FheUint<ExponentId>, &Self> for FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = Self;
+
+    /// Computes `self.pow(exponent) % modulus`
+    ///
+    /// See [PowMod] for `&FheUint`
+    fn pow_mod(self, exponent: &FheUint<ExponentId>, modulus: &Self) -> Self::Output {
+        <&Self as PowMod<&FheUint<ExponentId>, &Self>>::pow_mod(&self, exponent, modulus)
+    }
+}
+
+impl<Id, ExponentId, Clear> PowMod<&FheUint<ExponentId>, Clear> for &FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+    Clear: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    Clear::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `self.pow(exponent) % modulus`, where the modulus is a clear value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is 0, or if the clear type has less bits than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint4, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let base = FheUint8::encrypt(7u8, &client_key);
+    /// let exponent = FheUint4::encrypt(3u8, &client_key);
+    ///
+    /// let result = (&base).pow_mod(&exponent, 13u8);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, 5); // 343 % 13
+    /// ```
+    fn pow_mod(self, exponent: &FheUint<ExponentId>, modulus: Clear) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_pow_mod_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &exponent.ciphertext.on_cpu(),
+                    modulus,
+                );
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow_mod yet");
+            }
+        })
+    }
+}
+
+impl<Id, ExponentId, Clear> PowMod<&FheUint<ExponentId>, Clear> for FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+    Clear: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    Clear::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+{
+    type Output = Self;
+
+    /// Computes `self.pow(exponent) % modulus`, where the modulus is a clear value
+    ///
+    /// See [PowMod] for `&FheUint`
+    fn pow_mod(self, exponent: &FheUint<ExponentId>, modulus: Clear) -> Self::Output {
+        <&Self as PowMod<&FheUint<ExponentId>, Clear>>::pow_mod(&self, exponent, modulus)
+    }
+}
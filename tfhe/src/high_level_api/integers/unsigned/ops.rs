@@ -10,7 +10,7 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
+    DivRem, FheEq, FheMax, FheMin, FheOrd, Pow, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
 #[cfg(feature = "gpu")]
@@ -644,6 +644,115 @@ where
     }
 }
 
+impl<Id, ExponentId> Pow<&FheUint<ExponentId>> for &FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Raises a [FheUint] to the power of an encrypted exponent
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * The exponent can be of a different type than the base, the cost grows with the number of
+    ///   bits of the exponent, so smaller exponent types are faster.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint4};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint4::encrypt(9u8, &client_key);
+    ///
+    /// let result = (&a).pow(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16.wrapping_pow(9));
+    /// ```
+    fn pow(self, exponent: &FheUint<ExponentId>) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &exponent.ciphertext.on_cpu());
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id, ExponentId> Pow<&FheUint<ExponentId>> for FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: &FheUint<ExponentId>) -> Self::Output {
+        <&Self as Pow<&FheUint<ExponentId>>>::pow(&self, exponent)
+    }
+}
+
+impl<Id> Pow<u32> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Raises a [FheUint] to the power of a clear exponent
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let result = (&a).pow(9);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16.wrapping_pow(9));
+    /// ```
+    fn pow(self, exponent: u32) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), u64::from(exponent));
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id> Pow<u32> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: u32) -> Self::Output {
+        <&Self as Pow<u32>>::pow(&self, exponent)
+    }
+}
+
 // Ciphertext/Ciphertext operators
 macro_rules! generic_integer_impl_operation (
     (
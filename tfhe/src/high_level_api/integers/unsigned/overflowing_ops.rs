@@ -5,7 +5,7 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::prelude::{CastInto, OverflowingAdd, OverflowingMul, OverflowingSub};
+use crate::prelude::{CastInto, OverflowingAdd, OverflowingMul, OverflowingPow, OverflowingSub};
 use crate::{FheBool, FheUint};
 
 impl<Id> OverflowingAdd<Self> for &FheUint<Id>
@@ -509,3 +509,67 @@ where
         <&Self as OverflowingMul<&Self>>::overflowing_mul(&self, other)
     }
 }
+
+impl<Id, ExponentId> OverflowingPow<&FheUint<ExponentId>> for &FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Raises a [FheUint] to the power of an encrypted exponent and returns a boolean indicating
+    /// overflow.
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * On overflow the [FheBool] is true, otherwise false
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint4};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint4::encrypt(11u8, &client_key);
+    ///
+    /// let (result, overflowed) = (&a).overflowing_pow(&b);
+    /// let (expected_result, expected_overflowed) = 3u16.overflowing_pow(11);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, expected_result);
+    /// assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+    /// assert!(overflowed.decrypt(&client_key));
+    /// ```
+    fn overflowing_pow(self, exponent: &FheUint<ExponentId>) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_pow_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &exponent.ciphertext.on_cpu(),
+                );
+                (
+                    FheUint::new(result, cpu_key.tag.clone()),
+                    FheBool::new(overflow, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support overflowing_pow yet");
+            }
+        })
+    }
+}
+
+impl<Id, ExponentId> OverflowingPow<&FheUint<ExponentId>> for FheUint<Id>
+where
+    Id: FheUintId,
+    ExponentId: FheUintId,
+{
+    type Output = Self;
+
+    fn overflowing_pow(self, exponent: &FheUint<ExponentId>) -> (Self::Output, FheBool) {
+        <&Self as OverflowingPow<&FheUint<ExponentId>>>::overflowing_pow(&self, exponent)
+    }
+}
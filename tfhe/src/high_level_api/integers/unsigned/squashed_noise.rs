@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::integers::SquashedNoiseFheUintVersions;
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{FheDecrypt, SquashNoise, Tagged};
+use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::ciphertext::SquashedNoiseRadixCiphertext;
+use crate::named::Named;
+use crate::{ClientKey, FheUint, Tag};
+
+/// A [FheUint] whose noise has been squashed
+///
+/// Squashing the noise makes the ciphertext use a bigger modulus
+/// with a much lower noise level, which makes it suitable for
+/// decryption protocols that require it (e.g. threshold decryption).
+///
+/// No homomorphic operations are possible on this type, it can only be decrypted.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::{
+///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+/// };
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(201u8, &client_key);
+/// let squashed = a.squash_noise().unwrap();
+///
+/// let decrypted: u8 = squashed.decrypt(&client_key);
+/// assert_eq!(decrypted, 201u8);
+/// ```
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheUintVersions)]
+pub struct SquashedNoiseFheUint {
+    pub(in crate::high_level_api) inner: SquashedNoiseRadixCiphertext,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl Named for SquashedNoiseFheUint {
+    const NAME: &'static str = "high_level_api::SquashedNoiseFheUint";
+}
+
+impl SquashedNoiseFheUint {
+    pub fn from_raw_parts(inner: SquashedNoiseRadixCiphertext, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseRadixCiphertext, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    /// Returns the number of bits of the clear value
+    pub fn num_bits(&self) -> usize {
+        self.inner.original_block_count() * self.inner.message_modulus().0.ilog2() as usize
+    }
+}
+
+impl Tagged for SquashedNoiseFheUint {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl<Clear> FheDecrypt<Clear> for SquashedNoiseFheUint
+where
+    Clear: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    /// Decrypts the squashed ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [ClientKey] does not have a noise squashing private key,
+    /// see [ConfigBuilder::enable_noise_squashing](crate::ConfigBuilder::enable_noise_squashing)
+    fn decrypt(&self, key: &ClientKey) -> Clear {
+        key.key
+            .noise_squashing_private_key
+            .as_ref()
+            .expect("No noise squashing private key in ClientKey")
+            .decrypt_radix(&self.inner)
+    }
+}
+
+impl<Id> SquashNoise for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = SquashedNoiseFheUint;
+
+    fn squash_noise(&self) -> crate::Result<Self::Output> {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(server_key) => {
+                let noise_squashing_key = server_key.key.noise_squashing_key()?;
+
+                Ok(SquashedNoiseFheUint {
+                    inner: noise_squashing_key.squash_radix_ciphertext_noise(
+                        server_key.key.pbs_key(),
+                        &self.ciphertext.on_cpu(),
+                    )?,
+                    tag: server_key.tag.clone(),
+                })
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::error!(
+                "Cuda devices do not support noise squashing yet"
+            )),
+        })
+    }
+}
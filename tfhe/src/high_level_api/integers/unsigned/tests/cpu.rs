@@ -372,6 +372,30 @@ fn test_ilog2() {
     super::test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_isqrt() {
+    let client_key = setup_default_cpu();
+    super::test_case_isqrt(&client_key);
+}
+
+#[test]
+fn test_modular() {
+    let client_key = setup_default_cpu();
+    super::test_case_modular(&client_key);
+}
+
+#[test]
+fn test_widening_mul() {
+    let client_key = setup_default_cpu();
+    super::test_case_widening_mul(&client_key);
+}
+
+#[test]
+fn test_pow() {
+    let client_key = setup_default_cpu();
+    super::test_case_pow(&client_key);
+}
+
 #[test]
 fn test_is_even_is_odd() {
     let client_key = setup_default_cpu();
@@ -1,7 +1,7 @@
 use crate::high_level_api::traits::BitSlice;
 use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClientKey, FheUint256, FheUint32, FheUint64, FheUint8};
+use crate::{ClientKey, FheUint2, FheUint256, FheUint32, FheUint4, FheUint64, FheUint8};
 use rand::{thread_rng, Rng};
 
 mod cpu;
@@ -468,6 +468,89 @@ fn test_case_ilog2(cks: &ClientKey) {
     }
 }
 
+fn test_case_isqrt(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for clear_a in [0u32, u32::MAX, rng.gen(), rng.gen()] {
+        let a = FheUint32::try_encrypt(clear_a, cks).unwrap();
+
+        let isqrt: u32 = a.isqrt().decrypt(cks);
+        assert_eq!(isqrt, clear_a.isqrt());
+    }
+}
+
+fn test_case_modular(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+
+    let clear_modulus = rng.gen_range(1..=u8::MAX);
+    let clear_a = rng.gen_range(0..clear_modulus);
+    let clear_b = rng.gen_range(0..clear_modulus);
+
+    let a = FheUint8::encrypt(clear_a, cks);
+    let b = FheUint8::encrypt(clear_b, cks);
+    let modulus = FheUint8::encrypt(clear_modulus, cks);
+
+    let expected = ((clear_a as u32 + clear_b as u32) % clear_modulus as u32) as u8;
+    let result: u8 = (&a).add_mod(&b, &modulus).decrypt(cks);
+    assert_eq!(result, expected);
+    let result: u8 = (&a).add_mod(&b, clear_modulus).decrypt(cks);
+    assert_eq!(result, expected);
+
+    let expected = ((clear_a as u32 * clear_b as u32) % clear_modulus as u32) as u8;
+    let result: u8 = (&a).mul_mod(&b, &modulus).decrypt(cks);
+    assert_eq!(result, expected);
+    let result: u8 = (&a).mul_mod(&b, clear_modulus).decrypt(cks);
+    assert_eq!(result, expected);
+
+    // Small exponent type to keep the number of multiplications low
+    let clear_exponent = rng.gen_range(0..4u8);
+    let exponent = FheUint2::encrypt(clear_exponent, cks);
+    let expected = (0..clear_exponent).fold(1 % clear_modulus as u32, |acc, _| {
+        (acc * clear_a as u32) % clear_modulus as u32
+    }) as u8;
+    let result: u8 = (&a).pow_mod(&exponent, &modulus).decrypt(cks);
+    assert_eq!(result, expected);
+    let result: u8 = (&a).pow_mod(&exponent, clear_modulus).decrypt(cks);
+    assert_eq!(result, expected);
+}
+
+fn test_case_widening_mul(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for (clear_a, clear_b) in [(u32::MAX, u32::MAX), (rng.gen(), rng.gen())] {
+        let a = FheUint32::try_encrypt(clear_a, cks).unwrap();
+        let b = FheUint32::try_encrypt(clear_b, cks).unwrap();
+
+        let expected = u64::from(clear_a) * u64::from(clear_b);
+
+        let result: FheUint64 = a.widening_mul(&b);
+        let decrypted: u64 = result.decrypt(cks);
+        assert_eq!(decrypted, expected);
+
+        let decrypted: u32 = a.mul_high(&b).decrypt(cks);
+        assert_eq!(decrypted, (expected >> 32) as u32);
+    }
+}
+
+fn test_case_pow(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for (clear_base, clear_exponent) in [(3u8, 5u8), (2, 8), (rng.gen(), rng.gen_range(0..16))] {
+        let base = FheUint8::encrypt(clear_base, cks);
+        let exponent = FheUint4::encrypt(clear_exponent, cks);
+
+        let (expected, expected_overflowed) = clear_base.overflowing_pow(clear_exponent as u32);
+
+        let result: u8 = (&base).pow(&exponent).decrypt(cks);
+        assert_eq!(result, expected);
+
+        let result: u8 = (&base).pow(clear_exponent as u32).decrypt(cks);
+        assert_eq!(result, expected);
+
+        let (result, overflowed) = (&base).overflowing_pow(&exponent);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, expected);
+        assert_eq!(overflowed.decrypt(cks), expected_overflowed);
+    }
+}
+
 fn test_case_bitslice(cks: &ClientKey) {
     let mut rng = rand::thread_rng();
     for _ in 0..5 {
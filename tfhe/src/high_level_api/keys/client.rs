@@ -7,6 +7,7 @@ use crate::high_level_api::backward_compatibility::keys::ClientKeyVersions;
 use crate::high_level_api::config::Config;
 use crate::high_level_api::keys::{CompactPrivateKey, IntegerClientKey};
 use crate::integer::compression_keys::CompressionPrivateKeys;
+use crate::integer::noise_squashing::NoiseSquashingPrivateKey;
 use crate::named::Named;
 use crate::prelude::Tagged;
 use crate::shortint::MessageModulus;
@@ -105,6 +106,21 @@ impl ClientKey {
         }
     }
 
+    /// Returns the noise squashing private key, if the key was created with
+    /// noise squashing enabled.
+    pub fn noise_squashing_private_key(&self) -> Option<&NoiseSquashingPrivateKey> {
+        self.key.noise_squashing_private_key.as_ref()
+    }
+
+    /// Sets the noise squashing private key, replacing any existing one.
+    pub fn with_noise_squashing_private_key(
+        mut self,
+        noise_squashing_private_key: NoiseSquashingPrivateKey,
+    ) -> Self {
+        self.key.noise_squashing_private_key = Some(noise_squashing_private_key);
+        self
+    }
+
     /// Generates a new ServerKey
     ///
     /// The `ServerKey` generated is meant to be used to initialize the global state
@@ -6,15 +6,18 @@ use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, CompressionPrivateKeys,
     DecompressionKey,
 };
+use crate::integer::noise_squashing::{
+    CompressedNoiseSquashingKey, NoiseSquashingKey, NoiseSquashingPrivateKey,
+};
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
 use crate::shortint::key_switching_key::KeySwitchingKeyConformanceParams;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::parameters::{
-    CompactPublicKeyEncryptionParameters, ShortintKeySwitchingParameters,
+    CompactPublicKeyEncryptionParameters, NoiseSquashingParameters, ShortintKeySwitchingParameters,
 };
 use crate::shortint::{EncryptionKeyChoice, MessageModulus, PBSParameters};
-use crate::{Config, Error};
+use crate::{Config, Error, HashBackend};
 use serde::{Deserialize, Serialize};
 use tfhe_csprng::seeders::Seed;
 use tfhe_versionable::Versionize;
@@ -30,6 +33,8 @@ pub(crate) struct IntegerConfig {
         crate::shortint::parameters::ShortintKeySwitchingParameters,
     )>,
     pub(crate) compression_parameters: Option<CompressionParameters>,
+    pub(crate) noise_squashing_parameters: Option<NoiseSquashingParameters>,
+    pub(crate) hash_backend: HashBackend,
 }
 
 impl IntegerConfig {
@@ -44,6 +49,8 @@ impl IntegerConfig {
             block_parameters,
             dedicated_compact_public_key_parameters,
             compression_parameters: None,
+            noise_squashing_parameters: None,
+            hash_backend: HashBackend::default(),
         }
     }
 
@@ -51,6 +58,10 @@ impl IntegerConfig {
         self.compression_parameters = Some(compression_parameters);
     }
 
+    pub fn enable_noise_squashing(&mut self, noise_squashing_parameters: NoiseSquashingParameters) {
+        self.noise_squashing_parameters = Some(noise_squashing_parameters);
+    }
+
     pub fn public_key_encryption_parameters(
         &self,
     ) -> Result<crate::shortint::parameters::CompactPublicKeyEncryptionParameters, crate::Error>
@@ -76,6 +87,8 @@ impl Default for IntegerConfig {
             block_parameters: params,
             dedicated_compact_public_key_parameters: None,
             compression_parameters: None,
+            noise_squashing_parameters: None,
+            hash_backend: HashBackend::default(),
         }
     }
 }
@@ -91,6 +104,8 @@ pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) dedicated_compact_private_key: Option<CompactPrivateKey>,
     pub(crate) compression_key: Option<CompressionPrivateKeys>,
+    pub(crate) noise_squashing_private_key: Option<NoiseSquashingPrivateKey>,
+    pub(crate) hash_backend: HashBackend,
 }
 
 impl IntegerClientKey {
@@ -112,10 +127,17 @@ impl IntegerClientKey {
         let dedicated_compact_private_key = config
             .dedicated_compact_public_key_parameters
             .map(|p| (crate::integer::CompactPrivateKey::new(p.0), p.1));
+
+        let noise_squashing_private_key = config
+            .noise_squashing_parameters
+            .map(|params| key.new_noise_squashing_private_key(params));
+
         Self {
             key,
             dedicated_compact_private_key,
             compression_key,
+            noise_squashing_private_key,
+            hash_backend: config.hash_backend,
         }
     }
 
@@ -131,6 +153,8 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            noise_squashing_private_key: _,
+            hash_backend: _,
         } = self;
         (key, dedicated_compact_private_key, compression_key)
     }
@@ -170,6 +194,8 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            noise_squashing_private_key: None,
+            hash_backend: HashBackend::default(),
         }
     }
 
@@ -195,10 +221,16 @@ impl From<IntegerConfig> for IntegerClientKey {
             .compression_parameters
             .map(|params| key.new_compression_private_key(params));
 
+        let noise_squashing_private_key = config
+            .noise_squashing_parameters
+            .map(|params| key.new_noise_squashing_private_key(params));
+
         Self {
             key,
             dedicated_compact_private_key,
             compression_key,
+            noise_squashing_private_key,
+            hash_backend: config.hash_backend,
         }
     }
 }
@@ -215,6 +247,8 @@ pub struct IntegerServerKey {
         Option<crate::integer::key_switching_key::KeySwitchingKeyMaterial>,
     pub(crate) compression_key: Option<CompressionKey>,
     pub(crate) decompression_key: Option<DecompressionKey>,
+    pub(crate) noise_squashing_key: Option<NoiseSquashingKey>,
+    pub(crate) hash_backend: HashBackend,
 }
 
 impl IntegerServerKey {
@@ -246,11 +280,22 @@ impl IntegerServerKey {
 
                     build_helper.into()
                 });
+
+        let noise_squashing_key =
+            client_key
+                .noise_squashing_private_key
+                .as_ref()
+                .map(|noise_squashing_private_key| {
+                    cks.new_noise_squashing_key(noise_squashing_private_key)
+                });
+
         Self {
             key: base_integer_key,
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key,
+            hash_backend: client_key.hash_backend,
         }
     }
 
@@ -273,6 +318,17 @@ impl IntegerServerKey {
     pub(in crate::high_level_api) fn message_modulus(&self) -> MessageModulus {
         self.key.message_modulus()
     }
+
+    pub(in crate::high_level_api) fn noise_squashing_key(
+        &self,
+    ) -> crate::Result<&NoiseSquashingKey> {
+        self.noise_squashing_key.as_ref().ok_or_else(|| {
+            crate::error!(
+                "No noise squashing key found in ServerKey, \
+                did you call `enable_noise_squashing` when creating the Config?"
+            )
+        })
+    }
 }
 
 #[cfg(feature = "gpu")]
@@ -292,6 +348,8 @@ pub struct IntegerCompressedServerKey {
         Option<crate::integer::key_switching_key::CompressedKeySwitchingKeyMaterial>,
     pub(crate) compression_key: Option<CompressedCompressionKey>,
     pub(crate) decompression_key: Option<CompressedDecompressionKey>,
+    pub(crate) noise_squashing_key: Option<CompressedNoiseSquashingKey>,
+    pub(crate) hash_backend: HashBackend,
 }
 
 impl IntegerCompressedServerKey {
@@ -327,11 +385,21 @@ impl IntegerCompressedServerKey {
                     (Some(compression_keys), Some(decompression_keys))
                 });
 
+        let noise_squashing_key =
+            client_key
+                .noise_squashing_private_key
+                .as_ref()
+                .map(|noise_squashing_private_key| {
+                    cks.new_compressed_noise_squashing_key(noise_squashing_private_key)
+                });
+
         Self {
             key,
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key,
+            hash_backend: client_key.hash_backend,
         }
     }
 
@@ -364,6 +432,8 @@ impl IntegerCompressedServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key: None,
+            hash_backend: HashBackend::default(),
         }
     }
 
@@ -378,6 +448,11 @@ impl IntegerCompressedServerKey {
             .as_ref()
             .map(CompressedDecompressionKey::decompress);
 
+        let noise_squashing_key = self
+            .noise_squashing_key
+            .as_ref()
+            .map(CompressedNoiseSquashingKey::decompress);
+
         IntegerServerKey {
             key: self.key.decompress(),
             cpk_key_switching_key_material: self.cpk_key_switching_key_material.as_ref().map(
@@ -385,6 +460,8 @@ impl IntegerCompressedServerKey {
             ),
             compression_key,
             decompression_key,
+            noise_squashing_key,
+            hash_backend: self.hash_backend,
         }
     }
 }
@@ -473,6 +550,7 @@ pub struct IntegerServerKeyConformanceParams {
         ShortintKeySwitchingParameters,
     )>,
     pub compression_param: Option<CompressionParameters>,
+    pub noise_squashing_param: Option<NoiseSquashingParameters>,
 }
 
 impl From<Config> for IntegerServerKeyConformanceParams {
@@ -481,6 +559,7 @@ impl From<Config> for IntegerServerKeyConformanceParams {
             sk_param: value.inner.block_parameters,
             cpk_param: value.inner.dedicated_compact_public_key_parameters,
             compression_param: value.inner.compression_parameters,
+            noise_squashing_param: value.inner.noise_squashing_parameters,
         }
     }
 }
@@ -539,6 +618,8 @@ impl ParameterSetConformant for IntegerServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key,
+            hash_backend: _,
         } = self;
 
         let cpk_key_switching_key_material_is_ok = match (
@@ -570,9 +651,23 @@ impl ParameterSetConformant for IntegerServerKey {
             _ => return false,
         };
 
+        let noise_squashing_is_ok = match (
+            noise_squashing_key.as_ref(),
+            parameter_set.noise_squashing_param.as_ref(),
+        ) {
+            (None, None) => true,
+            (Some(noise_squashing_key), Some(noise_squashing_param)) => {
+                let noise_squashing_param = (parameter_set.sk_param, *noise_squashing_param).into();
+
+                noise_squashing_key.is_conformant(&noise_squashing_param)
+            }
+            _ => return false,
+        };
+
         key.is_conformant(&parameter_set.sk_param)
             && cpk_key_switching_key_material_is_ok
             && compression_is_ok
+            && noise_squashing_is_ok
     }
 }
 
@@ -585,6 +680,8 @@ impl ParameterSetConformant for IntegerCompressedServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key,
+            hash_backend: _,
         } = self;
 
         let cpk_key_switching_key_material_is_ok = match (
@@ -616,9 +713,23 @@ impl ParameterSetConformant for IntegerCompressedServerKey {
             _ => return false,
         };
 
+        let noise_squashing_is_ok = match (
+            noise_squashing_key.as_ref(),
+            parameter_set.noise_squashing_param.as_ref(),
+        ) {
+            (None, None) => true,
+            (Some(noise_squashing_key), Some(noise_squashing_param)) => {
+                let noise_squashing_param = (parameter_set.sk_param, *noise_squashing_param).into();
+
+                noise_squashing_key.is_conformant(&noise_squashing_param)
+            }
+            _ => return false,
+        };
+
         key.is_conformant(&parameter_set.sk_param)
             && cpk_key_switching_key_material_is_ok
             && compression_is_ok
+            && noise_squashing_is_ok
     }
 }
 
@@ -11,13 +11,14 @@ use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
 use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
 };
+use crate::integer::noise_squashing::{CompressedNoiseSquashingKey, NoiseSquashingKey};
 use crate::integer::parameters::IntegerCompactCiphertextListExpansionMode;
 use crate::named::Named;
 use crate::prelude::Tagged;
 use crate::shortint::MessageModulus;
 #[cfg(feature = "gpu")]
 use crate::GpuIndex;
-use crate::Tag;
+use crate::{HashBackend, Tag};
 use std::sync::Arc;
 
 /// Key of the server
@@ -60,6 +61,8 @@ impl ServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            noise_squashing_key: _,
+            hash_backend: _,
         } = (*self.key).clone();
 
         (
@@ -86,11 +89,28 @@ impl ServerKey {
                 cpk_key_switching_key_material,
                 compression_key,
                 decompression_key,
+                noise_squashing_key: None,
+                hash_backend: HashBackend::default(),
             }),
             tag,
         }
     }
 
+    /// Returns the noise squashing key, if the key was created with noise squashing enabled.
+    pub fn noise_squashing_key(&self) -> Option<&NoiseSquashingKey> {
+        self.key.noise_squashing_key.as_ref()
+    }
+
+    /// Sets the noise squashing key, replacing any existing one.
+    pub fn with_noise_squashing_key(self, noise_squashing_key: NoiseSquashingKey) -> Self {
+        let mut key = Arc::try_unwrap(self.key).unwrap_or_else(|key| (*key).clone());
+        key.noise_squashing_key = Some(noise_squashing_key);
+        Self {
+            key: Arc::new(key),
+            tag: self.tag,
+        }
+    }
+
     pub(in crate::high_level_api) fn pbs_key(&self) -> &crate::integer::ServerKey {
         self.key.pbs_key()
     }
@@ -98,6 +118,7 @@ impl ServerKey {
     #[cfg(feature = "strings")]
     pub(in crate::high_level_api) fn string_key(&self) -> crate::strings::ServerKeyRef<'_> {
         crate::strings::ServerKeyRef::new(self.key.pbs_key())
+            .with_hash_backend(self.key.hash_backend)
     }
 
     pub(in crate::high_level_api) fn cpk_casting_key(
@@ -252,6 +273,20 @@ impl CompressedServerKey {
         }
     }
 
+    /// Returns the noise squashing key, if the key was created with noise squashing enabled.
+    pub fn noise_squashing_key(&self) -> Option<&CompressedNoiseSquashingKey> {
+        self.integer_key.noise_squashing_key.as_ref()
+    }
+
+    /// Sets the noise squashing key, replacing any existing one.
+    pub fn with_noise_squashing_key(
+        mut self,
+        noise_squashing_key: CompressedNoiseSquashingKey,
+    ) -> Self {
+        self.integer_key.noise_squashing_key = Some(noise_squashing_key);
+        self
+    }
+
     pub fn decompress(&self) -> ServerKey {
         ServerKey {
             key: Arc::new(self.integer_key.decompress()),
@@ -432,6 +467,7 @@ mod test {
     use crate::prelude::ParameterSetConformant;
     use crate::shortint::parameters::{
         COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
         PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
         PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
         PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
@@ -456,6 +492,30 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: None,
+                noise_squashing_param: None,
+            };
+
+            assert!(sk.is_conformant(&conformance_params));
+        }
+        {
+            let config = ConfigBuilder::with_custom_parameters(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            )
+            .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+
+            let ck = ClientKey::generate(config);
+            let sk = ServerKey::new(&ck);
+
+            let sk_param = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+
+            let conformance_params = IntegerServerKeyConformanceParams {
+                sk_param,
+                cpk_param: None,
+                compression_param: None,
+                noise_squashing_param: Some(
+                    NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+                ),
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -476,6 +536,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: Some(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128),
+                noise_squashing_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -499,6 +560,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                noise_squashing_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -534,6 +596,7 @@ mod test {
                     sk_param,
                     cpk_param: None,
                     compression_param: None,
+                    noise_squashing_param: None,
                 };
 
                 assert!(!sk.is_conformant(&conformance_params));
@@ -560,6 +623,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                noise_squashing_param: None,
             };
 
             assert!(!sk.is_conformant(&conformance_params));
@@ -583,6 +647,30 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: None,
+                noise_squashing_param: None,
+            };
+
+            assert!(sk.is_conformant(&conformance_params));
+        }
+        {
+            let config = ConfigBuilder::with_custom_parameters(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            )
+            .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+
+            let ck = ClientKey::generate(config);
+            let sk = CompressedServerKey::new(&ck);
+
+            let sk_param = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+
+            let conformance_params = IntegerServerKeyConformanceParams {
+                sk_param,
+                cpk_param: None,
+                compression_param: None,
+                noise_squashing_param: Some(
+                    NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+                ),
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -603,6 +691,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: Some(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128),
+                noise_squashing_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -626,6 +715,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                noise_squashing_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -661,6 +751,7 @@ mod test {
                     sk_param,
                     cpk_param: None,
                     compression_param: None,
+                    noise_squashing_param: None,
                 };
 
                 assert!(!sk.is_conformant(&conformance_params));
@@ -687,6 +778,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                noise_squashing_param: None,
             };
 
             assert!(!sk.is_conformant(&conformance_params));
@@ -48,12 +48,15 @@ macro_rules! export_concrete_array_types {
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use crate::integer::server_key::MatchValues;
-pub use config::{Config, ConfigBuilder};
+pub use config::{Config, ConfigBuilder, HashBackend};
 #[cfg(feature = "gpu")]
 pub use global_state::CudaGpuChoice;
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 
-pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId};
+pub use integers::{
+    CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId, SquashedNoiseFheInt,
+    SquashedNoiseFheUint,
+};
 #[cfg(feature = "gpu")]
 pub use keys::CudaServerKey;
 pub use keys::{
@@ -64,7 +67,18 @@ pub use keys::{
 #[cfg(test)]
 mod tests;
 
-pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolConformanceParams};
+pub use fixed_point::{
+    FheFixed, FheFixedI16F16, FheFixedI16F16Id, FheFixedI32F32, FheFixedI32F32Id, FheFixedI4F4,
+    FheFixedI4F4Id, FheFixedI8F8, FheFixedI8F8Id, FheFixedId, FheUFixed, FheUFixedId,
+    FheUFixedU16F16, FheUFixedU16F16Id, FheUFixedU32F32, FheUFixedU32F32Id, FheUFixedU4F4,
+    FheUFixedU4F4Id, FheUFixedU8F8, FheUFixedU8F8Id, FixedPointId, RoundingMode,
+};
+
+pub use float::FheFloat32;
+
+pub use crate::high_level_api::booleans::{
+    CompressedFheBool, FheBool, FheBoolConformanceParams, SquashedNoiseFheBool,
+};
 
 #[cfg(feature = "extended-types")]
 expand_pub_use_fhe_type!(
@@ -107,6 +121,8 @@ pub use crate::integer::parameters::CompactCiphertextListConformanceParams;
 pub use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
 #[cfg(feature = "strings")]
 pub use crate::strings::ciphertext::ClearString;
+#[cfg(feature = "strings")]
+pub use crate::strings::regex::CompiledRegex;
 
 #[cfg(feature = "zk-pok")]
 pub use compact_list::ProvenCompactCiphertextList;
@@ -118,7 +134,11 @@ pub use compressed_ciphertext_list::{
     CompressedCiphertextList, CompressedCiphertextListBuilder, HlCompressible, HlExpandable,
 };
 #[cfg(feature = "strings")]
-pub use strings::ascii::{EncryptableString, FheAsciiString, FheStringIsEmpty, FheStringLen};
+pub use strings::ascii::{
+    EncryptableString, FheAsciiString, FheSplit, FheStringIsEmpty, FheStringLen,
+};
+#[cfg(feature = "strings")]
+pub use strings::utf8::FheUtf8String;
 pub use tag::Tag;
 pub use traits::FheId;
 
@@ -126,11 +146,13 @@ mod booleans;
 mod compressed_ciphertext_list;
 mod config;
 mod errors;
+mod fixed_point;
+mod float;
 mod global_state;
 mod integers;
 mod keys;
 #[cfg(feature = "strings")]
-mod strings;
+pub(crate) mod strings;
 mod traits;
 mod utils;
 
@@ -7,10 +7,10 @@
 //! use tfhe::prelude::*;
 //! ```
 pub use crate::high_level_api::traits::{
-    BitSlice, CiphertextList, DivRem, FheDecrypt, FheEncrypt, FheEq, FheKeyswitch, FheMax, FheMin,
-    FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt, IfThenElse, OverflowingAdd,
-    OverflowingMul, OverflowingSub, RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
-    Tagged,
+    AddMod, BitSlice, CiphertextList, DivRem, FheDecrypt, FheEncrypt, FheEq, FheKeyswitch, FheMax,
+    FheMin, FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt, IfThenElse, MulMod,
+    OverflowingAdd, OverflowingMul, OverflowingPow, OverflowingSub, Pow, PowMod, RotateLeft,
+    RotateLeftAssign, RotateRight, RotateRightAssign, SquashNoise, Tagged,
 };
 
 pub use crate::conformance::ParameterSetConformant;
@@ -1,9 +1,9 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::strings::ascii::FheAsciiString;
-use crate::prelude::{FheEq, FheEqIgnoreCase, FheOrd};
+use crate::prelude::{FheEq, FheEqIgnoreCase, FheNaturalOrd, FheOrd, FheOrdIgnoreCase};
 use crate::strings::ciphertext::ClearString;
-use crate::FheBool;
+use crate::{FheBool, FheInt8};
 
 impl FheEq<&Self> for FheAsciiString {
     fn eq(&self, other: &Self) -> FheBool {
@@ -258,3 +258,183 @@ impl FheEqIgnoreCase<ClearString> for FheAsciiString {
         })
     }
 }
+
+impl FheOrdIgnoreCase for FheAsciiString {
+    /// Compares the strings lexicographically, ignoring the case
+    ///
+    /// Returns a [FheInt8] that encrypts -1, 0 or 1 if `self` is respectively less than, equal
+    /// to or greater than `rhs`, like `std::cmp::Ordering as i8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheAsciiString::try_encrypt("alice", &client_key).unwrap();
+    /// let string2 = FheAsciiString::try_encrypt("Bob", &client_key).unwrap();
+    ///
+    /// let ordering: i8 = string1.cmp_ignore_case(&string2).decrypt(&client_key);
+    /// assert_eq!(ordering, Ordering::Less as i8);
+    /// assert!(string1.lt_ignore_case(&string2).decrypt(&client_key));
+    /// ```
+    fn cmp_ignore_case(&self, rhs: &Self) -> FheInt8 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .cmp_ignore_case(&self.inner.on_cpu(), (&*rhs.inner.on_cpu()).into());
+                FheInt8::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings cmp_ignore_case");
+            }
+        })
+    }
+
+    fn lt_ignore_case(&self, rhs: &Self) -> FheBool {
+        self.cmp_ignore_case(rhs).lt(0i8)
+    }
+
+    fn le_ignore_case(&self, rhs: &Self) -> FheBool {
+        self.cmp_ignore_case(rhs).le(0i8)
+    }
+
+    fn gt_ignore_case(&self, rhs: &Self) -> FheBool {
+        self.cmp_ignore_case(rhs).gt(0i8)
+    }
+
+    fn ge_ignore_case(&self, rhs: &Self) -> FheBool {
+        self.cmp_ignore_case(rhs).ge(0i8)
+    }
+}
+
+impl FheOrdIgnoreCase<ClearString> for FheAsciiString {
+    fn cmp_ignore_case(&self, rhs: &ClearString) -> FheInt8 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .cmp_ignore_case(&self.inner.on_cpu(), rhs.into());
+                FheInt8::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings cmp_ignore_case");
+            }
+        })
+    }
+
+    fn lt_ignore_case(&self, rhs: &ClearString) -> FheBool {
+        self.cmp_ignore_case(rhs).lt(0i8)
+    }
+
+    fn le_ignore_case(&self, rhs: &ClearString) -> FheBool {
+        self.cmp_ignore_case(rhs).le(0i8)
+    }
+
+    fn gt_ignore_case(&self, rhs: &ClearString) -> FheBool {
+        self.cmp_ignore_case(rhs).gt(0i8)
+    }
+
+    fn ge_ignore_case(&self, rhs: &ClearString) -> FheBool {
+        self.cmp_ignore_case(rhs).ge(0i8)
+    }
+}
+
+impl FheNaturalOrd for FheAsciiString {
+    /// Compares the strings in natural order, where runs of ASCII digits are compared as
+    /// numbers
+    ///
+    /// Digit runs are compared by length and then digit by digit, which is their numeric order
+    /// as long as they have no leading zeros. The comparison is case sensitive.
+    ///
+    /// Returns a [FheInt8] that encrypts -1, 0 or 1 if `self` is respectively less than, equal
+    /// to or greater than `rhs`, like `std::cmp::Ordering as i8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheAsciiString::try_encrypt("file2.txt", &client_key).unwrap();
+    /// let string2 = FheAsciiString::try_encrypt("file10.txt", &client_key).unwrap();
+    ///
+    /// let ordering: i8 = string1.natural_cmp(&string2).decrypt(&client_key);
+    /// assert_eq!(ordering, Ordering::Less as i8);
+    /// assert!(string1.natural_lt(&string2).decrypt(&client_key));
+    /// ```
+    fn natural_cmp(&self, rhs: &Self) -> FheInt8 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .natural_cmp(&self.inner.on_cpu(), (&*rhs.inner.on_cpu()).into());
+                FheInt8::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings natural_cmp");
+            }
+        })
+    }
+
+    fn natural_lt(&self, rhs: &Self) -> FheBool {
+        self.natural_cmp(rhs).lt(0i8)
+    }
+
+    fn natural_le(&self, rhs: &Self) -> FheBool {
+        self.natural_cmp(rhs).le(0i8)
+    }
+
+    fn natural_gt(&self, rhs: &Self) -> FheBool {
+        self.natural_cmp(rhs).gt(0i8)
+    }
+
+    fn natural_ge(&self, rhs: &Self) -> FheBool {
+        self.natural_cmp(rhs).ge(0i8)
+    }
+}
+
+impl FheNaturalOrd<ClearString> for FheAsciiString {
+    fn natural_cmp(&self, rhs: &ClearString) -> FheInt8 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .natural_cmp(&self.inner.on_cpu(), rhs.into());
+                FheInt8::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings natural_cmp");
+            }
+        })
+    }
+
+    fn natural_lt(&self, rhs: &ClearString) -> FheBool {
+        self.natural_cmp(rhs).lt(0i8)
+    }
+
+    fn natural_le(&self, rhs: &ClearString) -> FheBool {
+        self.natural_cmp(rhs).le(0i8)
+    }
+
+    fn natural_gt(&self, rhs: &ClearString) -> FheBool {
+        self.natural_cmp(rhs).gt(0i8)
+    }
+
+    fn natural_ge(&self, rhs: &ClearString) -> FheBool {
+        self.natural_cmp(rhs).ge(0i8)
+    }
+}
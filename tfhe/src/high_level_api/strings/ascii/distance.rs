@@ -0,0 +1,167 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::high_level_api::strings::traits::FheStringDistance;
+use crate::strings::ciphertext::ClearString;
+use crate::{FheBool, FheUint16};
+
+impl FheStringDistance<&Self> for FheAsciiString {
+    /// Returns the Levenshtein distance between the strings, that is the minimum number of
+    /// single character insertions, deletions and substitutions required to change one into the
+    /// other
+    ///
+    /// Padding is not taken into account. The number of operations grows with the product of the
+    /// lengths of the strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheAsciiString::try_encrypt("kitten", &client_key).unwrap();
+    /// let string2 = FheAsciiString::try_encrypt_with_padding("sitting", 1, &client_key).unwrap();
+    /// let distance = string1.edit_distance(&string2);
+    ///
+    /// let distance: u16 = distance.decrypt(&client_key);
+    /// assert_eq!(distance, 3);
+    /// ```
+    fn edit_distance(&self, other: &Self) -> FheUint16 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .edit_distance(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings edit_distance");
+            }
+        })
+    }
+
+    /// Returns the Hamming distance between the strings, that is the number of positions at which
+    /// their characters differ
+    ///
+    /// If the strings have different lengths, each character of the longest one that has no
+    /// counterpart in the other one counts as a difference. Padding is not taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheAsciiString::try_encrypt("karolin", &client_key).unwrap();
+    /// let string2 = FheAsciiString::try_encrypt("kathrin", &client_key).unwrap();
+    /// let distance = string1.hamming_distance(&string2);
+    ///
+    /// let distance: u16 = distance.decrypt(&client_key);
+    /// assert_eq!(distance, 3);
+    /// ```
+    fn hamming_distance(&self, other: &Self) -> FheUint16 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .hamming_distance(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings hamming_distance");
+            }
+        })
+    }
+
+    /// checks if the Levenshtein distance between the strings is at most `max_distance`
+    ///
+    /// Returns a [FheBool] that encrypts `true` if the strings are close enough.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheAsciiString::try_encrypt("jonathan", &client_key).unwrap();
+    /// let string2 = FheAsciiString::try_encrypt("jonatan", &client_key).unwrap();
+    ///
+    /// assert!(string1.fuzzy_eq(&string2, 1).decrypt(&client_key));
+    /// assert!(!string1.fuzzy_eq(&string2, 0).decrypt(&client_key));
+    /// ```
+    fn fuzzy_eq(&self, other: &Self, max_distance: u16) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().fuzzy_eq(
+                    &self.inner.on_cpu(),
+                    (&*other.inner.on_cpu()).into(),
+                    max_distance,
+                );
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings fuzzy_eq");
+            }
+        })
+    }
+}
+
+impl FheStringDistance<&ClearString> for FheAsciiString {
+    fn edit_distance(&self, other: &ClearString) -> FheUint16 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .edit_distance(&self.inner.on_cpu(), other.into());
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings edit_distance");
+            }
+        })
+    }
+
+    fn hamming_distance(&self, other: &ClearString) -> FheUint16 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .hamming_distance(&self.inner.on_cpu(), other.into());
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings hamming_distance");
+            }
+        })
+    }
+
+    fn fuzzy_eq(&self, other: &ClearString, max_distance: u16) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner =
+                    cpu_key
+                        .string_key()
+                        .fuzzy_eq(&self.inner.on_cpu(), other.into(), max_distance);
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings fuzzy_eq");
+            }
+        })
+    }
+}
@@ -1,8 +1,13 @@
 mod comp;
 mod contains;
+mod distance;
 mod find;
 mod no_pattern;
+mod parse;
+mod regex;
 mod replace;
+mod slice;
+mod split;
 mod strip;
 mod trim;
 
@@ -15,6 +20,7 @@ use crate::strings::ciphertext::FheString;
 use crate::{ClientKey, HlExpandable, Tag};
 pub use no_pattern::{FheStringIsEmpty, FheStringLen};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+pub use split::FheSplit;
 use tfhe_versionable::{Unversionize, UnversionizeError, Versionize, VersionizeOwned};
 
 pub enum EncryptableString<'a> {
@@ -23,7 +29,7 @@ pub enum EncryptableString<'a> {
 }
 
 impl EncryptableString<'_> {
-    fn str_and_padding(&self) -> (&str, Option<u32>) {
+    pub(super) fn str_and_padding(&self) -> (&str, Option<u32>) {
         match self {
             EncryptableString::NoPadding(str) => (str, None),
             EncryptableString::WithPadding { str, padding } => (str, Some(*padding)),
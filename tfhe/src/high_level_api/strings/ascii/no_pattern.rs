@@ -153,6 +153,39 @@ impl FheAsciiString {
         })
     }
 
+    /// Returns whether the encrypted string is a valid ASCII string, that is each character is
+    /// lower than 128 and nulls only appear in the padding at the end of the string.
+    ///
+    /// Strings encrypted with a [ClientKey](crate::ClientKey) are always valid. The proof of a
+    /// [ProvenCompactCiphertextList](crate::ProvenCompactCiphertextList) does not guarantee it,
+    /// so strings expanded from such a list should be checked with this function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("tfhe-rs", 2, &client_key).unwrap();
+    /// let is_valid = string.is_valid_ascii();
+    /// assert!(is_valid.decrypt(&client_key));
+    /// ```
+    pub fn is_valid_ascii(&self) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().is_valid_ascii(&self.inner.on_cpu());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings is_valid_ascii");
+            }
+        })
+    }
+
     /// Returns a new encrypted string with all characters converted to lowercase.
     ///
     /// # Example
@@ -0,0 +1,161 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::{FheBool, FheInt64, FheUint64};
+
+impl FheAsciiString {
+    /// Parses the string as a decimal unsigned integer
+    ///
+    /// See [Self::parse_u64_radix].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("1234", 2, &client_key).unwrap();
+    /// let (value, is_valid) = string.parse_u64();
+    ///
+    /// assert!(is_valid.decrypt(&client_key));
+    /// let value: u64 = value.decrypt(&client_key);
+    /// assert_eq!(value, 1234);
+    /// ```
+    pub fn parse_u64(&self) -> (FheUint64, FheBool) {
+        self.parse_u64_radix(10)
+    }
+
+    /// Parses the string as an unsigned integer written in base `radix`, following the rules of
+    /// [u64::from_str_radix]
+    ///
+    /// Digits above 9 are the letters `a` to `z`, in lower or upper case, and an optional `+`
+    /// sign may precede the digits.
+    ///
+    /// Returns the parsed value and a [FheBool] that encrypts `false` if the string is not a
+    /// valid number (empty, invalid character, or value overflowing a `u64`), in which case the
+    /// value encrypts 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("fF", &client_key).unwrap();
+    /// let (value, is_valid) = string.parse_u64_radix(16);
+    ///
+    /// assert!(is_valid.decrypt(&client_key));
+    /// let value: u64 = value.decrypt(&client_key);
+    /// assert_eq!(value, 255);
+    /// ```
+    pub fn parse_u64_radix(&self, radix: u32) -> (FheUint64, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (value, is_valid) = cpu_key.string_key().parse_u64(&self.inner.on_cpu(), radix);
+                (
+                    FheUint64::new(value, cpu_key.tag.clone()),
+                    FheBool::new(is_valid, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support parsing strings");
+            }
+        })
+    }
+
+    /// Parses the string as a decimal signed integer
+    ///
+    /// See [Self::parse_i64_radix].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("-42", &client_key).unwrap();
+    /// let (value, is_valid) = string.parse_i64();
+    ///
+    /// assert!(is_valid.decrypt(&client_key));
+    /// let value: i64 = value.decrypt(&client_key);
+    /// assert_eq!(value, -42);
+    /// ```
+    pub fn parse_i64(&self) -> (FheInt64, FheBool) {
+        self.parse_i64_radix(10)
+    }
+
+    /// Parses the string as a signed integer written in base `radix`, following the rules of
+    /// [i64::from_str_radix]
+    ///
+    /// Same as [Self::parse_u64_radix], except that a `-` sign is also accepted and that the
+    /// value must fit in an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn parse_i64_radix(&self, radix: u32) -> (FheInt64, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (value, is_valid) = cpu_key.string_key().parse_i64(&self.inner.on_cpu(), radix);
+                (
+                    FheInt64::new(value, cpu_key.tag.clone()),
+                    FheBool::new(is_valid, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support parsing strings");
+            }
+        })
+    }
+}
+
+impl FheUint64 {
+    /// Formats the integer as its decimal representation
+    ///
+    /// The result is padded, so that its size does not depend on the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint64};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let n = FheUint64::encrypt(2024u64, &client_key);
+    /// let string = n.to_decimal_string();
+    ///
+    /// let decrypted: String = string.decrypt(&client_key);
+    /// assert_eq!(decrypted, "2024");
+    /// ```
+    pub fn to_decimal_string(&self) -> FheAsciiString {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .to_decimal_string(&self.ciphertext.on_cpu());
+                FheAsciiString::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support formatting integers as strings");
+            }
+        })
+    }
+}
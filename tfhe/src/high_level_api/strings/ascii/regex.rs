@@ -0,0 +1,135 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::strings::regex::CompiledRegex;
+use crate::{FheBool, FheUint32};
+
+impl FheAsciiString {
+    /// Returns whether the regular expression matches somewhere in the string
+    ///
+    /// See [CompiledRegex] for the supported syntax.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompiledRegex, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let regex = CompiledRegex::new(r"^\d+ apples?$").unwrap();
+    ///
+    /// let string = FheAsciiString::try_encrypt("12 apples", &client_key).unwrap();
+    /// assert!(string.matches_regex(&regex).decrypt(&client_key));
+    ///
+    /// let string = FheAsciiString::try_encrypt("many apples", &client_key).unwrap();
+    /// assert!(!string.matches_regex(&regex).decrypt(&client_key));
+    /// ```
+    pub fn matches_regex(&self, regex: &CompiledRegex) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .regex_is_match(&self.inner.on_cpu(), regex);
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings regex");
+            }
+        })
+    }
+
+    /// Returns the leftmost match of the regular expression
+    ///
+    /// Among the matches starting at the leftmost position, the longest one is returned.
+    /// The result is the start index, the end index (exclusive) and a [FheBool] that encrypts
+    /// `true` if there was a match. Indices encrypt 0 if there was no match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompiledRegex, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let regex = CompiledRegex::new("[0-9]+").unwrap();
+    /// let string = FheAsciiString::try_encrypt("id: 042", &client_key).unwrap();
+    /// let (start, end, found) = string.find_regex(&regex);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// let start: u32 = start.decrypt(&client_key);
+    /// let end: u32 = end.decrypt(&client_key);
+    /// assert_eq!((start, end), (4, 7));
+    /// ```
+    pub fn find_regex(&self, regex: &CompiledRegex) -> (FheUint32, FheUint32, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (start, end, found) =
+                    cpu_key.string_key().regex_find(&self.inner.on_cpu(), regex);
+                (
+                    FheUint32::new(start, cpu_key.tag.clone()),
+                    FheUint32::new(end, cpu_key.tag.clone()),
+                    FheBool::new(found, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings regex");
+            }
+        })
+    }
+
+    /// Returns the bounds of the leftmost-longest match and of each capture group
+    ///
+    /// The first element is the whole match (as returned by [Self::find_regex]), followed by
+    /// one element per capture group, see [CompiledRegex] for which groups are capturing.
+    /// The [FheBool] encrypts `true` if there was a match.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompiledRegex, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let regex = CompiledRegex::new(r"(\w+)@(\w+)").unwrap();
+    /// let string = FheAsciiString::try_encrypt("to: bob@zama", &client_key).unwrap();
+    /// let (captures, found) = string.captures_regex(&regex);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// let bounds: Vec<(u32, u32)> = captures
+    ///     .iter()
+    ///     .map(|(start, end)| (start.decrypt(&client_key), end.decrypt(&client_key)))
+    ///     .collect();
+    /// assert_eq!(bounds, vec![(4, 12), (4, 7), (8, 12)]);
+    /// ```
+    pub fn captures_regex(&self, regex: &CompiledRegex) -> (Vec<(FheUint32, FheUint32)>, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (captures, found) = cpu_key
+                    .string_key()
+                    .regex_captures(&self.inner.on_cpu(), regex);
+                let captures = captures
+                    .into_iter()
+                    .map(|(start, end)| {
+                        (
+                            FheUint32::new(start, cpu_key.tag.clone()),
+                            FheUint32::new(end, cpu_key.tag.clone()),
+                        )
+                    })
+                    .collect();
+                (captures, FheBool::new(found, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings regex");
+            }
+        })
+    }
+}
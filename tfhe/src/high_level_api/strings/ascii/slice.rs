@@ -0,0 +1,247 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::integer::{RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::prelude::FheStringSlice;
+use crate::strings::ciphertext::UIntArg;
+use crate::strings::client_key::EncU16;
+use crate::{FheBool, FheUint32, FheUint8, FheUint8Id, Tag};
+
+/// Strings indices are 16 bits, larger encrypted indices are saturated as they are out of bounds
+/// anyway
+fn index_arg(index: &FheUint32, sk: &IntegerServerKey) -> UIntArg {
+    let index = sk.scalar_min_parallelized(&*index.ciphertext.on_cpu(), u16::MAX);
+    let index = sk.cast_to_unsigned(index, sk.num_blocks_to_represent_unsigned_value(u16::MAX));
+    UIntArg::Enc(EncU16::new(index, None))
+}
+
+fn clear_index_arg(index: u32) -> UIntArg {
+    UIntArg::Clear(u16::try_from(index).unwrap_or(u16::MAX))
+}
+
+/// Chars are encrypted on 7 bits, which is fewer blocks than a [FheUint8] with 1 bit of message
+/// per block
+fn char_to_fhe_uint8(sk: &IntegerServerKey, char: RadixCiphertext, tag: Tag) -> FheUint8 {
+    let char = sk.cast_to_unsigned(char, FheUint8Id::num_blocks(sk.message_modulus()));
+    FheUint8::new(char, tag)
+}
+
+impl FheStringSlice<u32> for FheAsciiString {
+    /// Returns the characters from `start` (inclusive) to `end` (exclusive)
+    ///
+    /// Bounds greater than the length of the string are clamped to it, and the result is empty
+    /// if `start >= end`. Clear bounds do not require any homomorphic computation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    ///
+    /// let sub = string.substring(1, 4);
+    /// assert_eq!(sub.decrypt(&client_key), "fhe");
+    ///
+    /// let sub = string.substring(5, 100);
+    /// assert_eq!(sub.decrypt(&client_key), "rs");
+    /// ```
+    fn substring(&self, start: u32, end: u32) -> Self {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().substring(
+                    &self.inner.on_cpu(),
+                    &clear_index_arg(start),
+                    &clear_index_arg(end),
+                );
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings substring");
+            }
+        })
+    }
+
+    /// Returns the first `len` characters of the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    ///
+    /// let truncated = string.truncate(4);
+    /// assert_eq!(truncated.decrypt(&client_key), "tfhe");
+    /// ```
+    fn truncate(&self, len: u32) -> Self {
+        self.substring(0, len)
+    }
+
+    /// Returns the character at `index`, and a [FheBool] that encrypts `true` if `index` is
+    /// within the bounds of the string
+    ///
+    /// If the index is out of bounds, the returned character encrypts 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("tfhe-rs", 2, &client_key).unwrap();
+    ///
+    /// let (char, in_bounds) = string.char_at(4);
+    /// assert!(in_bounds.decrypt(&client_key));
+    /// let char: u8 = char.decrypt(&client_key);
+    /// assert_eq!(char, b'-');
+    ///
+    /// let (_, in_bounds) = string.char_at(7);
+    /// assert!(!in_bounds.decrypt(&client_key));
+    /// ```
+    fn char_at(&self, index: u32) -> (FheUint8, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (char, in_bounds) = cpu_key
+                    .string_key()
+                    .char_at(&self.inner.on_cpu(), &clear_index_arg(index));
+                (
+                    char_to_fhe_uint8(cpu_key.pbs_key(), char, cpu_key.tag.clone()),
+                    FheBool::new(in_bounds, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings char_at");
+            }
+        })
+    }
+}
+
+impl FheStringSlice<&FheUint32> for FheAsciiString {
+    /// Returns the characters from the encrypted `start` (inclusive) to the encrypted `end`
+    /// (exclusive)
+    ///
+    /// Bounds greater than the length of the string are clamped to it, and the result is empty
+    /// if `start >= end`. The result is padded, its number of characters (including padding)
+    /// does not depend on the bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("name=zama;", &client_key).unwrap();
+    ///
+    /// let (equal, _) = string.find(&ClearString::new("=".into()));
+    /// let (semicolon, _) = string.find(&ClearString::new(";".into()));
+    /// let value = string.substring(&(equal + 1u32), &semicolon);
+    ///
+    /// assert_eq!(value.decrypt(&client_key), "zama");
+    /// ```
+    fn substring(&self, start: &FheUint32, end: &FheUint32) -> Self {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sk = cpu_key.pbs_key();
+                let inner = cpu_key.string_key().substring(
+                    &self.inner.on_cpu(),
+                    &index_arg(start, sk),
+                    &index_arg(end, sk),
+                );
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings substring");
+            }
+        })
+    }
+
+    /// Returns the first `len` characters of the string, `len` being encrypted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    /// let len = FheUint32::encrypt(4u32, &client_key);
+    ///
+    /// let truncated = string.truncate(&len);
+    /// assert_eq!(truncated.decrypt(&client_key), "tfhe");
+    /// ```
+    fn truncate(&self, len: &FheUint32) -> Self {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .truncate(&self.inner.on_cpu(), &index_arg(len, cpu_key.pbs_key()));
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings truncate");
+            }
+        })
+    }
+
+    /// Returns the character at the encrypted `index`, and a [FheBool] that encrypts `true` if
+    /// `index` is within the bounds of the string
+    ///
+    /// If the index is out of bounds, the returned character encrypts 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    /// let index = FheUint32::encrypt(5u32, &client_key);
+    ///
+    /// let (char, in_bounds) = string.char_at(&index);
+    /// assert!(in_bounds.decrypt(&client_key));
+    /// let char: u8 = char.decrypt(&client_key);
+    /// assert_eq!(char, b'r');
+    /// ```
+    fn char_at(&self, index: &FheUint32) -> (FheUint8, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (char, in_bounds) = cpu_key
+                    .string_key()
+                    .char_at(&self.inner.on_cpu(), &index_arg(index, cpu_key.pbs_key()));
+                (
+                    char_to_fhe_uint8(cpu_key.pbs_key(), char, cpu_key.tag.clone()),
+                    FheBool::new(in_bounds, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings char_at");
+            }
+        })
+    }
+}
@@ -0,0 +1,618 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::high_level_api::strings::traits::{FheStringSplit, FheStringSplitN, FheStringSplitOnce};
+use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{ClearString, FheString, GenericPatternRef, UIntArg};
+use crate::strings::client_key::EncU16;
+use crate::strings::server_key::{split_ascii_whitespace, FheStringIterator, ServerKeyRef};
+use crate::{FheBool, FheUint16};
+
+type SplitIter = Box<dyn for<'a> FheStringIterator<&'a IntegerServerKey> + Send>;
+
+/// Iterator-like object over the substrings of an encrypted string
+///
+/// It is created by the split family of functions (e.g. [FheStringSplit::split]).
+///
+/// As the number of substrings is not known, each call to [FheSplit::next] returns
+/// a substring along with a [FheBool] that encrypts `true` if the substring exists
+/// (the equivalent of `Some(_)`). Once all the substrings have been returned, the
+/// following calls return an empty string and an encryption of `false`.
+///
+/// Like [CompactCiphertextListExpander], it holds the state of a computation done on the server
+/// and is not meant to be stored or sent, so it is not serializable. The substrings it returns are
+/// [FheAsciiString]s, which are.
+///
+/// [CompactCiphertextListExpander]: crate::CompactCiphertextListExpander
+pub struct FheSplit {
+    inner: SplitIter,
+}
+
+impl FheSplit {
+    fn new(inner: impl for<'a> FheStringIterator<&'a IntegerServerKey> + Send + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Returns the next substring, and a [FheBool] that encrypts `true` if there was one
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> (FheAsciiString, FheBool) {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (inner, block) = self.inner.next(&cpu_key.string_key());
+                (
+                    FheAsciiString::new(inner, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings split");
+            }
+        })
+    }
+}
+
+impl FheAsciiString {
+    fn split_with<F, I>(&self, pat: GenericPatternRef<'_>, split_fn: F) -> FheSplit
+    where
+        F: FnOnce(&ServerKeyRef<'_>, &FheString, GenericPatternRef<'_>) -> I,
+        I: for<'a> FheStringIterator<&'a IntegerServerKey> + Send + 'static,
+    {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                FheSplit::new(split_fn(&cpu_key.string_key(), &self.inner.on_cpu(), pat))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings split");
+            }
+        })
+    }
+
+    fn split_once_with<F>(&self, pat: GenericPatternRef<'_>, split_fn: F) -> (Self, Self, FheBool)
+    where
+        F: FnOnce(
+            &ServerKeyRef<'_>,
+            &FheString,
+            GenericPatternRef<'_>,
+        ) -> (FheString, FheString, BooleanBlock),
+    {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (lhs, rhs, block) = split_fn(&cpu_key.string_key(), &self.inner.on_cpu(), pat);
+                (
+                    Self::new(lhs, cpu_key.tag.clone()),
+                    Self::new(rhs, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings split_once");
+            }
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string
+    /// that are separated by any amount of ASCII whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt(" tfhe \t rs", &client_key).unwrap();
+    /// let mut split = string.split_ascii_whitespace();
+    ///
+    /// for expected in ["tfhe", "rs"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    pub fn split_ascii_whitespace(&self) -> FheSplit {
+        FheSplit::new(split_ascii_whitespace(&self.inner.on_cpu()))
+    }
+}
+
+impl FheStringSplit<&Self> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt(",", &client_key).unwrap();
+    /// let mut split = string.split(&pattern);
+    ///
+    /// for expected in ["a", "b", "c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn split(&self, pat: &Self) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.split(str, pat)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt(",", &client_key).unwrap();
+    /// let mut split = string.rsplit(&pattern);
+    ///
+    /// for expected in ["c", "b", "a"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn rsplit(&self, pat: &Self) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.rsplit(str, pat)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// Equivalent to [FheStringSplit::split], except that the trailing substring is skipped
+    /// if it is empty.
+    fn split_terminator(&self, pat: &Self) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.split_terminator(str, pat)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// Equivalent to [FheStringSplit::rsplit], except that the trailing substring is skipped
+    /// if it is empty.
+    fn rsplit_terminator(&self, pat: &Self) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.rsplit_terminator(str, pat)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// Differs from [FheStringSplit::split] in that each substring keeps its terminating
+    /// pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt(",", &client_key).unwrap();
+    /// let mut split = string.split_inclusive(&pattern);
+    ///
+    /// for expected in ["a,", "b,", "c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn split_inclusive(&self, pat: &Self) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.split_inclusive(str, pat)
+        })
+    }
+}
+
+impl FheStringSplitOnce<&Self> for FheAsciiString {
+    /// Splits the string at the first occurrence of the pattern
+    ///
+    /// Returns the substrings before and after the pattern, as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found. If it was not found, the first
+    /// substring is empty and the second is the original string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("key=value=1", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt("=", &client_key).unwrap();
+    /// let (lhs, rhs, found) = string.split_once(&pattern);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// assert_eq!(lhs.decrypt(&client_key), "key");
+    /// assert_eq!(rhs.decrypt(&client_key), "value=1");
+    /// ```
+    fn split_once(&self, pat: &Self) -> (Self, Self, FheBool) {
+        self.split_once_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.split_once(str, pat)
+        })
+    }
+
+    /// Splits the string at the last occurrence of the pattern
+    ///
+    /// Returns the substrings before and after the pattern, as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found. If it was not found, the first
+    /// substring is empty and the second is the original string.
+    fn rsplit_once(&self, pat: &Self) -> (Self, Self, FheBool) {
+        self.split_once_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.rsplit_once(str, pat)
+        })
+    }
+}
+
+impl FheStringSplitN<&Self, i32> for FheAsciiString {
+    fn splitn(&self, pat: &Self, count: i32) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &Self, count: i32) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&Self, usize> for FheAsciiString {
+    fn splitn(&self, pat: &Self, count: usize) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &Self, count: usize) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&Self, u32> for FheAsciiString {
+    fn splitn(&self, pat: &Self, count: u32) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &Self, count: u32) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&Self, u16> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// The last substring contains the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt(",", &client_key).unwrap();
+    /// let mut split = string.splitn(&pattern, 2);
+    ///
+    /// for expected in ["a", "b,c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn splitn(&self, pat: &Self, count: u16) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.splitn(str, pat, UIntArg::Clear(count))
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// The last substring contains the remainder of the string.
+    fn rsplitn(&self, pat: &Self, count: u16) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            sk.rsplitn(str, pat, UIntArg::Clear(count))
+        })
+    }
+}
+
+impl FheStringSplitN<&Self, (FheUint16, u16)> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern, where `count` is encrypted and at most `max`.
+    fn splitn(&self, pat: &Self, (count, max): (FheUint16, u16)) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            let count = UIntArg::Enc(EncU16::new(count.ciphertext.into_cpu(), Some(max)));
+            sk.splitn(str, pat, count)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern and yielded in reverse order, where `count` is encrypted
+    /// and at most `max`.
+    fn rsplitn(&self, pat: &Self, (count, max): (FheUint16, u16)) -> FheSplit {
+        self.split_with((&*pat.inner.on_cpu()).into(), |sk, str, pat| {
+            let count = UIntArg::Enc(EncU16::new(count.ciphertext.into_cpu(), Some(max)));
+            sk.rsplitn(str, pat, count)
+        })
+    }
+}
+
+impl FheStringSplit<&ClearString> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = ClearString::new(",".into());
+    /// let mut split = string.split(&pattern);
+    ///
+    /// for expected in ["a", "b", "c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn split(&self, pat: &ClearString) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| sk.split(str, pat))
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = ClearString::new(",".into());
+    /// let mut split = string.rsplit(&pattern);
+    ///
+    /// for expected in ["c", "b", "a"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn rsplit(&self, pat: &ClearString) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| sk.rsplit(str, pat))
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// Equivalent to [FheStringSplit::split], except that the trailing substring is skipped
+    /// if it is empty.
+    fn split_terminator(&self, pat: &ClearString) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| sk.split_terminator(str, pat))
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// Equivalent to [FheStringSplit::rsplit], except that the trailing substring is skipped
+    /// if it is empty.
+    fn rsplit_terminator(&self, pat: &ClearString) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| sk.rsplit_terminator(str, pat))
+    }
+
+    /// Returns an iterator-like [FheSplit] over the substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// Differs from [FheStringSplit::split] in that each substring keeps its terminating
+    /// pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = ClearString::new(",".into());
+    /// let mut split = string.split_inclusive(&pattern);
+    ///
+    /// for expected in ["a,", "b,", "c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn split_inclusive(&self, pat: &ClearString) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| sk.split_inclusive(str, pat))
+    }
+}
+
+impl FheStringSplitOnce<&ClearString> for FheAsciiString {
+    /// Splits the string at the first occurrence of the pattern
+    ///
+    /// Returns the substrings before and after the pattern, as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found. If it was not found, the first
+    /// substring is empty and the second is the original string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("key=value=1", &client_key).unwrap();
+    /// let pattern = ClearString::new("=".into());
+    /// let (lhs, rhs, found) = string.split_once(&pattern);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// assert_eq!(lhs.decrypt(&client_key), "key");
+    /// assert_eq!(rhs.decrypt(&client_key), "value=1");
+    /// ```
+    fn split_once(&self, pat: &ClearString) -> (Self, Self, FheBool) {
+        self.split_once_with(pat.into(), |sk, str, pat| sk.split_once(str, pat))
+    }
+
+    /// Splits the string at the last occurrence of the pattern
+    ///
+    /// Returns the substrings before and after the pattern, as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found. If it was not found, the first
+    /// substring is empty and the second is the original string.
+    fn rsplit_once(&self, pat: &ClearString) -> (Self, Self, FheBool) {
+        self.split_once_with(pat.into(), |sk, str, pat| sk.rsplit_once(str, pat))
+    }
+}
+
+impl FheStringSplitN<&ClearString, i32> for FheAsciiString {
+    fn splitn(&self, pat: &ClearString, count: i32) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &ClearString, count: i32) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&ClearString, usize> for FheAsciiString {
+    fn splitn(&self, pat: &ClearString, count: usize) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &ClearString, count: usize) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&ClearString, u32> for FheAsciiString {
+    fn splitn(&self, pat: &ClearString, count: u32) -> FheSplit {
+        self.splitn(pat, count as u16)
+    }
+
+    fn rsplitn(&self, pat: &ClearString, count: u32) -> FheSplit {
+        self.rsplitn(pat, count as u16)
+    }
+}
+
+impl FheStringSplitN<&ClearString, u16> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern.
+    ///
+    /// The last substring contains the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("a,b,c", &client_key).unwrap();
+    /// let pattern = ClearString::new(",".into());
+    /// let mut split = string.splitn(&pattern, 2);
+    ///
+    /// for expected in ["a", "b,c"] {
+    ///     let (item, is_some) = split.next();
+    ///     assert!(is_some.decrypt(&client_key));
+    ///     assert_eq!(item.decrypt(&client_key), expected);
+    /// }
+    /// let (_, is_some) = split.next();
+    /// assert!(!is_some.decrypt(&client_key));
+    /// ```
+    fn splitn(&self, pat: &ClearString, count: u16) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| {
+            sk.splitn(str, pat, UIntArg::Clear(count))
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern and yielded in reverse order.
+    ///
+    /// The last substring contains the remainder of the string.
+    fn rsplitn(&self, pat: &ClearString, count: u16) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| {
+            sk.rsplitn(str, pat, UIntArg::Clear(count))
+        })
+    }
+}
+
+impl FheStringSplitN<&ClearString, (FheUint16, u16)> for FheAsciiString {
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern, where `count` is encrypted and at most `max`.
+    fn splitn(&self, pat: &ClearString, (count, max): (FheUint16, u16)) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| {
+            let count = UIntArg::Enc(EncU16::new(count.ciphertext.into_cpu(), Some(max)));
+            sk.splitn(str, pat, count)
+        })
+    }
+
+    /// Returns an iterator-like [FheSplit] over at most `count` substrings of this string,
+    /// separated by the pattern and yielded in reverse order, where `count` is encrypted
+    /// and at most `max`.
+    fn rsplitn(&self, pat: &ClearString, (count, max): (FheUint16, u16)) -> FheSplit {
+        self.split_with(pat.into(), |sk, str, pat| {
+            let count = UIntArg::Enc(EncU16::new(count.ciphertext.into_cpu(), Some(max)));
+            sk.rsplitn(str, pat, count)
+        })
+    }
+}
@@ -0,0 +1,172 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::integer::RadixCiphertext;
+use crate::strings::server_key::HashData;
+use crate::{FheUint256, FheUint32, FheUint8, Tag};
+
+/// The data that can be hashed, either an encrypted ASCII string or encrypted bytes
+pub enum HashInput<'a> {
+    String(&'a FheAsciiString),
+    Bytes(&'a [FheUint8]),
+}
+
+impl<'a> From<&'a FheAsciiString> for HashInput<'a> {
+    fn from(value: &'a FheAsciiString) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<'a> From<&'a [FheUint8]> for HashInput<'a> {
+    fn from(value: &'a [FheUint8]) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [FheUint8; N]> for HashInput<'a> {
+    fn from(value: &'a [FheUint8; N]) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl<'a> From<&'a Vec<FheUint8>> for HashInput<'a> {
+    fn from(value: &'a Vec<FheUint8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl HashInput<'_> {
+    fn with_hash_data<R>(&self, f: impl FnOnce(HashData<'_>) -> R) -> R {
+        match self {
+            Self::String(string) => f(HashData::String(&string.inner.on_cpu())),
+            Self::Bytes(bytes) => {
+                let bytes: Vec<RadixCiphertext> = bytes
+                    .iter()
+                    .map(|byte| byte.ciphertext.on_cpu().into_owned())
+                    .collect();
+                f(HashData::Bytes(&bytes))
+            }
+        }
+    }
+}
+
+fn to_fhe_uint32(digest: [RadixCiphertext; 8], tag: &Tag) -> [FheUint32; 8] {
+    digest.map(|word| FheUint32::new(word, tag.clone()))
+}
+
+/// Computes the SHA-256 digest of an encrypted string or of encrypted bytes
+///
+/// Returns the 8 words of the digest, the first one holding the first 4 bytes of the digest in
+/// big-endian order. Use [digest_to_uint256] to get the digest as a single integer.
+///
+/// If the input is a padded string, the padding is not part of the hashed message and its length
+/// stays hidden. The compression function is evaluated with the
+/// [HashBackend](crate::HashBackend) selected in the config, see
+/// [ConfigBuilder::use_hash_backend](crate::ConfigBuilder::use_hash_backend).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::hash::sha256;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let string = FheAsciiString::try_encrypt("abc", &client_key).unwrap();
+///
+/// let digest = sha256(&string);
+///
+/// let first_word: u32 = digest[0].decrypt(&client_key);
+/// assert_eq!(first_word, 0xba7816bf);
+/// ```
+pub fn sha256<'a>(input: impl Into<HashInput<'a>>) -> [FheUint32; 8] {
+    let input = input.into();
+
+    with_internal_keys(|keys| match keys {
+        InternalServerKey::Cpu(cpu_key) => {
+            let digest = input.with_hash_data(|data| cpu_key.string_key().sha256(data));
+            to_fhe_uint32(digest, &cpu_key.tag)
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("gpu does not support strings sha256");
+        }
+    })
+}
+
+/// Computes the HMAC-SHA-256 of an encrypted message with an encrypted key
+///
+/// Both the key and the message can be strings or bytes, see [sha256].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::hash::hmac_sha256;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let key = b"Jefe".map(|byte| FheUint8::encrypt(byte, &client_key));
+/// let message = FheAsciiString::try_encrypt("what do ya want for nothing?", &client_key).unwrap();
+///
+/// let mac = hmac_sha256(&key, &message);
+///
+/// let first_word: u32 = mac[0].decrypt(&client_key);
+/// assert_eq!(first_word, 0x5bdcc146);
+/// ```
+pub fn hmac_sha256<'a, 'b>(
+    key: impl Into<HashInput<'a>>,
+    message: impl Into<HashInput<'b>>,
+) -> [FheUint32; 8] {
+    let (key, message) = (key.into(), message.into());
+
+    with_internal_keys(|keys| match keys {
+        InternalServerKey::Cpu(cpu_key) => {
+            let digest = key.with_hash_data(|key| {
+                message.with_hash_data(|message| cpu_key.string_key().hmac_sha256(key, message))
+            });
+            to_fhe_uint32(digest, &cpu_key.tag)
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("gpu does not support strings hmac_sha256");
+        }
+    })
+}
+
+/// Concatenates the words of a digest into a single 256 bits integer
+///
+/// The first word of the digest becomes the most significant one, so that the integer is the
+/// digest read in big-endian order.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::hash::{digest_to_uint256, sha256};
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let stored = FheAsciiString::try_encrypt("hunter2", &client_key).unwrap();
+/// let stored = digest_to_uint256(&sha256(&stored));
+///
+/// let attempt = FheAsciiString::try_encrypt_with_padding("hunter2", 8, &client_key).unwrap();
+/// let attempt = digest_to_uint256(&sha256(&attempt));
+///
+/// assert!(attempt.eq(&stored).decrypt(&client_key));
+/// ```
+pub fn digest_to_uint256(digest: &[FheUint32; 8]) -> FheUint256 {
+    let blocks = digest
+        .iter()
+        .rev()
+        .flat_map(|word| word.ciphertext.on_cpu().blocks.clone())
+        .collect::<Vec<_>>();
+
+    FheUint256::new(RadixCiphertext::from(blocks), digest[0].tag.clone())
+}
@@ -0,0 +1,105 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::FheAsciiString;
+use crate::{FheBool, FheUint64};
+
+/// Extracts the value found at the given key path in an encrypted JSON document
+///
+/// Each key of the `path` is searched after the previous one, so `["a", "b"]` selects the value
+/// of `b` in the object value of `a`. Returns the value and a [FheBool] that encrypts `false` if
+/// one of the keys was not found, in which case the value is empty.
+///
+/// The value can be a string, returned without its quotes, or a number, boolean or null,
+/// returned as written in the document. Objects and arrays can not be extracted as a whole, their
+/// fields are reached by extending the path.
+///
+/// The document is not validated and the search is oblivious: the first occurrence of each key is
+/// selected, even if it belongs to a nested object that comes before the expected one. Whitespace
+/// is allowed anywhere except between a key and its colon, and escaped quotes are not supported in
+/// the extracted string values.
+///
+/// # Panics
+///
+/// Panics if `path` is empty, or if a key is not an ASCII string of at most 29 characters without
+/// quotes, backslashes or nulls.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::json::extract;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let document = FheAsciiString::try_encrypt(r#"{"user":{"name":"Ada"}}"#, &client_key).unwrap();
+/// let (name, found) = extract(&document, &["user", "name"]);
+///
+/// assert!(found.decrypt(&client_key));
+/// let name: String = name.decrypt(&client_key);
+/// assert_eq!(name, "Ada");
+/// ```
+pub fn extract(document: &FheAsciiString, path: &[&str]) -> (FheAsciiString, FheBool) {
+    with_internal_keys(|keys| match keys {
+        InternalServerKey::Cpu(cpu_key) => {
+            let (value, found) = cpu_key
+                .string_key()
+                .json_get(&document.inner.on_cpu(), path);
+            (
+                FheAsciiString::new(value, cpu_key.tag.clone()),
+                FheBool::new(found, cpu_key.tag.clone()),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("gpu does not support strings json extraction");
+        }
+    })
+}
+
+/// Extracts the number found at the given key path in an encrypted JSON document, and parses it as
+/// a decimal unsigned 64 bits integer
+///
+/// See [extract] for how the value is located. Returns the parsed value and a [FheBool] that
+/// encrypts `false` if the value was not found, is a string, or is not a valid `u64` (see
+/// [FheAsciiString::parse_u64]), in which case the value encrypts 0.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [extract].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::json::extract_u64;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let document = FheAsciiString::try_encrypt(r#"{"id": 42}"#, &client_key).unwrap();
+/// let (id, is_valid) = extract_u64(&document, &["id"]);
+///
+/// assert!(is_valid.decrypt(&client_key));
+/// let id: u64 = id.decrypt(&client_key);
+/// assert_eq!(id, 42);
+/// ```
+pub fn extract_u64(document: &FheAsciiString, path: &[&str]) -> (FheUint64, FheBool) {
+    with_internal_keys(|keys| match keys {
+        InternalServerKey::Cpu(cpu_key) => {
+            let (value, is_valid) = cpu_key
+                .string_key()
+                .json_get_u64(&document.inner.on_cpu(), path);
+            (
+                FheUint64::new(value, cpu_key.tag.clone()),
+                FheBool::new(is_valid, cpu_key.tag.clone()),
+            )
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("gpu does not support strings json extraction");
+        }
+    })
+}
@@ -1,4 +1,7 @@
 pub(crate) mod ascii;
+pub(crate) mod hash;
+pub(crate) mod json;
 #[cfg(test)]
 mod tests;
 pub(in crate::high_level_api) mod traits;
+pub(crate) mod utf8;
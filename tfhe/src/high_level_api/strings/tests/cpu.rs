@@ -1,4 +1,5 @@
 use crate::high_level_api::tests::setup_default_cpu;
+use crate::{generate_keys, set_server_key, ConfigBuilder, HashBackend};
 
 #[test]
 fn test_string_eq_ne() {
@@ -35,3 +36,67 @@ fn test_string_strip() {
     let cks = setup_default_cpu();
     super::test_string_strip(&cks);
 }
+
+#[test]
+fn test_string_split() {
+    let cks = setup_default_cpu();
+    super::test_string_split(&cks);
+}
+
+#[test]
+fn test_string_regex() {
+    let cks = setup_default_cpu();
+    super::test_string_regex(&cks);
+}
+
+#[test]
+fn test_string_parse() {
+    let cks = setup_default_cpu();
+    super::test_string_parse(&cks);
+}
+
+#[test]
+fn test_string_slice() {
+    let cks = setup_default_cpu();
+    super::test_string_slice(&cks);
+}
+
+#[test]
+fn test_string_ordering() {
+    let cks = setup_default_cpu();
+    super::test_string_ordering(&cks);
+}
+
+#[test]
+fn test_string_distance() {
+    let cks = setup_default_cpu();
+    super::test_string_distance(&cks);
+}
+
+#[test]
+fn test_string_hash() {
+    let cks = setup_default_cpu();
+    super::test_string_hash(&cks);
+}
+
+#[test]
+fn test_string_hash_boolean_backend() {
+    let config = ConfigBuilder::default()
+        .use_hash_backend(HashBackend::Boolean)
+        .build();
+    let (cks, sks) = generate_keys(config);
+    set_server_key(sks);
+    super::test_string_hash(&cks);
+}
+
+#[test]
+fn test_string_json() {
+    let cks = setup_default_cpu();
+    super::test_string_json(&cks);
+}
+
+#[test]
+fn test_utf8_string() {
+    let cks = setup_default_cpu();
+    super::test_utf8_string(&cks);
+}
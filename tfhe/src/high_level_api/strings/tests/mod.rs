@@ -1,5 +1,12 @@
+use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClearString, ClientKey, FheAsciiString, FheStringIsEmpty, FheStringLen};
+use crate::strings::hash::{digest_to_uint256, hmac_sha256, sha256};
+use crate::strings::json::{extract, extract_u64};
+use crate::{
+    ClearString, ClientKey, CompiledRegex, FheAsciiString, FheStringIsEmpty, FheStringLen,
+    FheUint32, FheUint64, FheUint8, FheUtf8String,
+};
+use std::fmt::Write;
 
 mod cpu;
 
@@ -181,3 +188,330 @@ fn test_string_strip(client_key: &ClientKey) {
     let dec = stripped.decrypt(client_key);
     assert_eq!(dec, "The lazy cat");
 }
+
+fn test_string_split(client_key: &ClientKey) {
+    let clear_string = "GET /index.html  200";
+    let string = FheAsciiString::try_encrypt(clear_string, client_key).unwrap();
+
+    let mut split = string.split(&ClearString::new(" ".into()));
+    for expected in clear_string.split(' ') {
+        let (item, is_some) = split.next();
+        assert!(is_some.decrypt(client_key));
+        assert_eq!(item.decrypt(client_key), expected);
+    }
+    let (_, is_some) = split.next();
+    assert!(!is_some.decrypt(client_key));
+
+    let pattern = FheAsciiString::try_encrypt(" ", client_key).unwrap();
+    let mut split = string.rsplitn(&pattern, 2u16);
+    for expected in clear_string.rsplitn(2, ' ') {
+        let (item, is_some) = split.next();
+        assert!(is_some.decrypt(client_key));
+        assert_eq!(item.decrypt(client_key), expected);
+    }
+    let (_, is_some) = split.next();
+    assert!(!is_some.decrypt(client_key));
+
+    let mut split = string.split_ascii_whitespace();
+    for expected in clear_string.split_ascii_whitespace() {
+        let (item, is_some) = split.next();
+        assert!(is_some.decrypt(client_key));
+        assert_eq!(item.decrypt(client_key), expected);
+    }
+    let (_, is_some) = split.next();
+    assert!(!is_some.decrypt(client_key));
+
+    let (lhs, rhs, found) = string.split_once(&pattern);
+    assert!(found.decrypt(client_key));
+    assert_eq!(lhs.decrypt(client_key), "GET");
+    assert_eq!(rhs.decrypt(client_key), "/index.html  200");
+
+    let (lhs, rhs, found) = string.rsplit_once(&ClearString::new("?".into()));
+    assert!(!found.decrypt(client_key));
+    assert_eq!(lhs.decrypt(client_key), "");
+    assert_eq!(rhs.decrypt(client_key), clear_string);
+}
+
+fn test_string_regex(client_key: &ClientKey) {
+    let string = FheAsciiString::try_encrypt_with_padding("key=42;", 2, client_key).unwrap();
+
+    let regex = CompiledRegex::new(r"^\w+=\d+;$").unwrap();
+    assert!(string.matches_regex(&regex).decrypt(client_key));
+    let regex = CompiledRegex::new(r"^\d").unwrap();
+    assert!(!string.matches_regex(&regex).decrypt(client_key));
+
+    let regex = CompiledRegex::new(r"(\w+)=(\d+)").unwrap();
+    let (start, end, found) = string.find_regex(&regex);
+    assert!(found.decrypt(client_key));
+    let start: u32 = start.decrypt(client_key);
+    let end: u32 = end.decrypt(client_key);
+    assert_eq!((start, end), (0, 6));
+
+    let (captures, found) = string.captures_regex(&regex);
+    assert!(found.decrypt(client_key));
+    let bounds: Vec<(u32, u32)> = captures
+        .iter()
+        .map(|(start, end)| (start.decrypt(client_key), end.decrypt(client_key)))
+        .collect();
+    assert_eq!(bounds, vec![(0, 6), (0, 3), (4, 6)]);
+}
+
+fn test_string_parse(client_key: &ClientKey) {
+    let string = FheAsciiString::try_encrypt_with_padding("-1234", 3, client_key).unwrap();
+
+    let (value, is_valid) = string.parse_i64();
+    assert!(is_valid.decrypt(client_key));
+    let value: i64 = value.decrypt(client_key);
+    assert_eq!(value, -1234);
+
+    let (value, is_valid) = string.parse_u64();
+    assert!(!is_valid.decrypt(client_key));
+    let value: u64 = value.decrypt(client_key);
+    assert_eq!(value, 0);
+
+    let string = FheAsciiString::try_encrypt("C0ffee", client_key).unwrap();
+    let (value, is_valid) = string.parse_u64_radix(16);
+    assert!(is_valid.decrypt(client_key));
+    let value: u64 = value.decrypt(client_key);
+    assert_eq!(value, 0xC0FFEE);
+
+    for clear in [0u64, 9, 1_000_000, u64::MAX] {
+        let n = FheUint64::encrypt(clear, client_key);
+        let string = n.to_decimal_string();
+        assert_eq!(string.decrypt(client_key), clear.to_string());
+
+        // Round trip
+        let (value, is_valid) = string.parse_u64();
+        assert!(is_valid.decrypt(client_key));
+        let value: u64 = value.decrypt(client_key);
+        assert_eq!(value, clear);
+    }
+}
+
+fn test_string_slice(client_key: &ClientKey) {
+    let clear_string = "user=alice;role=admin";
+    let string = FheAsciiString::try_encrypt_with_padding(clear_string, 2, client_key).unwrap();
+
+    assert_eq!(string.substring(5, 10).decrypt(client_key), "alice");
+    assert_eq!(string.truncate(4).decrypt(client_key), "user");
+    let (char, in_bounds) = string.char_at(4);
+    assert!(in_bounds.decrypt(client_key));
+    let char: u8 = char.decrypt(client_key);
+    assert_eq!(char, b'=');
+
+    // Positions returned by find can be used as bounds
+    let (equal, found) = string.find(&ClearString::new("=".into()));
+    assert!(found.decrypt(client_key));
+    let (semicolon, found) = string.find(&ClearString::new(";".into()));
+    assert!(found.decrypt(client_key));
+    let start = equal + 1u32;
+    let value = string.substring(&start, &semicolon);
+    assert_eq!(value.decrypt(client_key), "alice");
+
+    let len = FheUint32::encrypt(100u32, client_key);
+    assert_eq!(string.truncate(&len).decrypt(client_key), clear_string);
+
+    let (char, in_bounds) = string.char_at(&semicolon);
+    assert!(in_bounds.decrypt(client_key));
+    let char: u8 = char.decrypt(client_key);
+    assert_eq!(char, b';');
+
+    let (char, in_bounds) = string.char_at(&len);
+    assert!(!in_bounds.decrypt(client_key));
+    let char: u8 = char.decrypt(client_key);
+    assert_eq!(char, 0);
+}
+
+fn test_string_ordering(client_key: &ClientKey) {
+    let string = FheAsciiString::try_encrypt_with_padding("File2", 1, client_key).unwrap();
+    let other = FheAsciiString::try_encrypt("file10", client_key).unwrap();
+    let clear_other = ClearString::new("FILE2".into());
+
+    let ordering: i8 = string.cmp_ignore_case(&other).decrypt(client_key);
+    assert_eq!(ordering, std::cmp::Ordering::Greater as i8);
+    let ordering: i8 = string.cmp_ignore_case(&clear_other).decrypt(client_key);
+    assert_eq!(ordering, std::cmp::Ordering::Equal as i8);
+    assert!(string.ge_ignore_case(&clear_other).decrypt(client_key));
+    assert!(!string.lt_ignore_case(&clear_other).decrypt(client_key));
+
+    let lowercase = string.to_lowercase();
+    let ordering: i8 = lowercase.natural_cmp(&other).decrypt(client_key);
+    assert_eq!(ordering, std::cmp::Ordering::Less as i8);
+    assert!(lowercase.natural_lt(&other).decrypt(client_key));
+    assert!(!other.natural_le(&lowercase).decrypt(client_key));
+}
+
+fn test_string_distance(client_key: &ClientKey) {
+    let string = FheAsciiString::try_encrypt_with_padding("jonathan", 1, client_key).unwrap();
+    let other = FheAsciiString::try_encrypt("johnathan", client_key).unwrap();
+    let clear_other = ClearString::new("jonatan".into());
+
+    let distance: u16 = string.edit_distance(&other).decrypt(client_key);
+    assert_eq!(distance, 1);
+    let distance: u16 = string.edit_distance(&clear_other).decrypt(client_key);
+    assert_eq!(distance, 1);
+
+    let distance: u16 = string.hamming_distance(&other).decrypt(client_key);
+    assert_eq!(distance, 7);
+
+    assert!(string.fuzzy_eq(&other, 1).decrypt(client_key));
+    assert!(!string.fuzzy_eq(&clear_other, 0).decrypt(client_key));
+}
+
+fn test_string_hash(client_key: &ClientKey) {
+    // Trivial bytes go through the same computations, encrypted ones would be too slow
+    let trivial_bytes = |bytes: &[u8]| -> Vec<FheUint8> {
+        bytes
+            .iter()
+            .map(|byte| FheUint8::encrypt_trivial(*byte))
+            .collect()
+    };
+    let decrypt_digest = |digest: &[FheUint32; 8]| -> String {
+        digest.iter().fold(String::new(), |mut hex, word| {
+            write!(hex, "{:08x}", FheDecrypt::<u32>::decrypt(word, client_key)).unwrap();
+            hex
+        })
+    };
+
+    let message = trivial_bytes(b"what do ya want for nothing?");
+    let key = trivial_bytes(b"Jefe");
+
+    let digest = sha256(&trivial_bytes(b"abc"));
+    assert_eq!(
+        decrypt_digest(&digest),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let digest: U256 = digest_to_uint256(&digest).decrypt(client_key);
+    assert_eq!(
+        digest,
+        U256::from((
+            0xb00361a396177a9cb410ff61f20015ad,
+            0xba7816bf8f01cfea414140de5dae2223
+        ))
+    );
+
+    let mac = hmac_sha256(&key, &message);
+    assert_eq!(
+        decrypt_digest(&mac),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+fn test_string_json(client_key: &ClientKey) {
+    let document = FheAsciiString::try_encrypt(r#"{"id":7,"tag":{"v":"ok"}}"#, client_key).unwrap();
+
+    let (value, found) = extract(&document, &["tag", "v"]);
+    assert!(found.decrypt(client_key));
+    let value: String = value.decrypt(client_key);
+    assert_eq!(value, "ok");
+
+    let (id, is_valid) = extract_u64(&document, &["id"]);
+    assert!(is_valid.decrypt(client_key));
+    let id: u64 = id.decrypt(client_key);
+    assert_eq!(id, 7);
+
+    let (value, found) = extract(&document, &["name"]);
+    assert!(!found.decrypt(client_key));
+    let value: String = value.decrypt(client_key);
+    assert_eq!(value, "");
+}
+
+fn test_utf8_string(client_key: &ClientKey) {
+    let clear_string = "Ça coûte 5€ à Zürich";
+    let string = FheUtf8String::try_encrypt_with_padding(clear_string, 2, client_key).unwrap();
+    assert_eq!(string.decrypt(client_key), clear_string);
+
+    let len: u16 = string.len().decrypt(client_key);
+    assert_eq!(len as usize, clear_string.chars().count());
+    assert!(string.is_valid_utf8().decrypt(client_key));
+
+    assert_eq!(
+        string.to_lowercase().decrypt(client_key),
+        clear_string.to_lowercase()
+    );
+    assert_eq!(
+        string.to_uppercase().decrypt(client_key),
+        clear_string.to_uppercase()
+    );
+    let other = FheUtf8String::try_encrypt("ça COÛTE 5€ À zürich", client_key).unwrap();
+    assert!(string.eq_ignore_case(&other).decrypt(client_key));
+    assert!(!string.eq(&other).decrypt(client_key));
+    assert!(string.eq(clear_string).decrypt(client_key));
+
+    // Positions are in characters, not bytes
+    let char_index = |byte_index: usize| clear_string[..byte_index].chars().count() as u32;
+    let pattern = FheUtf8String::try_encrypt("à", client_key).unwrap();
+    assert!(string.contains(&pattern).decrypt(client_key));
+    let (index, found) = string.find(&pattern);
+    assert!(found.decrypt(client_key));
+    let index: u32 = index.decrypt(client_key);
+    assert_eq!(index, char_index(clear_string.find('à').unwrap()));
+
+    let (index, found) = string.rfind("ü");
+    assert!(found.decrypt(client_key));
+    let index: u32 = index.decrypt(client_key);
+    assert_eq!(index, char_index(clear_string.rfind('ü').unwrap()));
+
+    let (_, found) = string.find("ß");
+    assert!(!found.decrypt(client_key));
+
+    assert!(string.starts_with("Ça").decrypt(client_key));
+    assert!(string.ends_with("rich").decrypt(client_key));
+    assert!(!string.ends_with("Zü").decrypt(client_key));
+
+    let to = FheUtf8String::try_encrypt("EUR", client_key).unwrap();
+    let replaced = string.replace("€", &to);
+    assert_eq!(
+        replaced.decrypt(client_key),
+        clear_string.replace('€', "EUR")
+    );
+
+    // The padding of encrypted patterns is ignored
+    let padded = |str: &str| FheUtf8String::try_encrypt_with_padding(str, 2, client_key).unwrap();
+    let pattern = padded("ü");
+    assert!(string.contains(&pattern).decrypt(client_key));
+    let (index, found) = string.rfind(&pattern);
+    assert!(found.decrypt(client_key));
+    let index: u32 = index.decrypt(client_key);
+    assert_eq!(index, char_index(clear_string.rfind('ü').unwrap()));
+    let (index, found) = string.find(&padded(""));
+    assert!(found.decrypt(client_key));
+    let index: u32 = index.decrypt(client_key);
+    assert_eq!(index, 0);
+    assert!(string.starts_with(&padded("Ça")).decrypt(client_key));
+    assert!(string.ends_with(&padded("rich")).decrypt(client_key));
+    assert!(!string.ends_with(&padded("Zü")).decrypt(client_key));
+
+    let short_string = FheUtf8String::try_encrypt("5€ + 2€", client_key).unwrap();
+    let from = FheUtf8String::from_bytes(
+        "€"
+            .bytes()
+            .chain([0])
+            .map(|b| FheUint8::encrypt(b, client_key))
+            .collect(),
+    );
+    let replaced = short_string.replace(&from, &padded("EUR"));
+    assert_eq!(replaced.decrypt(client_key), "5EUR + 2EUR");
+
+    let ascii = FheAsciiString::try_encrypt("Zurich", client_key).unwrap();
+    let string = FheUtf8String::from(ascii);
+    assert_eq!(string.decrypt(client_key), "Zurich");
+    assert!(string.ends_with("rich").decrypt(client_key));
+
+    // Ill-formed sequences are detected
+    for invalid in [
+        &[0xC3u8, 0x28][..], // missing continuation byte
+        &[0xE0, 0x80, 0x80], // overlong encoding
+        &[0xED, 0xA0, 0x80], // surrogate
+        &[0x61, 0x00, 0x62], // data after padding
+        &[0xF0, 0x9F, 0x98], // truncated code point
+    ] {
+        let bytes = invalid
+            .iter()
+            .map(|b| FheUint8::encrypt(*b, client_key))
+            .collect();
+        let string = FheUtf8String::from_bytes(bytes);
+        assert!(!string.is_valid_utf8().decrypt(client_key));
+    }
+}
@@ -1,15 +1,38 @@
-use crate::{FheBool, FheUint32};
+use crate::high_level_api::strings::ascii::FheSplit;
+use crate::{FheBool, FheInt8, FheUint16, FheUint32, FheUint8};
 
 pub trait FheEqIgnoreCase<Rhs = Self> {
     fn eq_ignore_case(&self, rhs: &Rhs) -> FheBool;
 }
 
+pub trait FheOrdIgnoreCase<Rhs = Self> {
+    fn cmp_ignore_case(&self, rhs: &Rhs) -> FheInt8;
+    fn lt_ignore_case(&self, rhs: &Rhs) -> FheBool;
+    fn le_ignore_case(&self, rhs: &Rhs) -> FheBool;
+    fn gt_ignore_case(&self, rhs: &Rhs) -> FheBool;
+    fn ge_ignore_case(&self, rhs: &Rhs) -> FheBool;
+}
+
+pub trait FheNaturalOrd<Rhs = Self> {
+    fn natural_cmp(&self, rhs: &Rhs) -> FheInt8;
+    fn natural_lt(&self, rhs: &Rhs) -> FheBool;
+    fn natural_le(&self, rhs: &Rhs) -> FheBool;
+    fn natural_gt(&self, rhs: &Rhs) -> FheBool;
+    fn natural_ge(&self, rhs: &Rhs) -> FheBool;
+}
+
 pub trait FheStringMatching<Rhs> {
     fn contains(&self, other: Rhs) -> FheBool;
     fn starts_with(&self, other: Rhs) -> FheBool;
     fn ends_with(&self, other: Rhs) -> FheBool;
 }
 
+pub trait FheStringDistance<Rhs> {
+    fn edit_distance(&self, other: Rhs) -> FheUint16;
+    fn hamming_distance(&self, other: Rhs) -> FheUint16;
+    fn fuzzy_eq(&self, other: Rhs, max_distance: u16) -> FheBool;
+}
+
 pub trait FheStringFind<Rhs> {
     fn find(&self, other: Rhs) -> (FheUint32, FheBool);
     fn rfind(&self, other: Rhs) -> (FheUint32, FheBool);
@@ -43,3 +66,33 @@ where
 {
     fn repeat(&self, count: Count) -> Self;
 }
+
+pub trait FheStringSlice<Index>
+where
+    Self: Sized,
+{
+    fn substring(&self, start: Index, end: Index) -> Self;
+    fn truncate(&self, len: Index) -> Self;
+    fn char_at(&self, index: Index) -> (FheUint8, FheBool);
+}
+
+pub trait FheStringSplit<Rhs> {
+    fn split(&self, pat: Rhs) -> FheSplit;
+    fn rsplit(&self, pat: Rhs) -> FheSplit;
+    fn split_terminator(&self, pat: Rhs) -> FheSplit;
+    fn rsplit_terminator(&self, pat: Rhs) -> FheSplit;
+    fn split_inclusive(&self, pat: Rhs) -> FheSplit;
+}
+
+pub trait FheStringSplitN<Rhs, Count> {
+    fn splitn(&self, pat: Rhs, count: Count) -> FheSplit;
+    fn rsplitn(&self, pat: Rhs, count: Count) -> FheSplit;
+}
+
+pub trait FheStringSplitOnce<Rhs>
+where
+    Self: Sized,
+{
+    fn split_once(&self, pat: Rhs) -> (Self, Self, FheBool);
+    fn rsplit_once(&self, pat: Rhs) -> (Self, Self, FheBool);
+}
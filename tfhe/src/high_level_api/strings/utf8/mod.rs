@@ -0,0 +1,322 @@
+mod ops;
+mod pattern;
+
+use crate::core_crypto::prelude::Cleartext;
+pub use crate::high_level_api::backward_compatibility::strings::FheUtf8StringVersions;
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::{EncryptableString, FheAsciiString};
+use crate::integer::RadixCiphertext;
+use crate::named::Named;
+use crate::prelude::{FheDecrypt, FheTryEncrypt, Tagged};
+use crate::shortint::ciphertext::unchecked_create_trivial_with_lwe_size;
+use crate::{ClientKey, FheBool, FheUint16, Tag};
+use ops::Case;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// An encrypted UTF-8 string
+///
+/// The string is stored as the encryption of its UTF-8 bytes, optionally followed by padding
+/// bytes (encryptions of 0).
+///
+/// Lengths and positions returned by operations are expressed in characters (i.e. Unicode code
+/// points), not in bytes. As the number of bytes of a character is hidden, even strings without
+/// padding have an encrypted length.
+///
+/// Encrypting a `&str` always produces a well-formed UTF-8 string, strings built from ciphertexts
+/// coming from elsewhere can be checked with [FheUtf8String::is_valid_utf8].
+#[derive(Serialize, Deserialize, Versionize, Clone)]
+#[versionize(FheUtf8StringVersions)]
+pub struct FheUtf8String {
+    pub(crate) bytes: Vec<RadixCiphertext>,
+    pub(crate) padded: bool,
+    pub(crate) tag: Tag,
+}
+
+impl Named for FheUtf8String {
+    const NAME: &'static str = "high_level_api::FheUtf8String";
+}
+
+impl Tagged for FheUtf8String {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl FheUtf8String {
+    pub(crate) fn new(bytes: Vec<RadixCiphertext>, padded: bool, tag: Tag) -> Self {
+        Self { bytes, padded, tag }
+    }
+
+    /// Encrypts the string `str` and adds `padding` bytes of padding (encryption of zero)
+    pub fn try_encrypt_with_padding(
+        str: impl AsRef<str>,
+        padding: u32,
+        client_key: &ClientKey,
+    ) -> crate::Result<Self> {
+        Self::try_encrypt(
+            EncryptableString::WithPadding {
+                str: str.as_ref(),
+                padding,
+            },
+            client_key,
+        )
+    }
+
+    /// Creates a string from already encrypted bytes
+    ///
+    /// Each [FheUint8] encrypts a byte of the UTF-8 encoding, and trailing encryptions of 0 are
+    /// treated as padding. Since nothing guarantees that the bytes form well-formed UTF-8,
+    /// [FheUtf8String::is_valid_utf8] should be used to check them.
+    ///
+    /// [FheUint8]: crate::FheUint8
+    pub fn from_bytes(bytes: Vec<crate::FheUint8>) -> Self {
+        let tag = bytes.first().map(|b| b.tag().clone()).unwrap_or_default();
+        let bytes = bytes
+            .into_iter()
+            .map(|byte| byte.into_raw_parts().0)
+            .collect();
+        Self::new(bytes, true, tag)
+    }
+
+    /// Returns the number of characters (Unicode code points) of the string, padding excluded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt_with_padding("déjà", 2, &client_key).unwrap();
+    /// let len: u16 = string.len().decrypt(&client_key);
+    /// assert_eq!(len, 4);
+    /// ```
+    pub fn len(&self) -> FheUint16 {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = ops::len(cpu_key.pbs_key(), &self.bytes);
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support utf8 strings len");
+            }
+        })
+    }
+
+    /// Returns whether the string is empty, i.e. it has no bytes or only padding
+    pub fn is_empty(&self) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let inner = self.bytes.first().map_or_else(
+                    || sks.create_trivial_boolean_block(true),
+                    |first_byte| sks.scalar_eq_parallelized(first_byte, 0u8),
+                );
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support utf8 strings is_empty");
+            }
+        })
+    }
+
+    /// Returns whether the encrypted bytes are well-formed UTF-8, followed by padding
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // "é" is encoded as [0xC3, 0xA9]
+    /// let bytes = [0xC3u8, 0xA9, 0x00]
+    ///     .iter()
+    ///     .map(|b| FheUint8::encrypt(*b, &client_key))
+    ///     .collect();
+    /// let string = FheUtf8String::from_bytes(bytes);
+    /// assert!(string.is_valid_utf8().decrypt(&client_key));
+    ///
+    /// // A lone continuation byte is not valid
+    /// let bytes = [0xA9u8, 0xC3]
+    ///     .iter()
+    ///     .map(|b| FheUint8::encrypt(*b, &client_key))
+    ///     .collect();
+    /// let string = FheUtf8String::from_bytes(bytes);
+    /// assert!(!string.is_valid_utf8().decrypt(&client_key));
+    /// ```
+    pub fn is_valid_utf8(&self) -> FheBool {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = ops::is_valid_utf8(cpu_key.pbs_key(), &self.bytes);
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support utf8 strings validation");
+            }
+        })
+    }
+
+    fn change_case(&self, case: Case) -> Self {
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let bytes = ops::change_case(cpu_key.pbs_key(), &self.bytes, case);
+                Self::new(bytes, self.padded, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support utf8 strings case change");
+            }
+        })
+    }
+
+    /// Returns a new string where letters of the ASCII and Latin-1 Supplement blocks are mapped
+    /// to their lowercase counterpart
+    ///
+    /// Other characters are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("ÇA VA", &client_key).unwrap();
+    /// let lowercase = string.to_lowercase();
+    /// assert_eq!(lowercase.decrypt(&client_key), "ça va");
+    /// ```
+    pub fn to_lowercase(&self) -> Self {
+        self.change_case(Case::Lower)
+    }
+
+    /// Returns a new string where letters of the ASCII and Latin-1 Supplement blocks are mapped
+    /// to their uppercase counterpart
+    ///
+    /// Other characters are left unchanged, this includes 'ß', 'ÿ' and 'µ' whose uppercase is not
+    /// in the Latin-1 Supplement block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("ça va", &client_key).unwrap();
+    /// let uppercase = string.to_uppercase();
+    /// assert_eq!(uppercase.decrypt(&client_key), "ÇA VA");
+    /// ```
+    pub fn to_uppercase(&self) -> Self {
+        self.change_case(Case::Upper)
+    }
+}
+
+impl<'a> FheTryEncrypt<EncryptableString<'a>, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: EncryptableString<'a>, key: &ClientKey) -> Result<Self, Self::Error> {
+        let (str, padding) = value.str_and_padding();
+        if str.contains('\0') {
+            return Err(crate::Error::new(
+                "Input string contains a null character".to_string(),
+            ));
+        }
+
+        let ck = &key.key.key;
+        let num_blocks = ops::num_blocks(ck.parameters().message_modulus(), ops::BYTE_BITS);
+        let bytes = str
+            .bytes()
+            .chain(std::iter::repeat_n(0u8, padding.unwrap_or(0) as usize))
+            .map(|byte| ck.encrypt_radix(byte, num_blocks))
+            .collect();
+
+        Ok(Self::new(
+            bytes,
+            padding.is_some_and(|p| p != 0),
+            key.tag.clone(),
+        ))
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(EncryptableString::NoPadding(value), key)
+    }
+}
+
+impl FheTryEncrypt<&String, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &String, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(EncryptableString::NoPadding(value), key)
+    }
+}
+
+impl FheDecrypt<String> for FheUtf8String {
+    /// Decrypts the string, padding excluded
+    ///
+    /// Invalid UTF-8 sequences are replaced with U+FFFD
+    fn decrypt(&self, key: &ClientKey) -> String {
+        let ck = &key.key.key;
+        let bytes = self
+            .bytes
+            .iter()
+            .map(|byte| ck.decrypt_radix::<u8>(byte))
+            .take_while(|byte| *byte != 0)
+            .collect::<Vec<_>>();
+
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+impl From<FheAsciiString> for FheUtf8String {
+    /// Converts an ASCII string, this does not require any computation as ASCII strings are
+    /// valid UTF-8 strings
+    ///
+    /// Chars are encrypted on 7 bits, they are extended with trivial zero blocks when a byte
+    /// needs more blocks (e.g. with 1 bit of message per block).
+    fn from(value: FheAsciiString) -> Self {
+        let string = value.inner.on_cpu().into_owned();
+        let bytes = string
+            .enc_string
+            .into_iter()
+            .map(|char| {
+                let mut blocks = char.enc_char.blocks;
+                if let Some(last) = blocks.last() {
+                    let num_blocks = ops::num_blocks(last.message_modulus, ops::BYTE_BITS);
+                    let zero = unchecked_create_trivial_with_lwe_size(
+                        Cleartext(0),
+                        last.ct.lwe_size(),
+                        last.message_modulus,
+                        last.carry_modulus,
+                        last.pbs_order,
+                        last.ct.ciphertext_modulus(),
+                    );
+                    blocks.resize(num_blocks, zero);
+                }
+                RadixCiphertext::from(blocks)
+            })
+            .collect();
+        Self::new(bytes, string.padded, value.tag)
+    }
+}
@@ -0,0 +1,825 @@
+//! Server side algorithms backing [FheUtf8String](super::FheUtf8String).
+//!
+//! A string is a sequence of 8-bit radix ciphertexts, each one encrypting a byte of the UTF-8
+//! encoding. Padding is made of trailing encryptions of 0.
+//!
+//! As UTF-8 is self-synchronizing, the bytes of a well-formed pattern can only match a
+//! well-formed string at a code point boundary, this is what allows matching algorithms to work
+//! byte per byte while still returning positions expressed in code points.
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::shortint::MessageModulus;
+use rayon::prelude::*;
+
+pub(super) const BYTE_BITS: u32 = 8;
+pub(super) const LEN_BITS: u32 = 16;
+pub(super) const INDEX_BITS: u32 = 32;
+
+/// A check applied to a single encrypted byte
+type ByteRule = fn(&IntegerServerKey, &RadixCiphertext) -> BooleanBlock;
+
+#[derive(Copy, Clone)]
+pub(super) enum Pattern<'a> {
+    Clear(&'a [u8]),
+    Enc(&'a [RadixCiphertext]),
+    /// Encrypted bytes that may be followed by padding, which is not part of the pattern
+    PaddedEnc(&'a [RadixCiphertext]),
+}
+
+impl Pattern<'_> {
+    /// Returns the number of bytes of the pattern, padding included
+    fn len(&self) -> usize {
+        match self {
+            Self::Clear(bytes) => bytes.len(),
+            Self::Enc(bytes) | Self::PaddedEnc(bytes) => bytes.len(),
+        }
+    }
+
+    fn is_padded(&self) -> bool {
+        matches!(self, Self::PaddedEnc(_))
+    }
+
+    fn eq_at(&self, sks: &IntegerServerKey, byte: &RadixCiphertext, k: usize) -> BooleanBlock {
+        match self {
+            Self::Clear(bytes) => sks.scalar_eq_parallelized(byte, bytes[k]),
+            Self::Enc(bytes) | Self::PaddedEnc(bytes) => sks.eq_parallelized(byte, &bytes[k]),
+        }
+    }
+
+    /// Returns whether the byte at index `k` of the pattern is padding
+    fn is_padding_at(&self, sks: &IntegerServerKey, k: usize) -> BooleanBlock {
+        match self {
+            Self::PaddedEnc(bytes) => sks.scalar_eq_parallelized(&bytes[k], 0u8),
+            Self::Clear(_) | Self::Enc(_) => sks.create_trivial_boolean_block(false),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(super) enum Case {
+    Lower,
+    Upper,
+}
+
+impl Case {
+    /// Applies the same case mapping as [change_case] on a clear string
+    pub(super) fn apply_clear(self, str: &str) -> String {
+        str.chars()
+            .map(|c| match self {
+                Self::Lower if c.is_ascii_uppercase() || (('À'..='Þ').contains(&c) && c != '×') => {
+                    char::from_u32(c as u32 + 0x20).unwrap()
+                }
+                Self::Upper if c.is_ascii_lowercase() || (('à'..='þ').contains(&c) && c != '÷') => {
+                    char::from_u32(c as u32 - 0x20).unwrap()
+                }
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+pub(super) fn num_blocks(message_modulus: MessageModulus, num_bits: u32) -> usize {
+    let message_modulus = message_modulus.0;
+    assert!(message_modulus.is_power_of_two());
+
+    num_bits.div_ceil(message_modulus.ilog2()) as usize
+}
+
+fn all_true(sks: &IntegerServerKey, blocks: Vec<BooleanBlock>) -> BooleanBlock {
+    let blocks = blocks
+        .into_iter()
+        .map(BooleanBlock::into_raw_parts)
+        .collect();
+    BooleanBlock::new_unchecked(sks.are_all_comparisons_block_true(blocks))
+}
+
+fn any_true(sks: &IntegerServerKey, blocks: Vec<BooleanBlock>) -> BooleanBlock {
+    if blocks.is_empty() {
+        return sks.create_trivial_boolean_block(false);
+    }
+
+    let blocks = blocks
+        .into_iter()
+        .map(BooleanBlock::into_raw_parts)
+        .collect();
+    BooleanBlock::new_unchecked(sks.is_at_least_one_comparisons_block_true(blocks))
+}
+
+/// Counts the number of blocks encrypting `true`, the result has `num_bits` bits
+fn count_true(sks: &IntegerServerKey, flags: Vec<BooleanBlock>, num_bits: u32) -> RadixCiphertext {
+    let num_blocks = num_blocks(sks.message_modulus(), num_bits);
+    let radixes = flags
+        .into_par_iter()
+        .map(|flag| flag.into_radix::<RadixCiphertext>(num_blocks, sks))
+        .collect::<Vec<_>>();
+
+    sks.sum_ciphertexts_parallelized(radixes.iter())
+        .unwrap_or_else(|| sks.create_trivial_zero_radix(num_blocks))
+}
+
+fn map_bytes<F>(bytes: &[RadixCiphertext], f: F) -> Vec<BooleanBlock>
+where
+    F: Fn(&RadixCiphertext) -> BooleanBlock + Sync + Send,
+{
+    bytes.par_iter().map(f).collect()
+}
+
+fn in_range(sks: &IntegerServerKey, byte: &RadixCiphertext, low: u8, high: u8) -> BooleanBlock {
+    let (ge, le) = rayon::join(
+        || sks.scalar_ge_parallelized(byte, low),
+        || sks.scalar_le_parallelized(byte, high),
+    );
+    sks.boolean_bitand(&ge, &le)
+}
+
+fn masked_eq(sks: &IntegerServerKey, byte: &RadixCiphertext, mask: u8, value: u8) -> BooleanBlock {
+    let masked = sks.scalar_bitand_parallelized(byte, mask);
+    sks.scalar_eq_parallelized(&masked, value)
+}
+
+fn is_continuation(sks: &IntegerServerKey, byte: &RadixCiphertext) -> BooleanBlock {
+    masked_eq(sks, byte, 0xC0, 0x80)
+}
+
+/// Returns, for each byte, whether it is the first byte of a code point
+///
+/// Padding bytes are not the start of a code point
+fn char_starts(sks: &IntegerServerKey, bytes: &[RadixCiphertext]) -> Vec<BooleanBlock> {
+    bytes
+        .par_iter()
+        .map(|byte| {
+            let (is_cont, is_zero) = rayon::join(
+                || is_continuation(sks, byte),
+                || sks.scalar_eq_parallelized(byte, 0u8),
+            );
+            sks.boolean_bitnot(&sks.boolean_bitor(&is_cont, &is_zero))
+        })
+        .collect()
+}
+
+/// Returns the number of code points, on [LEN_BITS] bits
+pub(super) fn len(sks: &IntegerServerKey, bytes: &[RadixCiphertext]) -> RadixCiphertext {
+    count_true(sks, char_starts(sks, bytes), LEN_BITS)
+}
+
+/// Returns whether the bytes are a well-formed UTF-8 sequence followed by padding
+///
+/// This rejects invalid lead bytes, missing or unexpected continuation bytes, overlong
+/// encodings, surrogates, code points above U+10FFFF and non-zero bytes after a zero byte.
+pub(super) fn is_valid_utf8(sks: &IntegerServerKey, bytes: &[RadixCiphertext]) -> BooleanBlock {
+    let is_zero = map_bytes(bytes, |b| sks.scalar_eq_parallelized(b, 0u8));
+    let is_ascii = map_bytes(bytes, |b| sks.scalar_lt_parallelized(b, 0x80u8));
+    let is_cont = map_bytes(bytes, |b| is_continuation(sks, b));
+    let is_lead2 = map_bytes(bytes, |b| in_range(sks, b, 0xC2, 0xDF));
+    let is_lead3 = map_bytes(bytes, |b| masked_eq(sks, b, 0xF0, 0xE0));
+    let is_lead4 = map_bytes(bytes, |b| in_range(sks, b, 0xF0, 0xF4));
+
+    // Restrictions on the byte following some specific lead bytes, see the table of well-formed
+    // byte sequences in the Unicode standard (Table 3-7)
+    let second_byte_rules: [(u8, ByteRule); 4] = [
+        (0xE0, |sks, b| sks.scalar_ge_parallelized(b, 0xA0u8)),
+        (0xED, |sks, b| sks.scalar_le_parallelized(b, 0x9Fu8)),
+        (0xF0, |sks, b| sks.scalar_ge_parallelized(b, 0x90u8)),
+        (0xF4, |sks, b| sks.scalar_le_parallelized(b, 0x8Fu8)),
+    ];
+    let second_byte_checks = second_byte_rules
+        .iter()
+        .map(|(lead, rule)| {
+            let is_lead = map_bytes(bytes, |b| sks.scalar_eq_parallelized(b, *lead));
+            let follows_rule = map_bytes(bytes, |b| rule(sks, b));
+            (is_lead, follows_rule)
+        })
+        .collect::<Vec<_>>();
+
+    let n = bytes.len();
+    let checks = (0..n + 3)
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let mut checks = Vec::new();
+
+            // A continuation byte is expected iff one of the previous lead bytes
+            // still needs continuation bytes
+            let mut lead_flags = Vec::new();
+            for (lead, num_cont) in [(&is_lead2, 1), (&is_lead3, 2), (&is_lead4, 3)] {
+                for distance in 1..=num_cont {
+                    if let Some(flag) = i.checked_sub(distance).and_then(|j| lead.get(j)) {
+                        lead_flags.push(flag);
+                    }
+                }
+            }
+            let expects_cont = lead_flags
+                .into_iter()
+                .cloned()
+                .reduce(|lhs, rhs| sks.boolean_bitor(&lhs, &rhs));
+
+            if i < n {
+                let is_valid_byte = [&is_cont[i], &is_lead2[i], &is_lead3[i], &is_lead4[i]]
+                    .into_iter()
+                    .fold(is_ascii[i].clone(), |acc, flag| {
+                        sks.boolean_bitor(&acc, flag)
+                    });
+                checks.push(is_valid_byte);
+
+                let cont_as_expected = expects_cont.map_or_else(
+                    || sks.boolean_bitnot(&is_cont[i]),
+                    |expects_cont| {
+                        sks.boolean_bitnot(&sks.boolean_bitxor(&expects_cont, &is_cont[i]))
+                    },
+                );
+                checks.push(cont_as_expected);
+
+                if i > 0 {
+                    for (is_lead, follows_rule) in &second_byte_checks {
+                        let violation = sks
+                            .boolean_bitand(&is_lead[i - 1], &sks.boolean_bitnot(&follows_rule[i]));
+                        checks.push(sks.boolean_bitnot(&violation));
+                    }
+                }
+
+                if i + 1 < n {
+                    // Once the padding started, only padding can follow
+                    let violation =
+                        sks.boolean_bitand(&is_zero[i], &sks.boolean_bitnot(&is_zero[i + 1]));
+                    checks.push(sks.boolean_bitnot(&violation));
+                }
+            } else if let Some(expects_cont) = expects_cont {
+                // The string must not end in the middle of a code point
+                checks.push(sks.boolean_bitnot(&expects_cont));
+            }
+
+            checks
+        })
+        .collect::<Vec<_>>();
+
+    all_true(sks, checks)
+}
+
+/// Maps ASCII and Latin-1 Supplement letters to the requested case, other code points are left
+/// unchanged
+pub(super) fn change_case(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    case: Case,
+) -> Vec<RadixCiphertext> {
+    // Latin-1 letters are encoded as 0xC3 followed by a byte in the given range, except for the
+    // multiplication (×) and division (÷) signs
+    let (ascii_range, latin1_range, latin1_excluded) = match case {
+        Case::Lower => ((b'A', b'Z'), (0x80, 0x9E), 0x97u8),
+        Case::Upper => ((b'a', b'z'), (0xA0, 0xBE), 0xB7u8),
+    };
+
+    bytes
+        .par_iter()
+        .enumerate()
+        .map(|(i, byte)| {
+            let (is_ascii_letter, is_latin1_letter) = rayon::join(
+                || in_range(sks, byte, ascii_range.0, ascii_range.1),
+                || {
+                    let previous = i.checked_sub(1).map(|j| &bytes[j])?;
+                    let (after_c3, (in_latin1_range, not_excluded)) = rayon::join(
+                        || sks.scalar_eq_parallelized(previous, 0xC3u8),
+                        || {
+                            rayon::join(
+                                || in_range(sks, byte, latin1_range.0, latin1_range.1),
+                                || sks.scalar_ne_parallelized(byte, latin1_excluded),
+                            )
+                        },
+                    );
+                    let is_letter = sks.boolean_bitand(&in_latin1_range, &not_excluded);
+                    Some(sks.boolean_bitand(&after_c3, &is_letter))
+                },
+            );
+
+            let should_change = match is_latin1_letter {
+                Some(is_latin1_letter) => sks.boolean_bitor(&is_ascii_letter, &is_latin1_letter),
+                None => is_ascii_letter,
+            };
+
+            let changed = match case {
+                Case::Lower => sks.scalar_add_parallelized(byte, 0x20u8),
+                Case::Upper => sks.scalar_sub_parallelized(byte, 0x20u8),
+            };
+            sks.cmux_parallelized(&should_change, &changed, byte)
+        })
+        .collect()
+}
+
+/// Returns whether both strings are equal, padding excluded
+pub(super) fn eq(
+    sks: &IntegerServerKey,
+    lhs: &[RadixCiphertext],
+    rhs: Pattern<'_>,
+) -> BooleanBlock {
+    if let Pattern::Clear(clear) = rhs {
+        if clear.len() > lhs.len() {
+            return sks.create_trivial_boolean_block(false);
+        }
+    }
+
+    let n = lhs.len().max(rhs.len());
+    let checks = (0..n)
+        .into_par_iter()
+        .map(|i| match (lhs.get(i), rhs) {
+            (Some(byte), Pattern::Clear(clear)) => {
+                sks.scalar_eq_parallelized(byte, clear.get(i).copied().unwrap_or(0))
+            }
+            (Some(byte), Pattern::Enc(other) | Pattern::PaddedEnc(other)) => {
+                other.get(i).map_or_else(
+                    || sks.scalar_eq_parallelized(byte, 0u8),
+                    |other_byte| sks.eq_parallelized(byte, other_byte),
+                )
+            }
+            (None, Pattern::Enc(other) | Pattern::PaddedEnc(other)) => {
+                sks.scalar_eq_parallelized(&other[i], 0u8)
+            }
+            (None, Pattern::Clear(_)) => unreachable!(),
+        })
+        .collect();
+
+    all_true(sks, checks)
+}
+
+/// Returns whether the pattern matches the bytes starting at `position`
+///
+/// The padding of a pattern matches any byte, as well as the end of the bytes
+fn is_match_at(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+    position: usize,
+) -> BooleanBlock {
+    let checks = (0..pattern.len())
+        .into_par_iter()
+        .map(|k| match bytes.get(position + k) {
+            Some(byte) if pattern.is_padded() => {
+                let (is_eq, is_padding) = rayon::join(
+                    || pattern.eq_at(sks, byte, k),
+                    || pattern.is_padding_at(sks, k),
+                );
+                sks.boolean_bitor(&is_eq, &is_padding)
+            }
+            Some(byte) => pattern.eq_at(sks, byte, k),
+            None => pattern.is_padding_at(sks, k),
+        })
+        .collect();
+
+    all_true(sks, checks)
+}
+
+/// Returns whether the pattern matches the bytes starting at `position`, and is followed by
+/// padding or by the end of the bytes
+///
+/// The padding of a pattern must be aligned with the padding (or the end) of the bytes
+fn is_match_at_end(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+    position: usize,
+) -> BooleanBlock {
+    let checks = (0..=pattern.len())
+        .into_par_iter()
+        .map(|k| match (bytes.get(position + k), k < pattern.len()) {
+            (Some(byte), true) => pattern.eq_at(sks, byte, k),
+            (None, true) => pattern.is_padding_at(sks, k),
+            (Some(byte), false) => sks.scalar_eq_parallelized(byte, 0u8),
+            (None, false) => sks.create_trivial_boolean_block(true),
+        })
+        .collect();
+
+    all_true(sks, checks)
+}
+
+/// Returns for each position where the pattern can fit whether it matches there
+///
+/// The pattern must not be empty. A padded pattern can start at any position, as its padding
+/// can go past the end of the bytes.
+fn match_positions(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+) -> Vec<BooleanBlock> {
+    assert_ne!(pattern.len(), 0);
+
+    let last_position = if pattern.is_padded() {
+        bytes.len()
+    } else {
+        let Some(last_position) = bytes.len().checked_sub(pattern.len()) else {
+            return vec![];
+        };
+        last_position
+    };
+
+    (0..=last_position)
+        .into_par_iter()
+        .map(|position| is_match_at(sks, bytes, pattern, position))
+        .collect()
+}
+
+pub(super) fn contains(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+) -> BooleanBlock {
+    if pattern.len() == 0 {
+        return sks.create_trivial_boolean_block(true);
+    }
+
+    any_true(sks, match_positions(sks, bytes, pattern))
+}
+
+pub(super) fn starts_with(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+) -> BooleanBlock {
+    if !pattern.is_padded() && pattern.len() > bytes.len() {
+        return sks.create_trivial_boolean_block(false);
+    }
+
+    is_match_at(sks, bytes, pattern, 0)
+}
+
+pub(super) fn ends_with(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    padded: bool,
+    pattern: Pattern<'_>,
+) -> BooleanBlock {
+    let n = bytes.len();
+    if !padded && !pattern.is_padded() {
+        let Some(last_position) = n.checked_sub(pattern.len()) else {
+            return sks.create_trivial_boolean_block(false);
+        };
+        return is_match_at(sks, bytes, pattern, last_position);
+    }
+
+    // With padding, the match must be followed by the end of the bytes or by padding
+    let last_position = if pattern.is_padded() {
+        n
+    } else {
+        let Some(last_position) = n.checked_sub(pattern.len()) else {
+            return sks.create_trivial_boolean_block(false);
+        };
+        last_position
+    };
+    let checks = (0..=last_position)
+        .into_par_iter()
+        .map(|position| is_match_at_end(sks, bytes, pattern, position))
+        .collect();
+
+    any_true(sks, checks)
+}
+
+/// Returns the index, in code points, of the first match as well as whether there was a match
+///
+/// The index is on [INDEX_BITS] bits and encrypts 0 if there was no match
+pub(super) fn find(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+) -> (RadixCiphertext, BooleanBlock) {
+    let num_blocks = num_blocks(sks.message_modulus(), INDEX_BITS);
+    if pattern.len() == 0 {
+        return (
+            sks.create_trivial_zero_radix(num_blocks),
+            sks.create_trivial_boolean_block(true),
+        );
+    }
+
+    let (matches, starts) = rayon::join(
+        || match_positions(sks, bytes, pattern),
+        || char_starts(sks, bytes),
+    );
+    if matches.is_empty() {
+        return (
+            sks.create_trivial_zero_radix(num_blocks),
+            sks.create_trivial_boolean_block(false),
+        );
+    }
+
+    // seen[q] is true if there is a match at a position <= q
+    let mut seen = Vec::with_capacity(matches.len());
+    seen.push(matches[0].clone());
+    for is_match in &matches[1..] {
+        let previous = seen.last().unwrap();
+        seen.push(sks.boolean_bitor(previous, is_match));
+    }
+    let found = seen.last().unwrap().clone();
+
+    // The index of the first match is the number of code points that start before it
+    let counted = starts
+        .par_iter()
+        .enumerate()
+        .map(|(q, is_start)| {
+            let seen = seen.get(q).unwrap_or(&found);
+            sks.boolean_bitand(is_start, &sks.boolean_bitnot(seen))
+        })
+        .collect();
+    let mut index = count_true(sks, counted, INDEX_BITS);
+    sks.zero_out_if_condition_is_false(&mut index, found.as_ref());
+
+    (index, found)
+}
+
+/// Returns the index, in code points, of the last match as well as whether there was a match
+///
+/// The index is on [INDEX_BITS] bits and encrypts 0 if there was no match
+pub(super) fn rfind(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    pattern: Pattern<'_>,
+) -> (RadixCiphertext, BooleanBlock) {
+    if pattern.len() == 0 {
+        let starts = char_starts(sks, bytes);
+        return (
+            count_true(sks, starts, INDEX_BITS),
+            sks.create_trivial_boolean_block(true),
+        );
+    }
+
+    let (matches, starts) = rayon::join(
+        || match_positions(sks, bytes, pattern),
+        || char_starts(sks, bytes),
+    );
+    if matches.is_empty() {
+        return (
+            sks.create_trivial_zero_radix(num_blocks(sks.message_modulus(), INDEX_BITS)),
+            sks.create_trivial_boolean_block(false),
+        );
+    }
+
+    // later[q] is true if there is a match at a position > q
+    let mut later = vec![sks.create_trivial_boolean_block(false); starts.len().max(matches.len())];
+    for q in (0..matches.len() - 1).rev() {
+        later[q] = sks.boolean_bitor(&later[q + 1], &matches[q + 1]);
+    }
+    let found = sks.boolean_bitor(&later[0], &matches[0]);
+
+    // The index of the last match is the number of code points that start before it
+    let counted = starts
+        .into_par_iter()
+        .zip(later.par_iter())
+        .map(|(is_start, is_before_match)| sks.boolean_bitand(&is_start, is_before_match))
+        .collect();
+
+    (count_true(sks, counted, INDEX_BITS), found)
+}
+
+/// Returns the emissions of [replace] for the empty pattern, which matches before each code
+/// point and at the end
+fn empty_pattern_emissions(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+) -> (Vec<BooleanBlock>, Vec<BooleanBlock>) {
+    let (mut inserts, keeps) = rayon::join(
+        || char_starts(sks, bytes),
+        || {
+            bytes
+                .par_iter()
+                .map(|byte| sks.scalar_ne_parallelized(byte, 0u8))
+                .collect::<Vec<_>>()
+        },
+    );
+    inserts.push(sks.create_trivial_boolean_block(true));
+    (inserts, keeps)
+}
+
+/// Returns the emissions of [replace] for a non empty pattern
+///
+/// Matches are accepted from left to right, a match overlapping a previously accepted one is
+/// ignored. The padding of a pattern does not overlap anything.
+fn match_emissions(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    from: Pattern<'_>,
+) -> (Vec<BooleanBlock>, Vec<BooleanBlock>) {
+    let n = bytes.len();
+    let from_len = from.len();
+    let trivial_false = sks.create_trivial_boolean_block(false);
+
+    // For a padded pattern, covers[d] is true if a match covers the byte d positions after its
+    // start, i.e. if the byte d of the pattern is not padding
+    let (matches, covers) = rayon::join(
+        || match_positions(sks, bytes, from),
+        || match from {
+            Pattern::PaddedEnc(from_bytes) => Some(
+                from_bytes
+                    .par_iter()
+                    .map(|byte| sks.scalar_ne_parallelized(byte, 0u8))
+                    .collect::<Vec<_>>(),
+            ),
+            Pattern::Clear(_) | Pattern::Enc(_) => None,
+        },
+    );
+
+    let mut inserts: Vec<BooleanBlock> = Vec::with_capacity(n + 1);
+    let mut keeps = Vec::with_capacity(n);
+    for i in 0..n {
+        let window = i.saturating_sub(from_len - 1)..i;
+        let covered = inserts[window.clone()].iter().zip(window).fold(
+            trivial_false.clone(),
+            |acc, (accepted, j)| {
+                covers.as_ref().map_or_else(
+                    || sks.boolean_bitor(&acc, accepted),
+                    |covers| sks.boolean_bitor(&acc, &sks.boolean_bitand(accepted, &covers[i - j])),
+                )
+            },
+        );
+
+        let accepted = matches.get(i).map_or_else(
+            || trivial_false.clone(),
+            |is_match| sks.boolean_bitand(is_match, &sks.boolean_bitnot(&covered)),
+        );
+
+        keeps.push(sks.boolean_bitnot(&sks.boolean_bitor(&covered, &accepted)));
+        inserts.push(accepted);
+    }
+    inserts.push(trivial_false);
+
+    (inserts, keeps)
+}
+
+/// Replaces all non-overlapping matches of `from` by `to`
+///
+/// The padding of `to`, if `to_padded`, is not inserted. Returns the new bytes and whether they
+/// may contain padding
+pub(super) fn replace(
+    sks: &IntegerServerKey,
+    bytes: &[RadixCiphertext],
+    padded: bool,
+    from: Pattern<'_>,
+    to: &[RadixCiphertext],
+    to_padded: bool,
+) -> (Vec<RadixCiphertext>, bool) {
+    let n = bytes.len();
+    let (from_len, to_len) = (from.len(), to.len());
+    if !from.is_padded() && from_len > n {
+        return (bytes.to_vec(), padded);
+    }
+
+    let trivial_false = sks.create_trivial_boolean_block(false);
+
+    // Each input position i may emit `to` (when inserts[i]), and then its own byte
+    // (when keeps[i]). The extra last position only emits `to`.
+    let (inserts, keeps, max_output_len) = if from_len == 0 {
+        let (inserts, keeps) = empty_pattern_emissions(sks, bytes);
+        (inserts, keeps, n + (n + 1) * to_len)
+    } else if let Pattern::PaddedEnc([first_from_byte, ..]) = from {
+        // The pattern is empty if it is only padding, the emissions of both cases are computed
+        // and the right ones are selected
+        let (from_is_empty, ((empty_inserts, empty_keeps), (match_inserts, match_keeps))) =
+            rayon::join(
+                || sks.scalar_eq_parallelized(first_from_byte, 0u8),
+                || {
+                    rayon::join(
+                        || empty_pattern_emissions(sks, bytes),
+                        || match_emissions(sks, bytes, from),
+                    )
+                },
+            );
+        let from_is_not_empty = sks.boolean_bitnot(&from_is_empty);
+        let select = |if_empty: Vec<BooleanBlock>, otherwise: Vec<BooleanBlock>| {
+            if_empty
+                .par_iter()
+                .zip(otherwise.par_iter())
+                .map(|(if_empty, otherwise)| {
+                    let (if_empty, otherwise) = rayon::join(
+                        || sks.boolean_bitand(&from_is_empty, if_empty),
+                        || sks.boolean_bitand(&from_is_not_empty, otherwise),
+                    );
+                    sks.boolean_bitor(&if_empty, &otherwise)
+                })
+                .collect::<Vec<_>>()
+        };
+        let (inserts, keeps) = rayon::join(
+            || select(empty_inserts, match_inserts),
+            || select(empty_keeps, match_keeps),
+        );
+
+        // The empty pattern gives the longest output
+        (inserts, keeps, n + (n + 1) * to_len)
+    } else {
+        let (inserts, keeps) = match_emissions(sks, bytes, from);
+
+        let max_output_len = if to_len <= from_len {
+            n
+        } else {
+            n + (n / from_len) * (to_len - from_len)
+        };
+        (inserts, keeps, max_output_len)
+    };
+
+    // The offsets are at most `max_output_len`, they are sized for it so that long outputs
+    // cannot overflow them
+    let offset_bits = (usize::BITS - max_output_len.leading_zeros()).max(1);
+
+    // A padded `to` only emits its bytes before the padding, the padding bytes are still
+    // inserted but they are encryptions of 0 and do not change the sums of the output bytes
+    let to_emitted_len = to_padded.then(|| {
+        let is_not_padding = map_bytes(to, |byte| sks.scalar_ne_parallelized(byte, 0u8));
+        count_true(sks, is_not_padding, offset_bits)
+    });
+
+    // offsets[i] is where the emission of position i starts
+    let num_offset_blocks = num_blocks(sks.message_modulus(), offset_bits);
+    let emitted = inserts
+        .par_iter()
+        .enumerate()
+        .map(|(i, insert)| {
+            let mut emitted = to_emitted_len.as_ref().map_or_else(
+                || {
+                    let mut emitted = insert
+                        .clone()
+                        .into_radix::<RadixCiphertext>(num_offset_blocks, sks);
+                    sks.scalar_mul_assign_parallelized(&mut emitted, to_len as u64);
+                    emitted
+                },
+                |to_emitted_len| {
+                    let mut emitted = to_emitted_len.clone();
+                    sks.zero_out_if_condition_is_false(&mut emitted, insert.as_ref());
+                    emitted
+                },
+            );
+            if let Some(keep) = keeps.get(i) {
+                let keep = keep
+                    .clone()
+                    .into_radix::<RadixCiphertext>(num_offset_blocks, sks);
+                sks.add_assign_parallelized(&mut emitted, &keep);
+            }
+            emitted
+        })
+        .collect::<Vec<_>>();
+    let mut offsets: Vec<RadixCiphertext> = Vec::with_capacity(n + 2);
+    offsets.push(sks.create_trivial_zero_radix(num_offset_blocks));
+    for emitted in &emitted {
+        offsets.push(sks.add_parallelized(offsets.last().unwrap(), emitted));
+    }
+
+    // offset_is[i][c] is true if offsets[i] == c
+    // A position emits at most `to` and its own byte
+    let max_emitted_per_position = to_len + 1;
+    let offset_is = offsets
+        .par_iter()
+        .enumerate()
+        .map(|(i, offset)| {
+            (0..=max_output_len)
+                .into_par_iter()
+                .map(|c| {
+                    if c > i * max_emitted_per_position {
+                        // The offset cannot be that large
+                        trivial_false.clone()
+                    } else {
+                        sks.scalar_eq_parallelized(offset, c as u64)
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // insert_at[d] is true if a copy of `to` starts at output index d
+    let insert_at = (0..max_output_len)
+        .into_par_iter()
+        .map(|d| {
+            let flags = inserts
+                .par_iter()
+                .enumerate()
+                .map(|(i, insert)| sks.boolean_bitand(insert, &offset_is[i][d]))
+                .collect();
+            any_true(sks, flags)
+        })
+        .collect::<Vec<_>>();
+
+    let num_byte_blocks = num_blocks(sks.message_modulus(), BYTE_BITS);
+    let output = (0..max_output_len)
+        .into_par_iter()
+        .map(|c| {
+            // A kept byte at position i lands right before offsets[i + 1]
+            let kept_bytes =
+                keeps
+                    .par_iter()
+                    .zip(bytes.par_iter())
+                    .enumerate()
+                    .map(|(i, (keep, byte))| {
+                        let condition = sks.boolean_bitand(keep, &offset_is[i + 1][c + 1]);
+                        let mut byte = byte.clone();
+                        sks.zero_out_if_condition_is_false(&mut byte, condition.as_ref());
+                        byte
+                    });
+            let inserted_bytes =
+                to.par_iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k <= c)
+                    .map(|(k, to_byte)| {
+                        let mut to_byte = to_byte.clone();
+                        sks.zero_out_if_condition_is_false(&mut to_byte, insert_at[c - k].as_ref());
+                        to_byte
+                    });
+
+            let candidates = kept_bytes.chain(inserted_bytes).collect::<Vec<_>>();
+            sks.sum_ciphertexts_parallelized(candidates.iter())
+                .unwrap_or_else(|| sks.create_trivial_zero_radix(num_byte_blocks))
+        })
+        .collect();
+
+    let output_padded = padded || from.is_padded() || to_padded || from_len != to_len;
+    (output, output_padded)
+}
@@ -0,0 +1,282 @@
+use super::ops::{self, Case, Pattern};
+use super::FheUtf8String;
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::traits::{
+    FheEqIgnoreCase, FheStringFind, FheStringMatching, FheStringReplace,
+};
+use crate::prelude::FheEq;
+use crate::{FheBool, FheUint32};
+
+impl FheUtf8String {
+    /// Returns the bytes of self to be used as a pattern, its padding is ignored when matching
+    fn as_pattern(&self) -> Pattern<'_> {
+        if self.padded {
+            Pattern::PaddedEnc(&self.bytes)
+        } else {
+            Pattern::Enc(&self.bytes)
+        }
+    }
+}
+
+/// Runs an operation on the CPU server key, panicking on other devices
+#[cfg_attr(not(feature = "gpu"), allow(unused_variables))]
+fn with_cpu_key<R>(
+    op_name: &str,
+    f: impl FnOnce(&crate::integer::ServerKey, &crate::Tag) -> R,
+) -> R {
+    with_internal_keys(|keys| match keys {
+        InternalServerKey::Cpu(cpu_key) => f(cpu_key.pbs_key(), &cpu_key.tag),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("gpu does not support utf8 strings {op_name}");
+        }
+    })
+}
+
+impl FheEq<&Self> for FheUtf8String {
+    fn eq(&self, other: &Self) -> FheBool {
+        with_cpu_key("eq", |sks, tag| {
+            let inner = ops::eq(sks, &self.bytes, Pattern::Enc(&other.bytes));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        !self.eq(other)
+    }
+}
+
+impl FheEq<&str> for FheUtf8String {
+    fn eq(&self, other: &str) -> FheBool {
+        with_cpu_key("eq", |sks, tag| {
+            let inner = ops::eq(sks, &self.bytes, Pattern::Clear(other.as_bytes()));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn ne(&self, other: &str) -> FheBool {
+        !self.eq(other)
+    }
+}
+
+impl FheEqIgnoreCase for FheUtf8String {
+    /// Returns whether both strings are equal, ignoring the case of letters in the ASCII and
+    /// Latin-1 Supplement blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheUtf8String::try_encrypt("Élan", &client_key).unwrap();
+    /// let string2 = FheUtf8String::try_encrypt("éLAN", &client_key).unwrap();
+    /// assert!(string1.eq_ignore_case(&string2).decrypt(&client_key));
+    /// ```
+    fn eq_ignore_case(&self, rhs: &Self) -> FheBool {
+        with_cpu_key("eq_ignore_case", |sks, tag| {
+            let (lhs, rhs) = rayon::join(
+                || ops::change_case(sks, &self.bytes, Case::Lower),
+                || ops::change_case(sks, &rhs.bytes, Case::Lower),
+            );
+            let inner = ops::eq(sks, &lhs, Pattern::Enc(&rhs));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+}
+
+impl FheEqIgnoreCase<&str> for FheUtf8String {
+    fn eq_ignore_case(&self, rhs: &&str) -> FheBool {
+        with_cpu_key("eq_ignore_case", |sks, tag| {
+            let lhs = ops::change_case(sks, &self.bytes, Case::Lower);
+            let rhs = Case::Lower.apply_clear(rhs);
+            let inner = ops::eq(sks, &lhs, Pattern::Clear(rhs.as_bytes()));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+}
+
+impl FheStringMatching<&Self> for FheUtf8String {
+    /// Returns whether the string contains the pattern
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("crème brûlée", &client_key).unwrap();
+    /// let pattern = FheUtf8String::try_encrypt("brû", &client_key).unwrap();
+    /// assert!(string.contains(&pattern).decrypt(&client_key));
+    /// ```
+    fn contains(&self, other: &Self) -> FheBool {
+        with_cpu_key("contains", |sks, tag| {
+            let inner = ops::contains(sks, &self.bytes, other.as_pattern());
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn starts_with(&self, other: &Self) -> FheBool {
+        with_cpu_key("starts_with", |sks, tag| {
+            let inner = ops::starts_with(sks, &self.bytes, other.as_pattern());
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn ends_with(&self, other: &Self) -> FheBool {
+        with_cpu_key("ends_with", |sks, tag| {
+            let inner = ops::ends_with(sks, &self.bytes, self.padded, other.as_pattern());
+            FheBool::new(inner, tag.clone())
+        })
+    }
+}
+
+impl FheStringMatching<&str> for FheUtf8String {
+    fn contains(&self, other: &str) -> FheBool {
+        with_cpu_key("contains", |sks, tag| {
+            let inner = ops::contains(sks, &self.bytes, Pattern::Clear(other.as_bytes()));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn starts_with(&self, other: &str) -> FheBool {
+        with_cpu_key("starts_with", |sks, tag| {
+            let inner = ops::starts_with(sks, &self.bytes, Pattern::Clear(other.as_bytes()));
+            FheBool::new(inner, tag.clone())
+        })
+    }
+
+    fn ends_with(&self, other: &str) -> FheBool {
+        with_cpu_key("ends_with", |sks, tag| {
+            let pattern = Pattern::Clear(other.as_bytes());
+            let inner = ops::ends_with(sks, &self.bytes, self.padded, pattern);
+            FheBool::new(inner, tag.clone())
+        })
+    }
+}
+
+impl FheStringFind<&Self> for FheUtf8String {
+    /// Finds the first occurrence of a pattern
+    ///
+    /// Returns the index, in characters, of the start of the first match as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("naïve café", &client_key).unwrap();
+    /// let pattern = FheUtf8String::try_encrypt("café", &client_key).unwrap();
+    /// let (index, found) = string.find(&pattern);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// let index: u32 = index.decrypt(&client_key);
+    /// // "naïve café".find("café") would return 7, as it is expressed in bytes
+    /// assert_eq!(index, 6);
+    /// ```
+    fn find(&self, other: &Self) -> (FheUint32, FheBool) {
+        with_cpu_key("find", |sks, tag| {
+            let (index, found) = ops::find(sks, &self.bytes, other.as_pattern());
+            (
+                FheUint32::new(index, tag.clone()),
+                FheBool::new(found, tag.clone()),
+            )
+        })
+    }
+
+    /// Finds the last occurrence of a pattern
+    ///
+    /// Returns the index, in characters, of the start of the last match as well as a [FheBool]
+    /// that encrypts `true` if the pattern was found.
+    fn rfind(&self, other: &Self) -> (FheUint32, FheBool) {
+        with_cpu_key("rfind", |sks, tag| {
+            let (index, found) = ops::rfind(sks, &self.bytes, other.as_pattern());
+            (
+                FheUint32::new(index, tag.clone()),
+                FheBool::new(found, tag.clone()),
+            )
+        })
+    }
+}
+
+impl FheStringFind<&str> for FheUtf8String {
+    fn find(&self, other: &str) -> (FheUint32, FheBool) {
+        with_cpu_key("find", |sks, tag| {
+            let (index, found) = ops::find(sks, &self.bytes, Pattern::Clear(other.as_bytes()));
+            (
+                FheUint32::new(index, tag.clone()),
+                FheBool::new(found, tag.clone()),
+            )
+        })
+    }
+
+    fn rfind(&self, other: &str) -> (FheUint32, FheBool) {
+        with_cpu_key("rfind", |sks, tag| {
+            let (index, found) = ops::rfind(sks, &self.bytes, Pattern::Clear(other.as_bytes()));
+            (
+                FheUint32::new(index, tag.clone()),
+                FheBool::new(found, tag.clone()),
+            )
+        })
+    }
+}
+
+impl FheStringReplace<&Self> for FheUtf8String {
+    /// Returns a new string where all non-overlapping occurrences of a pattern are replaced
+    /// by another string
+    ///
+    /// The padding of the pattern and of the replacement is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("€10 + €5", &client_key).unwrap();
+    /// let from = FheUtf8String::try_encrypt("€", &client_key).unwrap();
+    /// let to = FheUtf8String::try_encrypt("£", &client_key).unwrap();
+    /// let replaced = string.replace(&from, &to);
+    ///
+    /// assert_eq!(replaced.decrypt(&client_key), "£10 + £5");
+    /// ```
+    fn replace(&self, from: &Self, to: &Self) -> Self {
+        with_cpu_key("replace", |sks, tag| {
+            let (bytes, padded) = ops::replace(
+                sks,
+                &self.bytes,
+                self.padded,
+                from.as_pattern(),
+                &to.bytes,
+                to.padded,
+            );
+            Self::new(bytes, padded, tag.clone())
+        })
+    }
+}
+
+impl FheStringReplace<&str> for FheUtf8String {
+    fn replace(&self, from: &str, to: &Self) -> Self {
+        with_cpu_key("replace", |sks, tag| {
+            let from = Pattern::Clear(from.as_bytes());
+            let (bytes, padded) =
+                ops::replace(sks, &self.bytes, self.padded, from, &to.bytes, to.padded);
+            Self::new(bytes, padded, tag.clone())
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use crate::shortint::parameters::{
+    NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+};
+use crate::{
+    set_server_key, ClientKey, CompressedServerKey, ConfigBuilder, FheBool, FheInt32, FheUint32,
+    FheUint8, ServerKey, SquashedNoiseFheUint,
+};
+use rand::random;
+
+#[test]
+fn test_noise_squashing_cpu() {
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+    let cks = ClientKey::generate(config);
+    let sks = CompressedServerKey::new(&cks).decompress();
+    set_server_key(sks);
+
+    for _ in 0..5 {
+        let clear: u32 = random();
+        let ct = FheUint32::encrypt(clear, &cks);
+        let squashed = ct.squash_noise().unwrap();
+        assert_eq!(squashed.num_bits(), 32);
+        let decrypted: u32 = squashed.decrypt(&cks);
+        assert_eq!(decrypted, clear);
+
+        let clear: i32 = random();
+        let ct = FheInt32::encrypt(clear, &cks);
+        let squashed = ct.squash_noise().unwrap();
+        let decrypted: i32 = squashed.decrypt(&cks);
+        assert_eq!(decrypted, clear);
+
+        let clear: bool = random();
+        let ct = FheBool::encrypt(clear, &cks);
+        let squashed = ct.squash_noise().unwrap();
+        let decrypted: bool = squashed.decrypt(&cks);
+        assert_eq!(decrypted, clear);
+    }
+
+    // Non clean carries
+    let a: u8 = random();
+    let b: u8 = random();
+    let ct = FheUint8::encrypt(a, &cks) + FheUint8::encrypt(b, &cks);
+    let squashed = ct.squash_noise().unwrap();
+
+    let mut serialized = vec![];
+    crate::safe_serialization::safe_serialize(&squashed, &mut serialized, 1 << 30).unwrap();
+    let squashed: SquashedNoiseFheUint =
+        crate::safe_serialization::safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+
+    let decrypted: u8 = squashed.decrypt(&cks);
+    assert_eq!(decrypted, a.wrapping_add(b));
+}
+
+#[test]
+fn test_noise_squashing_not_enabled() {
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+    let cks = ClientKey::generate(config);
+    let sks = ServerKey::new(&cks);
+    set_server_key(sks);
+
+    let ct = FheUint32::encrypt(42u32, &cks);
+    assert!(ct.squash_noise().is_err());
+}
@@ -166,6 +166,36 @@ pub trait OverflowingMul<Rhs> {
     fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
 }
 
+pub trait OverflowingPow<Exponent> {
+    type Output;
+
+    fn overflowing_pow(self, exponent: Exponent) -> (Self::Output, FheBool);
+}
+
+pub trait Pow<Exponent> {
+    type Output;
+
+    fn pow(self, exponent: Exponent) -> Self::Output;
+}
+
+pub trait AddMod<Rhs, Modulus> {
+    type Output;
+
+    fn add_mod(self, rhs: Rhs, modulus: Modulus) -> Self::Output;
+}
+
+pub trait MulMod<Rhs, Modulus> {
+    type Output;
+
+    fn mul_mod(self, rhs: Rhs, modulus: Modulus) -> Self::Output;
+}
+
+pub trait PowMod<Exponent, Modulus> {
+    type Output;
+
+    fn pow_mod(self, exponent: Exponent, modulus: Modulus) -> Self::Output;
+}
+
 pub trait BitSlice<Bounds> {
     type Output;
 
@@ -174,6 +204,12 @@ pub trait BitSlice<Bounds> {
         R: RangeBounds<Bounds>;
 }
 
+pub trait SquashNoise {
+    type Output;
+
+    fn squash_noise(&self) -> crate::Result<Self::Output>;
+}
+
 pub trait Tagged {
     fn tag(&self) -> &Tag;
 
@@ -1,3 +1,4 @@
+use crate::high_level_api::fixed_point::{FheFixed, FheFixedId, FheUFixed, FheUFixedId};
 use crate::high_level_api::integers::signed::FheIntId;
 use crate::high_level_api::integers::unsigned::FheUintId;
 use crate::integer::ciphertext::{DataKind, Expandable};
@@ -47,6 +48,10 @@ impl<Id: FheUintId> Expandable for FheUint<Id> {
                 "Tried to expand a FheUint{}  while a string is stored in this slot",
                 Id::num_bits()
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => Err(crate::error!(
+                "Tried to expand a FheUint{} while a fixed-point number is stored in this slot",
+                Id::num_bits()
+            )),
         }
     }
 }
@@ -86,10 +91,73 @@ impl<Id: FheIntId> Expandable for FheInt<Id> {
                 "Tried to expand a FheInt{}  while a string is stored in this slot",
                 Id::num_bits()
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => Err(crate::error!(
+                "Tried to expand a FheInt{} while a fixed-point number is stored in this slot",
+                Id::num_bits()
+            )),
         }
     }
 }
 
+/// Describes what is stored in a slot, for the errors of the fixed-point expansions
+fn stored_kind_name(blocks: &[Ciphertext], kind: DataKind) -> String {
+    let stored_num_bits = num_bits_of_blocks(blocks);
+    match kind {
+        DataKind::Unsigned(_) => format!("a FheUint{stored_num_bits}"),
+        DataKind::Signed(_) => format!("a FheInt{stored_num_bits}"),
+        DataKind::Boolean => "a FheBool".to_string(),
+        DataKind::String { .. } => "a string".to_string(),
+        DataKind::UnsignedFixed {
+            num_fractional_bits,
+            ..
+        } => format!("a FheUFixed of {stored_num_bits} bits ({num_fractional_bits} fractional)"),
+        DataKind::SignedFixed {
+            num_fractional_bits,
+            ..
+        } => format!("a FheFixed of {stored_num_bits} bits ({num_fractional_bits} fractional)"),
+    }
+}
+
+macro_rules! impl_expandable_for_fixed_point (
+    ($fixed:ident, $id_trait:ident, $kind:ident, $radix:ty) => {
+        impl<Id: $id_trait> Expandable for $fixed<Id> {
+            fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+                match kind {
+                    DataKind::$kind {
+                        num_fractional_bits,
+                        ..
+                    } if num_bits_of_blocks(&blocks) as usize == Id::num_bits()
+                        && num_fractional_bits as usize == Id::num_fractional_bits() =>
+                    {
+                        // The expander will be responsible for setting the correct tag
+                        Ok(Self::new(<$radix>::from(blocks), Tag::default()))
+                    }
+                    _ => Err(crate::error!(
+                        "Tried to expand a {} of {} bits ({} fractional) while {} is stored in this slot",
+                        stringify!($fixed),
+                        Id::num_bits(),
+                        Id::num_fractional_bits(),
+                        stored_kind_name(&blocks, kind),
+                    )),
+                }
+            }
+        }
+    }
+);
+
+impl_expandable_for_fixed_point!(
+    FheUFixed,
+    FheUFixedId,
+    UnsignedFixed,
+    crate::integer::RadixCiphertext
+);
+impl_expandable_for_fixed_point!(
+    FheFixed,
+    FheFixedId,
+    SignedFixed,
+    crate::integer::SignedRadixCiphertext
+);
+
 impl Expandable for FheBool {
     fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
         match kind {
@@ -116,6 +184,12 @@ impl Expandable for FheBool {
             DataKind::String { .. } => Err(crate::Error::new(
                 "Tried to expand a FheBool while a string is stored in this slot".to_string(),
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                Err(crate::Error::new(
+                    "Tried to expand a FheBool while a fixed-point number is stored in this slot"
+                        .to_string(),
+                ))
+            }
         }
     }
 }
@@ -0,0 +1,77 @@
+use crate::integer::backward_compatibility::ciphertext::{
+    SquashedNoiseBooleanBlockVersions, SquashedNoiseRadixCiphertextVersions,
+    SquashedNoiseSignedRadixCiphertextVersions,
+};
+use crate::shortint::ciphertext::SquashedNoiseCiphertext;
+use crate::shortint::MessageModulus;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// A radix ciphertext whose blocks went through noise squashing.
+///
+/// To reduce the number of squashing operations, pairs of consecutive blocks
+/// are packed together before squashing, so each squashed block
+/// holds the message of (at most) two original blocks.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseRadixCiphertextVersions)]
+pub struct SquashedNoiseRadixCiphertext {
+    pub(crate) packed_blocks: Vec<SquashedNoiseCiphertext>,
+    pub(crate) original_block_count: usize,
+    pub(crate) message_modulus: MessageModulus,
+}
+
+impl SquashedNoiseRadixCiphertext {
+    pub fn packed_blocks(&self) -> &[SquashedNoiseCiphertext] {
+        &self.packed_blocks
+    }
+
+    /// Returns the number of blocks of the radix ciphertext before squashing
+    pub fn original_block_count(&self) -> usize {
+        self.original_block_count
+    }
+
+    /// Returns the message modulus of the blocks before they were packed and squashed
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+}
+
+/// A signed radix ciphertext whose blocks went through noise squashing.
+///
+/// See [SquashedNoiseRadixCiphertext] for the packing scheme.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseSignedRadixCiphertextVersions)]
+pub struct SquashedNoiseSignedRadixCiphertext {
+    pub(crate) packed_blocks: Vec<SquashedNoiseCiphertext>,
+    pub(crate) original_block_count: usize,
+    pub(crate) message_modulus: MessageModulus,
+}
+
+impl SquashedNoiseSignedRadixCiphertext {
+    pub fn packed_blocks(&self) -> &[SquashedNoiseCiphertext] {
+        &self.packed_blocks
+    }
+
+    /// Returns the number of blocks of the radix ciphertext before squashing
+    pub fn original_block_count(&self) -> usize {
+        self.original_block_count
+    }
+
+    /// Returns the message modulus of the blocks before they were packed and squashed
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+}
+
+/// A [BooleanBlock](crate::integer::BooleanBlock) that went through noise squashing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseBooleanBlockVersions)]
+pub struct SquashedNoiseBooleanBlock {
+    pub(crate) ciphertext: SquashedNoiseCiphertext,
+}
+
+impl SquashedNoiseBooleanBlock {
+    pub fn ciphertext(&self) -> &SquashedNoiseCiphertext {
+        &self.ciphertext
+    }
+}
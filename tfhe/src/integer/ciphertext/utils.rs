@@ -16,12 +16,27 @@ pub enum DataKind {
         n_chars: u32,
         padded: bool,
     },
+    /// The held value is a number of radix blocks, encrypting an unsigned fixed-point number
+    /// with `num_fractional_bits` fractional bits.
+    UnsignedFixed {
+        num_blocks: usize,
+        num_fractional_bits: u32,
+    },
+    /// The held value is a number of radix blocks, encrypting a signed fixed-point number
+    /// with `num_fractional_bits` fractional bits.
+    SignedFixed {
+        num_blocks: usize,
+        num_fractional_bits: u32,
+    },
 }
 
 impl DataKind {
     pub fn num_blocks(self, message_modulus: MessageModulus) -> usize {
         match self {
             Self::Unsigned(n) | Self::Signed(n) => n,
+            Self::UnsignedFixed { num_blocks, .. } | Self::SignedFixed { num_blocks, .. } => {
+                num_blocks
+            }
             Self::Boolean => 1,
             Self::String { n_chars, .. } => {
                 let blocks_per_char = 7u32.div_ceil(message_modulus.0.ilog2());
@@ -59,6 +74,12 @@ where
             (DataKind::String { .. }, _) => Err(crate::Error::new(
                 "Tried to expand an unsigned radix while a string is stored".to_string(),
             )),
+            (DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. }, signed) => {
+                let signedness = if signed { "signed" } else { "unsigned" };
+                Err(crate::error!(
+                    "Tried to expand a {signedness} radix while a fixed-point number is stored"
+                ))
+            }
         }
     }
 }
@@ -76,6 +97,12 @@ impl Expandable for BooleanBlock {
             DataKind::String { .. } => Err(crate::Error::new(
                 "Tried to expand a boolean block while a string is stored".to_string(),
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                Err(crate::Error::new(
+                    "Tried to expand a boolean block while a fixed-point number is stored"
+                        .to_string(),
+                ))
+            }
         }
     }
 }
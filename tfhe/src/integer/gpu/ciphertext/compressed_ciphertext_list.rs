@@ -46,6 +46,12 @@ where
                     "Tried to expand a {signedness} radix while a string is stored"
                 ))
             }
+            (DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. }, signed) => {
+                let signedness = if signed { "signed" } else { "unsigned" };
+                Err(crate::error!(
+                    "Tried to expand a {signedness} radix while a fixed-point number is stored"
+                ))
+            }
         }
     }
 }
@@ -63,6 +69,12 @@ impl CudaExpandable for CudaBooleanBlock {
             DataKind::String { .. } => Err(crate::Error::new(
                 "Tried to expand a boolean block while a string  radix was stored".to_string(),
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                Err(crate::Error::new(
+                    "Tried to expand a boolean block while a fixed-point number was stored"
+                        .to_string(),
+                ))
+            }
         }
     }
 }
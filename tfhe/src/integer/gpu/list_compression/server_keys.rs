@@ -297,9 +297,11 @@ impl CudaDecompressionKey {
                 streams.synchronize();
 
                 let degree = match kind {
-                    DataKind::Unsigned(_) | DataKind::Signed(_) | DataKind::String { .. } => {
-                        Degree::new(message_modulus.0 - 1)
-                    }
+                    DataKind::Unsigned(_)
+                    | DataKind::Signed(_)
+                    | DataKind::String { .. }
+                    | DataKind::UnsignedFixed { .. }
+                    | DataKind::SignedFixed { .. } => Degree::new(message_modulus.0 - 1),
                     DataKind::Boolean => Degree::new(1),
                 };
 
@@ -0,0 +1,368 @@
+use super::ClientKey;
+use crate::conformance::ParameterSetConformant;
+use crate::integer::backward_compatibility::noise_squashing::*;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::{
+    SquashedNoiseBooleanBlock, SquashedNoiseRadixCiphertext, SquashedNoiseSignedRadixCiphertext,
+};
+use crate::integer::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::{NoiseLevel, SquashedNoiseCiphertext};
+use crate::shortint::noise_squashing::NoiseSquashingKeyConformanceParams;
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::{Ciphertext, MessageModulus};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingPrivateKeyVersions)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) key: crate::shortint::noise_squashing::NoiseSquashingPrivateKey,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyVersions)]
+pub struct NoiseSquashingKey {
+    pub(crate) key: crate::shortint::noise_squashing::NoiseSquashingKey,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedNoiseSquashingKeyVersions)]
+pub struct CompressedNoiseSquashingKey {
+    pub(crate) key: crate::shortint::noise_squashing::CompressedNoiseSquashingKey,
+}
+
+impl NoiseSquashingPrivateKey {
+    pub fn new(client_key: &ClientKey, params: NoiseSquashingParameters) -> Self {
+        client_key.new_noise_squashing_private_key(params)
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::noise_squashing::NoiseSquashingPrivateKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::noise_squashing::NoiseSquashingPrivateKey) -> Self {
+        Self { key }
+    }
+
+    pub fn noise_squashing_parameters(&self) -> NoiseSquashingParameters {
+        self.key.noise_squashing_parameters()
+    }
+
+    /// Decrypts a [SquashedNoiseRadixCiphertext] into an unsigned clear value
+    ///
+    /// The value is truncated if `T` has less bits than what the ciphertext encrypts.
+    pub fn decrypt_radix<T>(&self, ciphertext: &SquashedNoiseRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        self.decrypt_packed_blocks(&ciphertext.packed_blocks, ciphertext.message_modulus)
+    }
+
+    /// Decrypts a [SquashedNoiseSignedRadixCiphertext] into a signed clear value
+    pub fn decrypt_signed_radix<T>(&self, ciphertext: &SquashedNoiseSignedRadixCiphertext) -> T
+    where
+        T: RecomposableSignedInteger,
+    {
+        let unpadded_value: T =
+            self.decrypt_packed_blocks(&ciphertext.packed_blocks, ciphertext.message_modulus);
+
+        let num_bits_in_message = ciphertext.message_modulus.0.ilog2();
+        let num_bits_in_ctxt = num_bits_in_message * ciphertext.original_block_count as u32;
+        sign_extend_partial_number(unpadded_value, num_bits_in_ctxt)
+    }
+
+    pub fn decrypt_bool(&self, ciphertext: &SquashedNoiseBooleanBlock) -> bool {
+        self.key
+            .decrypt_squashed_noise_ciphertext(&ciphertext.ciphertext)
+            != 0
+    }
+
+    fn decrypt_packed_blocks<T>(
+        &self,
+        packed_blocks: &[SquashedNoiseCiphertext],
+        message_modulus: MessageModulus,
+    ) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        if packed_blocks.is_empty() {
+            return T::ZERO;
+        }
+
+        // Each packed block holds the message of 2 blocks
+        let bits_in_packed_block = 2 * message_modulus.0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_packed_block);
+
+        for packed_block in packed_blocks {
+            let decrypted = self.key.decrypt_squashed_noise_ciphertext(packed_block);
+            // A packed block message is less than message_modulus^2 which always fits in a u64
+            let decrypted = u64::try_from(decrypted).unwrap();
+            if !recomposer.add_unmasked(decrypted) {
+                // End of T::BITS reached no need to try more
+                // recomposition
+                break;
+            }
+        }
+
+        recomposer.value()
+    }
+}
+
+impl NoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        client_key.new_noise_squashing_key(noise_squashing_private_key)
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::noise_squashing::NoiseSquashingKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::noise_squashing::NoiseSquashingKey) -> Self {
+        Self { key }
+    }
+
+    /// Squashes the noise of the given radix ciphertext
+    ///
+    /// Blocks are packed two-by-two before squashing, so carries have to be empty:
+    /// if they are not, a carry propagation is done on a copy of the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    /// };
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+    ///     cks.as_ref(),
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    /// );
+    /// let noise_squashing_key =
+    ///     NoiseSquashingKey::new(cks.as_ref(), &noise_squashing_private_key);
+    ///
+    /// let msg = 173u8;
+    /// let ct: RadixCiphertext = cks.encrypt(msg);
+    ///
+    /// let squashed = noise_squashing_key
+    ///     .squash_radix_ciphertext_noise(&sks, &ct)
+    ///     .unwrap();
+    ///
+    /// let decrypted: u8 = noise_squashing_private_key.decrypt_radix(&squashed);
+    /// assert_eq!(decrypted, msg);
+    /// ```
+    pub fn squash_radix_ciphertext_noise(
+        &self,
+        src_server_key: &ServerKey,
+        ciphertext: &crate::integer::RadixCiphertext,
+    ) -> crate::Result<SquashedNoiseRadixCiphertext> {
+        let packed_blocks = self.squash_blocks_noise(src_server_key, ciphertext)?;
+
+        Ok(SquashedNoiseRadixCiphertext {
+            packed_blocks,
+            original_block_count: ciphertext.blocks.len(),
+            message_modulus: src_server_key.message_modulus(),
+        })
+    }
+
+    /// Squashes the noise of the given signed radix ciphertext
+    ///
+    /// See [Self::squash_radix_ciphertext_noise]
+    pub fn squash_signed_radix_ciphertext_noise(
+        &self,
+        src_server_key: &ServerKey,
+        ciphertext: &crate::integer::SignedRadixCiphertext,
+    ) -> crate::Result<SquashedNoiseSignedRadixCiphertext> {
+        let packed_blocks = self.squash_blocks_noise(src_server_key, ciphertext)?;
+
+        Ok(SquashedNoiseSignedRadixCiphertext {
+            packed_blocks,
+            original_block_count: ciphertext.blocks.len(),
+            message_modulus: src_server_key.message_modulus(),
+        })
+    }
+
+    pub fn squash_boolean_block_noise(
+        &self,
+        src_server_key: &ServerKey,
+        boolean_block: &BooleanBlock,
+    ) -> crate::Result<SquashedNoiseBooleanBlock> {
+        let mut block = boolean_block.0.clone();
+        if block.degree.get() > 1 {
+            return Err(crate::error!(
+                "BooleanBlock has a degree of {}, expected at most 1",
+                block.degree.get()
+            ));
+        }
+        if block.noise_level() > NoiseLevel::NOMINAL {
+            src_server_key.key.message_extract_assign(&mut block);
+        }
+
+        let ciphertext = self
+            .key
+            .squash_ciphertext_noise(&block, &src_server_key.key);
+
+        Ok(SquashedNoiseBooleanBlock { ciphertext })
+    }
+
+    fn squash_blocks_noise<T>(
+        &self,
+        src_server_key: &ServerKey,
+        ciphertext: &T,
+    ) -> crate::Result<Vec<SquashedNoiseCiphertext>>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ciphertext.blocks().is_empty() {
+            return Err(crate::error!(
+                "Cannot squash the noise of an empty ciphertext"
+            ));
+        }
+
+        let message_modulus = src_server_key.message_modulus();
+        let carry_modulus = src_server_key.carry_modulus();
+        if carry_modulus.0 < message_modulus.0 {
+            return Err(crate::error!(
+                "Noise squashing requires a carry modulus ({carry_modulus:?}) greater or equal \
+                than the message modulus ({message_modulus:?}) to pack blocks"
+            ));
+        }
+
+        let needs_cleaning = !ciphertext.block_carries_are_empty()
+            || ciphertext
+                .blocks()
+                .iter()
+                .any(|block| block.noise_level() > NoiseLevel::NOMINAL);
+
+        let cleaned;
+        let blocks = if needs_cleaning {
+            let mut tmp = ciphertext.clone();
+            src_server_key.full_propagate_parallelized(&mut tmp);
+            cleaned = tmp;
+            cleaned.blocks()
+        } else {
+            ciphertext.blocks()
+        };
+
+        let packed_blocks = blocks
+            .par_chunks(2)
+            .map(|two_blocks| {
+                let packed = pack_two_blocks(src_server_key, two_blocks);
+                self.key
+                    .squash_ciphertext_noise(&packed, &src_server_key.key)
+            })
+            .collect();
+
+        Ok(packed_blocks)
+    }
+}
+
+/// Packs the message of (at most) 2 blocks into one block
+///
+/// The second block, if present, is put in the carry space of the first one.
+fn pack_two_blocks(src_server_key: &ServerKey, two_blocks: &[Ciphertext]) -> Ciphertext {
+    let message_modulus = src_server_key.message_modulus().0;
+
+    match two_blocks {
+        [low] => low.clone(),
+        [low, high] => {
+            let mut packed = src_server_key
+                .key
+                .unchecked_scalar_mul(high, message_modulus.try_into().unwrap());
+            src_server_key.key.unchecked_add_assign(&mut packed, low);
+            packed
+        }
+        _ => unreachable!("Expected chunks of 1 or 2 blocks"),
+    }
+}
+
+impl CompressedNoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        client_key.new_compressed_noise_squashing_key(noise_squashing_private_key)
+    }
+
+    pub fn decompress(&self) -> NoiseSquashingKey {
+        NoiseSquashingKey {
+            key: self.key.decompress(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::noise_squashing::CompressedNoiseSquashingKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(
+        key: crate::shortint::noise_squashing::CompressedNoiseSquashingKey,
+    ) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    pub fn new_noise_squashing_private_key(
+        &self,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        NoiseSquashingPrivateKey {
+            key: self.key.new_noise_squashing_private_key(params),
+        }
+    }
+
+    pub fn new_noise_squashing_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> NoiseSquashingKey {
+        NoiseSquashingKey {
+            key: self
+                .key
+                .new_noise_squashing_key(&noise_squashing_private_key.key),
+        }
+    }
+
+    pub fn new_compressed_noise_squashing_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> CompressedNoiseSquashingKey {
+        CompressedNoiseSquashingKey {
+            key: self
+                .key
+                .new_compressed_noise_squashing_key(&noise_squashing_private_key.key),
+        }
+    }
+}
+
+impl ParameterSetConformant for NoiseSquashingKey {
+    type ParameterSet = NoiseSquashingKeyConformanceParams;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self { key } = self;
+
+        key.is_conformant(parameter_set)
+    }
+}
+
+impl ParameterSetConformant for CompressedNoiseSquashingKey {
+    type ParameterSet = NoiseSquashingKeyConformanceParams;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self { key } = self;
+
+        key.is_conformant(parameter_set)
+    }
+}
@@ -1,7 +1,9 @@
-use super::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use super::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::DefaultRandomGenerator;
-use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
 
 pub use tfhe_csprng::seeders::{Seed, Seeder};
 
@@ -289,6 +291,411 @@ impl ServerKey {
     }
 }
 
+/// Maximum number of bits of precision of the distributions defined by floating point parameters
+const MAX_PRECISION_BITS: u64 = f64::MANTISSA_DIGITS as u64;
+
+impl ServerKey {
+    /// Generates an encrypted `num_block` blocks unsigned integer
+    /// taken uniformly in `[0, excluded_upper_bound[` using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// Unless `excluded_upper_bound` is a power of two, the distribution is not exactly uniform:
+    /// its statistical distance to the uniform distribution is at most
+    /// `2^-statistical_security_bits`, each additional bit makes the generation a bit more costly.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let excluded_upper_bound = 6;
+    ///
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+    ///     Seed(0),
+    ///     excluded_upper_bound,
+    ///     40,
+    ///     size as u64,
+    /// );
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < excluded_upper_bound);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+        &self,
+        seed: Seed,
+        excluded_upper_bound: u64,
+        statistical_security_bits: u64,
+        num_blocks: u64,
+    ) -> RadixCiphertext {
+        assert!(excluded_upper_bound > 0, "The range [0, 0[ is empty");
+
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+        let range_log_size = message_bits_count * num_blocks;
+
+        // Number of bits needed to represent the values of the range
+        let bound_bits_count = u64::from(u64::BITS - (excluded_upper_bound - 1).leading_zeros());
+
+        assert!(
+            bound_bits_count <= range_log_size,
+            "The range asked for a random value (=[0, {excluded_upper_bound}[) does not fit in the available range [0, 2^{range_log_size}[",
+        );
+
+        if excluded_upper_bound.is_power_of_two() {
+            return self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+                seed,
+                bound_bits_count,
+                num_blocks,
+            );
+        }
+
+        // With r uniform in [0, 2^m[, each value of (r * n) >> m in [0, n[ is reached by
+        // floor(2^m / n) or ceil(2^m / n) values of r, so the distance to uniform is below n / 2^m
+        let random_bits_count = bound_bits_count + statistical_security_bits;
+        let product_num_blocks =
+            (random_bits_count + bound_bits_count).div_ceil(message_bits_count);
+
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+            seed,
+            random_bits_count,
+            random_bits_count.div_ceil(message_bits_count),
+        );
+        let random = self.cast_to_unsigned(random, product_num_blocks as usize);
+
+        let product = self.scalar_mul_parallelized(&random, excluded_upper_bound);
+        let result = self.scalar_right_shift_parallelized(&product, random_bits_count);
+
+        self.cast_to_unsigned(result, num_blocks as usize)
+    }
+
+    /// Generates an encrypted unsigned integer taken uniformly in `[0, excluded_upper_bound[`
+    /// using the given seed, where the bound is encrypted.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The result has as many blocks as `excluded_upper_bound`, and encrypts 0 if the bound
+    /// encrypts 0. The statistical distance of the distribution to the uniform distribution is
+    /// at most `2^-statistical_security_bits`.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let excluded_upper_bound = 6u64;
+    /// let ct_bound = cks.encrypt(excluded_upper_bound);
+    ///
+    /// let ct_res =
+    ///     sks.par_generate_oblivious_pseudo_random_unsigned_integer_in_encrypted_range(
+    ///         Seed(0),
+    ///         &ct_bound,
+    ///         40,
+    ///     );
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < excluded_upper_bound);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_unsigned_integer_in_encrypted_range(
+        &self,
+        seed: Seed,
+        excluded_upper_bound: &RadixCiphertext,
+        statistical_security_bits: u64,
+    ) -> RadixCiphertext {
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+
+        let num_blocks = excluded_upper_bound.blocks.len();
+        let bound_bits_count = message_bits_count * num_blocks as u64;
+
+        // Same as the clear bound, with the bound taken at its maximum
+        let random_bits_count = bound_bits_count + statistical_security_bits;
+        let product_num_blocks =
+            (random_bits_count + bound_bits_count).div_ceil(message_bits_count);
+
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+            seed,
+            random_bits_count,
+            random_bits_count.div_ceil(message_bits_count),
+        );
+        let random = self.cast_to_unsigned(random, product_num_blocks as usize);
+        let bound =
+            self.cast_to_unsigned(excluded_upper_bound.clone(), product_num_blocks as usize);
+
+        let product = self.mul_parallelized(&random, &bound);
+        let result = self.scalar_right_shift_parallelized(&product, random_bits_count);
+
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Generates an encrypted boolean which is true with the given `probability`,
+    /// using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability is rounded to a multiple of `2^-precision_bits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is not in `[0, 1]`, or if `precision_bits` is not in `[1, 53]`.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_bernoulli(Seed(0), 0.25, 16);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_bernoulli(
+        &self,
+        seed: Seed,
+        probability: f64,
+        precision_bits: u64,
+    ) -> BooleanBlock {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "The probability (={probability}) must be in [0, 1]"
+        );
+        assert!(
+            (1..=MAX_PRECISION_BITS).contains(&precision_bits),
+            "The precision (={precision_bits} bits) must be in [1, {MAX_PRECISION_BITS}]"
+        );
+
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+
+        let threshold = (probability * (1u64 << precision_bits) as f64).round() as u64;
+
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+            seed,
+            precision_bits,
+            precision_bits.div_ceil(message_bits_count),
+        );
+
+        // random is uniform in [0, 2^precision_bits[
+        self.scalar_lt_parallelized(&random, threshold)
+    }
+
+    /// Generates an encrypted `num_block` blocks signed integer following the discrete Laplace
+    /// distribution centered on 0 of the given `scale`, using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability of `x` is proportional to `exp(-|x| / scale)`, it is rounded to a multiple
+    /// of `2^-precision_bits` and the values whose probability rounds to 0 are never generated.
+    /// The cost of the generation grows linearly with the number of values that can be generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not a positive finite number, if `precision_bits` is not in `[1, 53]`
+    /// or if the values that can be generated do not fit in `num_blocks` blocks.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct_res =
+    ///     sks.par_generate_oblivious_pseudo_random_discrete_laplace(Seed(0), 2.0, 8, size as u64);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i64 = cks.decrypt_signed(&ct_res);
+    /// assert!(dec_result.abs() < 1 << (2 * size - 1));
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_discrete_laplace(
+        &self,
+        seed: Seed,
+        scale: f64,
+        precision_bits: u64,
+        num_blocks: u64,
+    ) -> SignedRadixCiphertext {
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "The scale (={scale}) must be a positive finite number"
+        );
+
+        self.par_generate_oblivious_pseudo_random_symmetric(
+            seed,
+            |x| (-(x as f64) / scale).exp(),
+            precision_bits,
+            num_blocks,
+        )
+    }
+
+    /// Generates an encrypted `num_block` blocks signed integer following the discrete Gaussian
+    /// distribution centered on 0 of the given `std_dev`, using the given seed.
+    /// The encryted value is oblivious to the server.
+    /// It can be useful to make server random generation deterministic.
+    ///
+    /// The probability of `x` is proportional to `exp(-x^2 / (2 * std_dev^2))`, it is rounded
+    /// to a multiple of `2^-precision_bits` and the values whose probability rounds to 0 are
+    /// never generated. The cost of the generation grows linearly with the number of values that
+    /// can be generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is not a positive finite number, if `precision_bits` is not in
+    /// `[1, 53]` or if the values that can be generated do not fit in `num_blocks` blocks.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct_res =
+    ///     sks.par_generate_oblivious_pseudo_random_discrete_gaussian(Seed(0), 2.0, 8, size as u64);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i64 = cks.decrypt_signed(&ct_res);
+    /// assert!(dec_result.abs() < 1 << (2 * size - 1));
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_discrete_gaussian(
+        &self,
+        seed: Seed,
+        std_dev: f64,
+        precision_bits: u64,
+        num_blocks: u64,
+    ) -> SignedRadixCiphertext {
+        assert!(
+            std_dev > 0.0 && std_dev.is_finite(),
+            "The standard deviation (={std_dev}) must be a positive finite number"
+        );
+
+        let two_variances = 2.0 * std_dev * std_dev;
+        self.par_generate_oblivious_pseudo_random_symmetric(
+            seed,
+            |x| (-((x as f64) * (x as f64)) / two_variances).exp(),
+            precision_bits,
+            num_blocks,
+        )
+    }
+
+    /// Generates a signed integer whose probability to be `x` is proportional to `weight(|x|)`
+    ///
+    /// `weight` must be decreasing and log-concave, so that its tail can be bounded.
+    /// A uniform value r on `precision_bits` bits is compared to the clear cumulative
+    /// distribution function, the result is the number of steps of the function below r.
+    fn par_generate_oblivious_pseudo_random_symmetric<F>(
+        &self,
+        seed: Seed,
+        weight: F,
+        precision_bits: u64,
+        num_blocks: u64,
+    ) -> SignedRadixCiphertext
+    where
+        F: Fn(u64) -> f64,
+    {
+        assert!(
+            (1..=MAX_PRECISION_BITS).contains(&precision_bits),
+            "The precision (={precision_bits} bits) must be in [1, {MAX_PRECISION_BITS}]"
+        );
+
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+        let range_log_size = message_bits_count * num_blocks;
+        let max_magnitude = u64::MAX
+            .checked_shr((u64::BITS as u64 + 1).saturating_sub(range_log_size) as u32)
+            .unwrap_or(0);
+
+        // The weights of 0, 1, ..., up to the magnitude where the mass of the two tails is
+        // negligible compared to the precision
+        let tail_limit = weight(0) * 2.0f64.powi(-(precision_bits as i32) - 2);
+        let mut weights = vec![weight(0)];
+        loop {
+            let x = weights.len() as u64;
+            let w = weight(x);
+            // As the weights are log-concave, the tail starting at x is bounded by a geometric
+            // series
+            let ratio = w / weights[weights.len() - 1];
+            if w == 0.0 || w / (1.0 - ratio) < tail_limit {
+                break;
+            }
+
+            assert!(
+                x <= max_magnitude,
+                "The distribution does not fit in the {range_log_size} bits of the signed integer"
+            );
+            weights.push(w);
+        }
+
+        let magnitude = weights.len() as u64 - 1;
+        let total = weights[0] + 2.0 * weights[1..].iter().sum::<f64>();
+        let scale = (1u64 << precision_bits) as f64;
+
+        // The thresholds of the cumulative distribution function, P(X <= x) for x in
+        // [-magnitude, magnitude[
+        let mut cumulative = 0.0;
+        let thresholds: Vec<u64> = weights
+            .iter()
+            .rev()
+            .chain(weights[1..].iter())
+            .take(2 * magnitude as usize)
+            .map(|w| {
+                cumulative += w / total;
+                (cumulative * scale).round() as u64
+            })
+            .collect();
+
+        // A null threshold is always passed and one of 2^precision_bits never is, only the
+        // others have to be compared
+        let always_passed = thresholds.iter().filter(|&&t| t == 0).count() as u64;
+        let thresholds: Vec<u64> = thresholds
+            .into_iter()
+            .filter(|&t| t != 0 && t < (1u64 << precision_bits))
+            .collect();
+
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+            seed,
+            precision_bits,
+            precision_bits.div_ceil(message_bits_count),
+        );
+
+        let passed: Vec<SignedRadixCiphertext> = thresholds
+            .par_iter()
+            .map(|&threshold| {
+                self.scalar_ge_parallelized(&random, threshold)
+                    .into_radix(num_blocks as usize, self)
+            })
+            .collect();
+
+        let count = self
+            .sum_ciphertexts_parallelized(&passed)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks as usize));
+
+        self.scalar_sub_parallelized(&count, magnitude as i64 - always_passed as i64)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
 
@@ -349,4 +756,139 @@ pub(crate) mod test {
             result as u64
         });
     }
+
+    #[test]
+    fn oprf_test_range_uniformity_ci_run_filter() {
+        let sample_count: usize = 1_000;
+
+        let p_value_limit: f64 = 0.000_01;
+
+        let num_blocks = 2;
+
+        use crate::integer::gen_keys_radix;
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+        let (ck, sk) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+
+        let test_uniformity = |distinct_values: u64, f: &(dyn Fn(usize) -> u64 + Sync)| {
+            test_uniformity(sample_count, p_value_limit, distinct_values, f)
+        };
+
+        let excluded_upper_bound = 5;
+
+        test_uniformity(excluded_upper_bound, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_in_range(
+                Seed(seed as u128),
+                excluded_upper_bound,
+                8,
+                num_blocks as u64,
+            );
+            ck.decrypt(&img)
+        });
+
+        let excluded_upper_bound = 6;
+        let ct_bound = ck.encrypt(excluded_upper_bound);
+
+        test_uniformity(excluded_upper_bound, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_in_encrypted_range(
+                Seed(seed as u128),
+                &ct_bound,
+                8,
+            );
+            ck.decrypt(&img)
+        });
+    }
+
+    #[test]
+    fn oprf_test_bernoulli_ci_run_filter() {
+        use rayon::prelude::*;
+
+        let sample_count: usize = 1_000;
+
+        let probability = 0.25;
+
+        use crate::integer::gen_keys_radix;
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+        let (ck, sk) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 1);
+
+        let true_count = (0..sample_count)
+            .into_par_iter()
+            .filter(|&seed| {
+                let img = sk.par_generate_oblivious_pseudo_random_bernoulli(
+                    Seed(seed as u128),
+                    probability,
+                    8,
+                );
+                ck.decrypt_bool(&img)
+            })
+            .count();
+
+        let frequency = true_count as f64 / sample_count as f64;
+
+        // The standard deviation of the frequency is below 0.014
+        assert!(
+            (frequency - probability).abs() < 0.06,
+            "frequency (={frequency}) is too far from the probability (={probability})"
+        );
+    }
+
+    #[test]
+    fn oprf_test_symmetric_distributions_ci_run_filter() {
+        use rayon::prelude::*;
+
+        let sample_count: usize = 500;
+
+        let num_blocks = 4;
+
+        use crate::integer::gen_keys_radix;
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+        let (ck, sk) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+
+        // Checks the values are small and the empirical mean and variance are close to the
+        // expected ones
+        let check_distribution = |expected_variance: f64, f: &(dyn Fn(usize) -> i64 + Sync)| {
+            let values: Vec<i64> = (0..sample_count).into_par_iter().map(f).collect();
+
+            assert!(values.iter().all(|value| value.abs() < 64));
+
+            let mean = values.iter().sum::<i64>() as f64 / sample_count as f64;
+            let variance = values
+                .iter()
+                .map(|&value| (value as f64 - mean).powi(2))
+                .sum::<f64>()
+                / sample_count as f64;
+
+            assert!(
+                mean.abs() < 5.0 * (expected_variance / sample_count as f64).sqrt(),
+                "mean (={mean}) is too far from 0"
+            );
+            assert!(
+                (0.5 * expected_variance..1.5 * expected_variance).contains(&variance),
+                "variance (={variance}) is too far from {expected_variance}"
+            );
+        };
+
+        let scale: f64 = 2.0;
+        // Variance of the discrete Laplace distribution of parameter exp(-1 / scale)
+        let q = (-1.0 / scale).exp();
+        check_distribution(2.0 * q / (1.0 - q).powi(2), &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_discrete_laplace(
+                Seed(seed as u128),
+                scale,
+                8,
+                num_blocks as u64,
+            );
+            ck.decrypt_signed(&img)
+        });
+
+        let std_dev: f64 = 3.0;
+        check_distribution(std_dev * std_dev, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_discrete_gaussian(
+                Seed(seed as u128),
+                std_dev,
+                8,
+                num_blocks as u64,
+            );
+            ck.decrypt_signed(&img)
+        });
+    }
 }
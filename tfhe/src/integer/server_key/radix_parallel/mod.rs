@@ -6,9 +6,11 @@ mod block_shift;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod modular;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod pow;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -19,6 +21,8 @@ mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
 mod shift;
+mod shuffle;
+pub(crate) mod sorting_network;
 pub(crate) mod sub;
 mod sum;
 
@@ -26,6 +30,7 @@ mod count_zeros_ones;
 pub(crate) mod ilog2;
 mod reverse_bits;
 mod slice;
+mod sqrt;
 #[cfg(test)]
 pub(crate) mod tests_cases_unsigned;
 #[cfg(test)]
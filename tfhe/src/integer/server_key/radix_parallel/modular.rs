@@ -0,0 +1,561 @@
+//! Modular arithmetic
+//!
+//! Additions, multiplications and exponentiations reduced modulo an encrypted
+//! or a clear modulus.
+//!
+//! Intermediate values are computed on a larger number of blocks so that they
+//! never wrap around, then reduced and truncated back to the input size.
+//!
+//! When the modulus is a clear value, the reduction is done using the
+//! multiplication by an approximation of the inverse of the modulus (see
+//! [scalar_div_mod](super::scalar_div_mod)), which is much cheaper than an
+//! encrypted division.
+use super::bit_extractor::BitExtractor;
+use super::scalar_div_mod::Reciprocable;
+use crate::core_crypto::prelude::{CastFrom, UnsignedNumeric};
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    fn propagated_clone(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut ct = ct.clone();
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut ct);
+        }
+        ct
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`
+    ///
+    /// Both `lhs` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// The ciphertexts must not have any carries.
+    pub fn unchecked_add_mod_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        assert_eq!(
+            rhs.blocks.len(),
+            num_blocks,
+            "lhs and rhs must have the same number of blocks"
+        );
+        assert_eq!(
+            modulus.blocks.len(),
+            num_blocks,
+            "lhs and modulus must have the same number of blocks"
+        );
+
+        // One more block, so that the sum does not overflow
+        let lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, 1);
+        let rhs = self.extend_radix_with_trivial_zero_blocks_msb(rhs, 1);
+        let modulus = self.extend_radix_with_trivial_zero_blocks_msb(modulus, 1);
+
+        let sum = self.add_parallelized(&lhs, &rhs);
+        let (reduced, is_smaller) = self.unsigned_overflowing_sub_parallelized(&sum, &modulus);
+        let mut result = self.if_then_else_parallelized(&is_smaller, &sum, &reduced);
+        self.trim_radix_blocks_msb_assign(&mut result, 1);
+        result
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`
+    ///
+    /// Both `lhs` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (msg1, msg2, modulus) = (200u8, 150u8, 251u8);
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let ct_modulus = cks.encrypt(modulus);
+    ///
+    /// let ct_res = sks.add_mod_parallelized(&ct1, &ct2, &ct_modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res as u32, (msg1 as u32 + msg2 as u32) % modulus as u32);
+    /// ```
+    pub fn add_mod_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let lhs = self.propagated_clone(lhs);
+        let rhs = self.propagated_clone(rhs);
+        let modulus = self.propagated_clone(modulus);
+        self.unchecked_add_mod_parallelized(&lhs, &rhs, &modulus)
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus` where the modulus is a clear value
+    ///
+    /// Both `lhs` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// The ciphertexts must not have any carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn unchecked_scalar_add_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: UnsignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = T>,
+    {
+        assert_ne!(
+            modulus,
+            T::ZERO,
+            "attempt to compute a remainder with a modulus of 0"
+        );
+        assert_eq!(
+            rhs.blocks.len(),
+            lhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+
+        let lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, 1);
+        let rhs = self.extend_radix_with_trivial_zero_blocks_msb(rhs, 1);
+
+        let sum = self.add_parallelized(&lhs, &rhs);
+        let (reduced, is_smaller) =
+            self.unsigned_overflowing_scalar_sub_parallelized(&sum, modulus);
+        let mut result = self.if_then_else_parallelized(&is_smaller, &sum, &reduced);
+        self.trim_radix_blocks_msb_assign(&mut result, 1);
+        result
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus` where the modulus is a clear value
+    ///
+    /// Both `lhs` and `rhs` must be < `modulus`, otherwise the result is not meaningful.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (msg1, msg2, modulus) = (200u8, 150u8, 251u8);
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.scalar_add_mod_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res as u32, (msg1 as u32 + msg2 as u32) % modulus as u32);
+    /// ```
+    pub fn scalar_add_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: UnsignedNumeric + DecomposableInto<u8> + std::ops::Not<Output = T>,
+    {
+        let lhs = self.propagated_clone(lhs);
+        let rhs = self.propagated_clone(rhs);
+        self.unchecked_scalar_add_mod_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`
+    ///
+    /// The product is computed on twice the number of blocks, so `lhs` and `rhs` do not
+    /// need to be reduced.
+    ///
+    /// If the modulus is 0, the result is the product truncated to the input size
+    /// (i.e. the remainder of a division by 0 is the numerator).
+    ///
+    /// The ciphertexts must not have any carries.
+    pub fn unchecked_mul_mod_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        assert_eq!(
+            rhs.blocks.len(),
+            num_blocks,
+            "lhs and rhs must have the same number of blocks"
+        );
+        assert_eq!(
+            modulus.blocks.len(),
+            num_blocks,
+            "lhs and modulus must have the same number of blocks"
+        );
+
+        let (product, modulus) = rayon::join(
+            || {
+                let lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks);
+                let rhs = self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks);
+                self.mul_parallelized(&lhs, &rhs)
+            },
+            || self.extend_radix_with_trivial_zero_blocks_msb(modulus, num_blocks),
+        );
+
+        let mut result = self.rem_parallelized(&product, &modulus);
+        self.trim_radix_blocks_msb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`
+    ///
+    /// The product is computed on twice the number of blocks, so `lhs` and `rhs` do not
+    /// need to be reduced.
+    ///
+    /// If the modulus is 0, the result is the product truncated to the input size
+    /// (i.e. the remainder of a division by 0 is the numerator).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (msg1, msg2, modulus) = (200u8, 150u8, 251u8);
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let ct_modulus = cks.encrypt(modulus);
+    ///
+    /// let ct_res = sks.mul_mod_parallelized(&ct1, &ct2, &ct_modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res as u32, (msg1 as u32 * msg2 as u32) % modulus as u32);
+    /// ```
+    pub fn mul_mod_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let lhs = self.propagated_clone(lhs);
+        let rhs = self.propagated_clone(rhs);
+        let modulus = self.propagated_clone(modulus);
+        self.unchecked_mul_mod_parallelized(&lhs, &rhs, &modulus)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus` where the modulus is a clear value
+    ///
+    /// The product is computed on twice the number of blocks, so `lhs` and `rhs` do not
+    /// need to be reduced.
+    ///
+    /// The ciphertexts must not have any carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if the modulus type has less bits than
+    /// the number of bits encrypted in the ciphertexts.
+    pub fn unchecked_scalar_mul_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Reciprocable,
+        T::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        let num_blocks = lhs.blocks.len();
+        assert_eq!(
+            rhs.blocks.len(),
+            num_blocks,
+            "lhs and rhs must have the same number of blocks"
+        );
+        assert_ne!(
+            modulus,
+            T::ZERO,
+            "attempt to compute a remainder with a modulus of 0"
+        );
+        let num_bits = self.message_modulus().0.ilog2() as usize * num_blocks;
+        assert!(
+            T::BITS >= num_bits,
+            "The modulus type must have a number of bits that is \
+            >= to the number of bits encrypted in the ciphertext: \n\
+            encrypted bits: {num_bits}, modulus bits: {}",
+            T::BITS
+        );
+
+        let product = {
+            let (lhs, rhs) = rayon::join(
+                || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+                || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+            );
+            self.mul_parallelized(&lhs, &rhs)
+        };
+
+        // The product has up to twice the number of bits, the reduction
+        // needs a modulus type that is large enough to represent it
+        let modulus = T::DoublePrecision::cast_from(modulus);
+        let mut result = self.unchecked_scalar_rem_parallelized(&product, modulus);
+        self.trim_radix_blocks_msb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus` where the modulus is a clear value
+    ///
+    /// The product is computed on twice the number of blocks, so `lhs` and `rhs` do not
+    /// need to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if the modulus type has less bits than
+    /// the number of bits encrypted in the ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (msg1, msg2, modulus) = (200u8, 150u8, 251u8);
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.scalar_mul_mod_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res as u32, (msg1 as u32 * msg2 as u32) % modulus as u32);
+    /// ```
+    pub fn scalar_mul_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Reciprocable,
+        T::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        let lhs = self.propagated_clone(lhs);
+        let rhs = self.propagated_clone(rhs);
+        self.unchecked_scalar_mul_mod_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Square and multiply exponentiation, where `mul_mod` computes the modular
+    /// product of two ciphertexts and `one` is the value of 1 reduced by the modulus
+    fn unchecked_pow_mod_impl<F>(
+        &self,
+        base: RadixCiphertext,
+        exponent: &RadixCiphertext,
+        one: RadixCiphertext,
+        mul_mod: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> RadixCiphertext + Sync,
+    {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let exponent_bits = BitExtractor::new(&exponent.blocks, self, bits_per_block)
+            .extract_all_bits()
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect::<Vec<_>>();
+
+        let mut result = one;
+        let mut power = base;
+        let num_exponent_bits = exponent_bits.len();
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let is_last_bit = i == num_exponent_bits - 1;
+            let (candidate, next_power) = rayon::join(
+                || mul_mod(&result, &power),
+                || (!is_last_bit).then(|| mul_mod(&power, &power)),
+            );
+            result = self.if_then_else_parallelized(bit, &candidate, &result);
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus`
+    ///
+    /// The ciphertexts must not have any carries.
+    ///
+    /// If the modulus is 0, the result is the power truncated to the input size.
+    pub fn unchecked_pow_mod_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = base.blocks.len();
+        assert_eq!(
+            modulus.blocks.len(),
+            num_blocks,
+            "base and modulus must have the same number of blocks"
+        );
+
+        // 1 % modulus is 0 when the modulus is 1, 1 otherwise
+        let (base, one) = rayon::join(
+            || self.rem_parallelized(base, modulus),
+            || {
+                self.scalar_ne_parallelized(modulus, 1u64)
+                    .into_radix(num_blocks, self)
+            },
+        );
+
+        self.unchecked_pow_mod_impl(base, exponent, one, |lhs, rhs| {
+            self.unchecked_mul_mod_parallelized(lhs, rhs, modulus)
+        })
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus`
+    ///
+    /// The exponent can have a different number of blocks than the base.
+    ///
+    /// If the modulus is 0, the result is the power truncated to the input size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (base, exponent, modulus) = (7u8, 3u8, 13u8);
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt_radix(exponent, 1);
+    /// let ct_modulus = cks.encrypt(modulus);
+    ///
+    /// let ct_res = sks.pow_mod_parallelized(&ct_base, &ct_exponent, &ct_modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 5); // 343 % 13
+    /// ```
+    pub fn pow_mod_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let base = self.propagated_clone(base);
+        let exponent = self.propagated_clone(exponent);
+        let modulus = self.propagated_clone(modulus);
+        self.unchecked_pow_mod_parallelized(&base, &exponent, &modulus)
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus` where the modulus is a clear
+    /// value
+    ///
+    /// The ciphertexts must not have any carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if the modulus type has less bits than
+    /// the number of bits encrypted in the base.
+    pub fn unchecked_scalar_pow_mod_parallelized<T>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+        T::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        assert_ne!(
+            modulus,
+            T::ZERO,
+            "attempt to compute a remainder with a modulus of 0"
+        );
+        let num_blocks = base.blocks.len();
+
+        let base = self.unchecked_scalar_rem_parallelized(base, modulus);
+        let one: RadixCiphertext =
+            self.create_trivial_radix(u64::from(modulus != T::ONE), num_blocks);
+
+        self.unchecked_pow_mod_impl(base, exponent, one, |lhs, rhs| {
+            self.unchecked_scalar_mul_mod_parallelized(lhs, rhs, modulus)
+        })
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus` where the modulus is a clear
+    /// value
+    ///
+    /// The exponent can have a different number of blocks than the base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if the modulus type has less bits than
+    /// the number of bits encrypted in the base.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let (base, exponent, modulus) = (7u8, 3u8, 13u8);
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt_radix(exponent, 1);
+    ///
+    /// let ct_res = sks.scalar_pow_mod_parallelized(&ct_base, &ct_exponent, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 5); // 343 % 13
+    /// ```
+    pub fn scalar_pow_mod_parallelized<T>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+        T::DoublePrecision: Reciprocable + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        let base = self.propagated_clone(base);
+        let exponent = self.propagated_clone(exponent);
+        self.unchecked_scalar_pow_mod_parallelized(&base, &exponent, modulus)
+    }
+}
@@ -792,6 +792,168 @@ impl ServerKey {
         self.unchecked_unsigned_overflowing_mul_assign_parallelized(lhs, rhs)
     }
 
+    /// Computes homomorphically the full product of two unsigned ciphertexts.
+    ///
+    /// The result has `lhs.blocks.len() + rhs.blocks.len()` blocks, so it never overflows.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    pub fn unchecked_widening_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks ({} vs {})",
+            lhs.blocks.len(),
+            rhs.blocks.len()
+        );
+        let num_blocks = lhs.blocks.len();
+
+        // The added blocks are trivial zeros, the multiplication skips the terms
+        // coming from them, so this costs about half of a mul on 2 * num_blocks
+        let (extended_lhs, extended_rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+            || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+        );
+        self.unchecked_mul_parallelized(&extended_lhs, &extended_rhs)
+    }
+
+    /// Computes homomorphically the full product of two unsigned ciphertexts.
+    ///
+    /// The result has `lhs.blocks.len() + rhs.blocks.len()` blocks, so it never overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let clear_1 = 255u8;
+    /// let clear_2 = 143u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let ct_res = sks.widening_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// assert_eq!(ct_res.blocks().len(), 2 * num_blocks);
+    ///
+    /// // Decrypt
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, u16::from(clear_1) * u16::from(clear_2));
+    /// ```
+    pub fn widening_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_widening_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the high half of the full product of two unsigned
+    /// ciphertexts, that is `(lhs * rhs) >> num_bits`.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    pub fn unchecked_mul_high_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut result = self.unchecked_widening_mul_parallelized(lhs, rhs);
+        result.blocks.drain(..lhs.blocks.len());
+        result
+    }
+
+    /// Computes homomorphically the high half of the full product of two unsigned
+    /// ciphertexts, that is `(lhs * rhs) >> num_bits`.
+    ///
+    /// The result has the same number of blocks as the inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let clear_1 = 255u8;
+    /// let clear_2 = 143u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let ct_res = sks.mul_high_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, ((u16::from(clear_1) * u16::from(clear_2)) >> 8) as u8);
+    /// ```
+    pub fn mul_high_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut result = self.widening_mul_parallelized(lhs, rhs);
+        result.blocks.drain(..lhs.blocks.len());
+        result
+    }
+
     /// Computes homomorphically a multiplication along with an overflow flag
     ///
     /// # Example
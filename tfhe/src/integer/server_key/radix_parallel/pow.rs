@@ -0,0 +1,316 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Returns the bits of the exponent, from the least significant one
+    fn extract_exponent_bits(&self, exponent: &RadixCiphertext) -> Vec<BooleanBlock> {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        BitExtractor::new(&exponent.blocks, self, bits_per_block)
+            .extract_all_bits()
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+
+    /// Computes homomorphically `base.pow(exponent)`, wrapping around on overflow.
+    ///
+    /// The exponent is an unsigned ciphertext that may have a different number
+    /// of blocks than the base. The cost grows linearly with the number of bits
+    /// of the exponent.
+    ///
+    /// The ciphertexts must not have any carries.
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+        let one: T = self.create_trivial_radix(1u64, num_blocks);
+        let exponent_bits = self.extract_exponent_bits(exponent);
+        let Some((first_bit, other_bits)) = exponent_bits.split_first() else {
+            return one;
+        };
+
+        // Right to left square and multiply, squaring the next power
+        // is done in parallel to the multiplication of the result
+        let (mut result, mut power) = rayon::join(
+            || self.if_then_else_parallelized(first_bit, base, &one),
+            || self.unchecked_mul_parallelized(base, base),
+        );
+        for (i, bit) in other_bits.iter().enumerate() {
+            let is_last_bit = i == other_bits.len() - 1;
+            let (candidate, next_power) = rayon::join(
+                || self.unchecked_mul_parallelized(&result, &power),
+                || (!is_last_bit).then(|| self.unchecked_mul_parallelized(&power, &power)),
+            );
+            result = self.if_then_else_parallelized(bit, &candidate, &result);
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `base.pow(exponent)`, wrapping around on overflow.
+    ///
+    /// The exponent is an unsigned ciphertext that may have a different number
+    /// of blocks than the base. The cost grows linearly with the number of bits
+    /// of the exponent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let base = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// // The exponent only needs enough blocks to hold its value
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(exponent as u32));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_base;
+        let mut tmp_exponent;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base.pow(exponent)`, along with a boolean
+    /// telling whether the exact result overflowed.
+    ///
+    /// The ciphertexts must not have any carries.
+    pub fn unchecked_unsigned_overflowing_pow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let num_blocks = base.blocks.len();
+        let one: RadixCiphertext = self.create_trivial_radix(1u64, num_blocks);
+        let exponent_bits = self.extract_exponent_bits(exponent);
+        let Some((first_bit, other_bits)) = exponent_bits.split_first() else {
+            return (one, self.create_trivial_boolean_block(false));
+        };
+
+        // The squares are computed even for bits of the exponent that end up being 0,
+        // so an overflow on the power only counts if it is used for the result
+        let (mut result, (mut power, mut power_overflowed)) = rayon::join(
+            || self.if_then_else_parallelized(first_bit, base, &one),
+            || self.unchecked_unsigned_overflowing_mul_parallelized(base, base),
+        );
+        let mut overflowed = self.create_trivial_boolean_block(false);
+        for (i, bit) in other_bits.iter().enumerate() {
+            let is_last_bit = i == other_bits.len() - 1;
+            let ((candidate, candidate_overflowed), next_power) = rayon::join(
+                || self.unchecked_unsigned_overflowing_mul_parallelized(&result, &power),
+                || {
+                    (!is_last_bit).then(|| {
+                        self.unchecked_unsigned_overflowing_mul_parallelized(&power, &power)
+                    })
+                },
+            );
+
+            let (new_result, mut used_overflow) = rayon::join(
+                || self.if_then_else_parallelized(bit, &candidate, &result),
+                || self.boolean_bitor(&candidate_overflowed, &power_overflowed),
+            );
+            result = new_result;
+            self.boolean_bitand_assign(&mut used_overflow, bit);
+            self.boolean_bitor_assign(&mut overflowed, &used_overflow);
+
+            if let Some((next_power, next_power_overflowed)) = next_power {
+                power = next_power;
+                self.boolean_bitor_assign(&mut power_overflowed, &next_power_overflowed);
+            }
+        }
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically `base.pow(exponent)`, along with a boolean
+    /// telling whether the exact result overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let base = 3u8;
+    /// let exponent = 6u8;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let (ct_res, ct_overflowed) = sks.unsigned_overflowing_pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// let overflowed = cks.decrypt_bool(&ct_overflowed);
+    /// let (expected_result, expected_overflowed) = base.overflowing_pow(exponent as u32);
+    /// assert_eq!(res, expected_result);
+    /// assert_eq!(overflowed, expected_overflowed);
+    /// ```
+    pub fn unsigned_overflowing_pow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let mut tmp_base;
+        let mut tmp_exponent;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        self.unchecked_unsigned_overflowing_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base.pow(exponent)` where the exponent is a clear value,
+    /// wrapping around on overflow.
+    ///
+    /// Only the multiplications required by the bits of the exponent are done.
+    ///
+    /// The ciphertext must not have any carries.
+    pub fn unchecked_scalar_pow_parallelized<T>(&self, base: &T, exponent: u64) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if exponent == 0 {
+            return self.create_trivial_radix(1u64, base.blocks().len());
+        }
+
+        let mut result: Option<T> = None;
+        let mut power = base.clone();
+        let mut remaining = exponent;
+        loop {
+            let bit_is_set = remaining & 1 == 1;
+            remaining >>= 1;
+
+            let (new_result, next_power) = rayon::join(
+                || match (&result, bit_is_set) {
+                    (Some(result), true) => Some(self.unchecked_mul_parallelized(result, &power)),
+                    (None, true) => Some(power.clone()),
+                    (_, false) => None,
+                },
+                || (remaining != 0).then(|| self.unchecked_mul_parallelized(&power, &power)),
+            );
+            if new_result.is_some() {
+                result = new_result;
+            }
+
+            match next_power {
+                Some(next_power) => power = next_power,
+                None => break,
+            }
+        }
+
+        // The highest bit of a non-zero exponent is always set
+        result.unwrap()
+    }
+
+    /// Computes homomorphically `base.pow(exponent)` where the exponent is a clear value,
+    /// wrapping around on overflow.
+    ///
+    /// Only the multiplications required by the bits of the exponent are done.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let base = 7u8;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct_base, 3);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(3));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u64) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if base.block_carries_are_empty() {
+            self.unchecked_scalar_pow_parallelized(base, exponent)
+        } else {
+            let mut tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            self.unchecked_scalar_pow_parallelized(&tmp_base, exponent)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::sorting_network::bitonic_stages;
+use super::ServerKey;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::RadixCiphertext;
+use rayon::prelude::*;
+use tfhe_csprng::seeders::Seed;
+
+impl ServerKey {
+    /// Shuffles the values using the given seed
+    ///
+    /// Each value is given an encrypted random key, generated obliviously from the seed (see
+    /// [Self::par_generate_oblivious_pseudo_random_unsigned_integer]), and the values are sorted
+    /// by key with a sorting network. Neither the server nor anyone without the client key
+    /// learns the permutation that was applied.
+    ///
+    /// The permutation is uniform, except when two keys collide, which happens with probability
+    /// at most `2^-statistical_security_bits`. Each additional bit makes the keys, and so their
+    /// comparisons, a bit more costly.
+    ///
+    /// The values must all have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::Seed;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msgs = [3u64, 14, 15, 92];
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.par_oblivious_shuffle(Seed(0), &cts, 40);
+    ///
+    /// // Decrypt:
+    /// let mut dec_result = ct_res
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u64>(ct))
+    ///     .collect::<Vec<_>>();
+    /// dec_result.sort_unstable();
+    /// assert_eq!(dec_result, msgs);
+    /// ```
+    pub fn par_oblivious_shuffle<T>(
+        &self,
+        seed: Seed,
+        values: &[T],
+        statistical_security_bits: u64,
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let len = values.len();
+        if len <= 1 {
+            return values.to_vec();
+        }
+
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+
+        // Among the len * (len - 1) / 2 pairs of keys, the probability that one collides is
+        // below len^2 / 2^(key_bits_count + 1)
+        let len_bits_count = u64::from(usize::BITS - (len - 1).leading_zeros());
+        let key_bits_count = 2 * len_bits_count + statistical_security_bits;
+        let num_key_blocks = key_bits_count.div_ceil(message_bits_count) as usize;
+
+        // All the keys are taken from one random integer, so that a single seed is consumed
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer(
+            seed,
+            (num_key_blocks * len) as u64,
+        );
+
+        // The keys get a trivial zero block on top of their random blocks, so that the keys of
+        // the padding values are greater than all of them and end up at the end
+        let padded_len = len.next_power_of_two();
+        let mut keys = random
+            .blocks
+            .chunks_exact(num_key_blocks)
+            .map(|blocks| {
+                self.extend_radix_with_trivial_zero_blocks_msb(
+                    &RadixCiphertext::from(blocks.to_vec()),
+                    1,
+                )
+            })
+            .collect::<Vec<_>>();
+        keys.resize(
+            padded_len,
+            self.create_trivial_max_radix(num_key_blocks + 1),
+        );
+
+        let mut values = values.to_vec();
+        values.resize(padded_len, values[0].clone());
+
+        for stage in bitonic_stages(padded_len) {
+            let exchanged = stage
+                .par_iter()
+                .map(|&(lo, hi)| {
+                    let must_swap = self.gt_parallelized(&keys[lo], &keys[hi]);
+
+                    rayon::join(
+                        || {
+                            rayon::join(
+                                || self.if_then_else_parallelized(&must_swap, &keys[hi], &keys[lo]),
+                                || self.if_then_else_parallelized(&must_swap, &keys[lo], &keys[hi]),
+                            )
+                        },
+                        || {
+                            rayon::join(
+                                || {
+                                    self.if_then_else_parallelized(
+                                        &must_swap,
+                                        &values[hi],
+                                        &values[lo],
+                                    )
+                                },
+                                || {
+                                    self.if_then_else_parallelized(
+                                        &must_swap,
+                                        &values[lo],
+                                        &values[hi],
+                                    )
+                                },
+                            )
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            for (&(lo, hi), ((new_lo_key, new_hi_key), (new_lo, new_hi))) in
+                stage.iter().zip(exchanged)
+            {
+                keys[lo] = new_lo_key;
+                keys[hi] = new_hi_key;
+                values[lo] = new_lo;
+                values[hi] = new_hi;
+            }
+        }
+
+        values.truncate(len);
+        values
+    }
+}
@@ -0,0 +1,28 @@
+/// Returns the compare-exchange pairs of each stage of a bitonic
+/// sorting network over `len` elements, `len` must be a power of two.
+///
+/// For each pair `(lo, hi)` the smallest value must go at index `lo`
+/// and the greatest at index `hi`.
+pub(crate) fn bitonic_stages(len: usize) -> Vec<Vec<(usize, usize)>> {
+    assert!(len.is_power_of_two());
+
+    let mut stages = Vec::new();
+    let mut block_size = 2;
+    while block_size <= len {
+        let mut distance = block_size / 2;
+        while distance > 0 {
+            let stage = (0..len)
+                .filter_map(|i| {
+                    let j = i ^ distance;
+                    // Alternate the direction of each block, so that two neighbouring
+                    // blocks form a bitonic sequence for the next merge
+                    (j > i).then_some(if i & block_size == 0 { (i, j) } else { (j, i) })
+                })
+                .collect();
+            stages.push(stage);
+            distance /= 2;
+        }
+        block_size *= 2;
+    }
+    stages
+}
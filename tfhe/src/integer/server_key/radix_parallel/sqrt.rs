@@ -0,0 +1,166 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{
+    BooleanBlock, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
+    SignedRadixCiphertext,
+};
+
+impl ServerKey {
+    /// Computes homomorphically the integer square root of an unsigned integer,
+    /// rounded down.
+    ///
+    /// The input must not have any carries.
+    pub fn unchecked_isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks().len();
+        let mut root: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        if num_blocks == 0 {
+            return root;
+        }
+
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_bits = num_blocks * bits_per_block;
+
+        // Digit by digit algorithm, each iteration computes one bit of the root.
+        //
+        // At the start of the iteration handling the bit at `bit_index`, all the bits
+        // of `root` below `bit_index + 2` are zeros, so 'adding' a bit at `bit_index`
+        // (before or after the shift) can be done with a bitor on a single block
+        // instead of a full addition.
+        let mut remainder = ct.clone();
+        let highest_bit_index = (num_bits - 1) & !1;
+        for bit_index in (0..=highest_bit_index).rev().step_by(2) {
+            let block_index = bit_index / bits_per_block;
+            let bit_in_block = (bit_index % bits_per_block) as u32;
+
+            let mut candidate = root.clone();
+            self.key
+                .scalar_bitor_assign(&mut candidate.blocks[block_index], 1 << bit_in_block);
+
+            let ((difference, is_smaller), shifted_root) = rayon::join(
+                || self.unsigned_overflowing_sub_parallelized(&remainder, &candidate),
+                || self.scalar_right_shift_parallelized(&root, 1),
+            );
+
+            let insert_bit_lut = self
+                .key
+                .generate_lookup_table_bivariate(|block, is_smaller| {
+                    if is_smaller == 0 {
+                        block | (1 << bit_in_block)
+                    } else {
+                        block
+                    }
+                });
+
+            let (new_remainder, ()) = rayon::join(
+                || self.if_then_else_parallelized(&is_smaller, &remainder, &difference),
+                || {
+                    root = shifted_root;
+                    self.key.unchecked_apply_lookup_table_bivariate_assign(
+                        &mut root.blocks[block_index],
+                        &is_smaller.0,
+                        &insert_bit_lut,
+                    );
+                },
+            );
+            remainder = new_remainder;
+        }
+
+        root
+    }
+
+    /// Computes homomorphically the integer square root of an unsigned integer,
+    /// rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 200u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.isqrt());
+    /// ```
+    pub fn isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        self.unchecked_isqrt_parallelized(ct)
+    }
+
+    /// Computes homomorphically the integer square root of a signed integer,
+    /// rounded down.
+    ///
+    /// Also returns a BooleanBlock, encrypting true (1) if the result is
+    /// valid (input is >= 0), otherwise 0. The result has no meaning when the input is
+    /// negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 99i8;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// let (ct_res, is_ok) = sks.checked_isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, msg.isqrt());
+    /// assert!(cks.decrypt_bool(&is_ok));
+    ///
+    /// let ct = cks.encrypt_signed(-msg);
+    /// let (_, is_ok) = sks.checked_isqrt_parallelized(&ct);
+    /// assert!(!cks.decrypt_bool(&is_ok));
+    /// ```
+    pub fn checked_isqrt_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        // Non-negative values have the same representation as unsigned ones,
+        // and their root always fits in the positive range of the signed type
+        let (root, is_ok) = rayon::join(
+            || {
+                let unsigned = RadixCiphertext::from_blocks(ct.blocks().to_vec());
+                self.unchecked_isqrt_parallelized(&unsigned)
+            },
+            || self.scalar_ge_parallelized(ct, 0),
+        );
+
+        (
+            SignedRadixCiphertext::from_blocks(root.into_blocks()),
+            is_ok,
+        )
+    }
+}
@@ -7,6 +7,7 @@ pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_rem;
 pub(crate) mod test_ilog2;
+pub(crate) mod test_isqrt;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
 pub(crate) mod test_rotate;
@@ -19,6 +20,7 @@ pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
 pub(crate) mod test_shift;
+pub(crate) mod test_shuffle;
 pub(crate) mod test_sub;
 pub(crate) mod test_vector_comparisons;
 
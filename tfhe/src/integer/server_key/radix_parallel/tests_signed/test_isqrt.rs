@@ -0,0 +1,126 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::{
+    random_non_zero_value, signed_add_under_modulus, NB_CTXT,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext,
+};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use crate::shortint::PBSParameters;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_signed_default_checked_isqrt {
+    // uses comparison so 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    V1_0_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_signed_default_checked_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::checked_isqrt_parallelized);
+    default_checked_isqrt_test(param, executor);
+}
+
+pub(crate) fn default_checked_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a SignedRadixCiphertext, (SignedRadixCiphertext, BooleanBlock)>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    // Test with invalid input
+    for clear in [-1i64, -modulus, rng.gen_range(-modulus..=-1i64)] {
+        let ctxt = cks.encrypt_signed(clear);
+
+        let (ct_res, is_ok) = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(is_ok.as_ref().degree.get(), 1);
+
+        let is_ok = cks.decrypt_bool(&is_ok);
+        assert!(!is_ok, "Invalid is_ok for {clear}.isqrt(), expected false");
+    }
+
+    let input_values = [0i64, 1, modulus - 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen_range(0..modulus)))
+        .collect::<Vec<_>>();
+
+    for clear in input_values {
+        let ctxt = cks.encrypt_signed(clear);
+
+        let (ct_res, is_ok) = executor.execute(&ctxt);
+        let (tmp, tmp_is_ok) = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(
+            ct_res, tmp,
+            "Failed determinism check, \n\n\n msg: {clear}, \n\n\nctxt: {ctxt:?}\n\n\n"
+        );
+        assert_eq!(is_ok, tmp_is_ok);
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = clear.isqrt();
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt for {clear}.isqrt() \
+                expected {expected_result}, got {decrypted_result}"
+        );
+        let is_ok = cks.decrypt_bool(&is_ok);
+        assert!(is_ok);
+
+        for _ in 0..nb_tests_smaller {
+            // Add non-zero scalar to have non-clean ciphertexts
+            // But here, we have to make sure clear is still >= 0
+            // as we are only testing valid isqrt inputs
+            let (clear, clear_2) = loop {
+                let clear_2 = random_non_zero_value(&mut rng, modulus);
+                let clear = signed_add_under_modulus(clear, clear_2, modulus);
+                if clear >= 0 {
+                    break (clear, clear_2);
+                }
+            };
+
+            let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+
+            let d0: i64 = cks.decrypt_signed(&ctxt);
+            assert_eq!(d0, clear, "Failed sanity decryption check");
+
+            let (ct_res, is_ok) = executor.execute(&ctxt);
+            assert!(ct_res.block_carries_are_empty());
+
+            let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+            let expected_result = clear.isqrt();
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for isqrt, for {clear}.isqrt() \
+                    expected {expected_result}, got {decrypted_result}"
+            );
+            let is_ok = cks.decrypt_bool(&is_ok);
+            assert!(is_ok);
+        }
+    }
+}
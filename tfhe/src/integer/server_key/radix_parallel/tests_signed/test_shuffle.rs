@@ -0,0 +1,77 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use std::sync::Arc;
+use tfhe_csprng::seeders::Seed;
+
+create_parameterized_test!(integer_signed_default_oblivious_shuffle);
+
+fn integer_signed_default_oblivious_shuffle<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::par_oblivious_shuffle::<SignedRadixCiphertext>);
+    signed_default_oblivious_shuffle_test_case(param, executor);
+}
+
+pub(crate) fn signed_default_oblivious_shuffle_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (Seed, &'a [SignedRadixCiphertext], u64),
+        Vec<SignedRadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(2..=7);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(-modulus..modulus))
+            .collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_signed(clear))
+            .collect::<Vec<_>>();
+
+        let seed = Seed(rng.gen());
+        let shuffled = executor.execute((seed, &cts, 16));
+        assert_eq!(shuffled.len(), num_values);
+
+        let shuffled_2 = executor.execute((seed, &cts, 16));
+        assert_eq!(shuffled, shuffled_2, "Failed determinism test");
+
+        let mut decrypted = shuffled
+            .iter()
+            .map(|ct| cks.decrypt_signed::<i64>(ct))
+            .collect::<Vec<_>>();
+        decrypted.sort_unstable();
+        let mut expected = clears;
+        expected.sort_unstable();
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result for shuffle: the values are not a permutation of the inputs"
+        );
+    }
+}
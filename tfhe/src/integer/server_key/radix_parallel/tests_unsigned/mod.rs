@@ -6,8 +6,11 @@ pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_mod;
 pub(crate) mod test_ilog2;
+pub(crate) mod test_isqrt;
+pub(crate) mod test_modular;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+pub(crate) mod test_pow;
 pub(crate) mod test_rotate;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
@@ -18,6 +21,7 @@ pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
 pub(crate) mod test_shift;
+pub(crate) mod test_shuffle;
 pub(crate) mod test_slice;
 pub(crate) mod test_sub;
 pub(crate) mod test_sum;
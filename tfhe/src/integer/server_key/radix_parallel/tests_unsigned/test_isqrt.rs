@@ -0,0 +1,120 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, random_non_zero_value, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_isqrt {
+    // This uses subtractions with borrow, so require more than 1 bit
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    V1_0_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::isqrt_parallelized);
+    default_isqrt_test(param, executor);
+}
+
+pub(crate) fn default_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // Perfect squares and their neighbours are the interesting edge cases
+    let root_of_max = (modulus - 1).isqrt();
+    let input_values = [0u64, 1, modulus - 1, root_of_max * root_of_max]
+        .into_iter()
+        .chain([root_of_max * root_of_max - 1, 4, 8, 9])
+        .chain((0..nb_tests_smaller).map(|_| rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+
+    for clear in input_values {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(
+            ct_res, tmp,
+            "Failed determinism check, \n\n\n msg: {clear}, \n\n\nctxt: {ctxt:?}\n\n\n"
+        );
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear.isqrt();
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt, for {clear}.isqrt() \
+             expected {expected_result}, got {decrypted_result}"
+        );
+
+        for _ in 0..nb_tests_smaller {
+            // Add non-zero scalar to have non-clean ciphertexts
+            let clear_2 = random_non_zero_value(&mut rng, modulus);
+
+            let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+
+            let clear = clear.wrapping_add(clear_2) % modulus;
+
+            let d0: u64 = cks.decrypt(&ctxt);
+            assert_eq!(d0, clear, "Failed sanity decryption check");
+
+            let ct_res = executor.execute(&ctxt);
+            assert!(ct_res.block_carries_are_empty());
+
+            let decrypted_result: u64 = cks.decrypt(&ct_res);
+            let expected_result = clear.isqrt();
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for isqrt, for {clear}.isqrt() \
+                 expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+
+    let input_values = [0u64, modulus - 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in input_values {
+        let ctxt = sks.create_trivial_radix(clear, NB_CTXT);
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear.isqrt();
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt, for {clear}.isqrt() \
+             expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
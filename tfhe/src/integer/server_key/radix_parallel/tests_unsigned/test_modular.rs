@@ -0,0 +1,267 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_add_mod {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_mul_mod {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_pow_mod {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_default_add_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_mod_parallelized);
+    let scalar_executor = CpuFunctionExecutor::new(&ServerKey::scalar_add_mod_parallelized::<u64>);
+    default_add_mod_test(param, executor, scalar_executor);
+}
+
+fn integer_default_mul_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::mul_mod_parallelized);
+    let scalar_executor = CpuFunctionExecutor::new(&ServerKey::scalar_mul_mod_parallelized::<u64>);
+    default_mul_mod_test(param, executor, scalar_executor);
+}
+
+fn integer_default_pow_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_mod_parallelized);
+    let scalar_executor = CpuFunctionExecutor::new(&ServerKey::scalar_pow_mod_parallelized::<u64>);
+    default_pow_mod_test(param, executor, scalar_executor);
+}
+
+pub(crate) fn default_add_mod_test<P, T1, T2>(param: P, mut executor: T1, mut scalar_executor: T2)
+where
+    P: Into<PBSParameters>,
+    T1: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+    T2: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+    scalar_executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests_smaller {
+        // Operands must be reduced, use the max modulus as edge case
+        let clear_modulus = if rng.gen_bool(0.5) {
+            modulus - 1
+        } else {
+            rng.gen_range(1..modulus)
+        };
+        let clear_0 = rng.gen_range(0..clear_modulus);
+        let clear_1 = rng.gen_range(0..clear_modulus);
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_modulus = cks.encrypt(clear_modulus);
+
+        let expected = (clear_0 + clear_1) % clear_modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1, &ctxt_modulus));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1, &ctxt_modulus));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid add_mod result for ({clear_0} + {clear_1}) % {clear_modulus}"
+        );
+
+        let ct_res = scalar_executor.execute((&ctxt_0, &ctxt_1, clear_modulus));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid scalar_add_mod result for ({clear_0} + {clear_1}) % {clear_modulus}"
+        );
+    }
+}
+
+pub(crate) fn default_mul_mod_test<P, T1, T2>(param: P, mut executor: T1, mut scalar_executor: T2)
+where
+    P: Into<PBSParameters>,
+    T1: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+    T2: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+    scalar_executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests_smaller {
+        // Operands do not need to be reduced
+        let clear_0 = rng.gen_range(0..modulus);
+        let clear_1 = rng.gen_range(0..modulus);
+        let clear_modulus = rng.gen_range(1..modulus);
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_modulus = cks.encrypt(clear_modulus);
+
+        let expected = (clear_0 * clear_1) % clear_modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1, &ctxt_modulus));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid mul_mod result for ({clear_0} * {clear_1}) % {clear_modulus}"
+        );
+
+        let ct_res = scalar_executor.execute((&ctxt_0, &ctxt_1, clear_modulus));
+        let tmp = scalar_executor.execute((&ctxt_0, &ctxt_1, clear_modulus));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid scalar_mul_mod result for ({clear_0} * {clear_1}) % {clear_modulus}"
+        );
+    }
+
+    // A modulus of zero gives the wrapping product
+    let clear_0 = rng.gen_range(0..modulus);
+    let clear_1 = rng.gen_range(0..modulus);
+    let ct_res = executor.execute((
+        &cks.encrypt(clear_0),
+        &cks.encrypt(clear_1),
+        &cks.encrypt(0u64),
+    ));
+    let decrypted: u64 = cks.decrypt(&ct_res);
+    assert_eq!(decrypted, (clear_0 * clear_1) % modulus);
+}
+
+pub(crate) fn default_pow_mod_test<P, T1, T2>(param: P, mut executor: T1, mut scalar_executor: T2)
+where
+    P: Into<PBSParameters>,
+    T1: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+    T2: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    // The exponent is on a single block, to keep the number of multiplications low
+    let exponent_modulus = cks.parameters().message_modulus().0;
+
+    executor.setup(&cks, sks.clone());
+    scalar_executor.setup(&cks, sks);
+
+    let clear_pow_mod = |base: u64, exponent: u64, modulus: u64| {
+        (0..exponent).fold(1 % modulus, |acc, _| (acc * base) % modulus)
+    };
+
+    let cases = [(3, exponent_modulus - 1, 1), (5, 0, 1)]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| {
+            (
+                rng.gen_range(0..modulus),
+                rng.gen_range(0..exponent_modulus),
+                rng.gen_range(1..modulus),
+            )
+        }))
+        .collect::<Vec<_>>();
+
+    for (clear_base, clear_exponent, clear_modulus) in cases {
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(clear_exponent, 1);
+        let ctxt_modulus = cks.encrypt(clear_modulus);
+
+        let expected = clear_pow_mod(clear_base, clear_exponent, clear_modulus);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent, &ctxt_modulus));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid pow_mod result for {clear_base}.pow({clear_exponent}) % {clear_modulus}"
+        );
+
+        let ct_res = scalar_executor.execute((&ctxt_base, &ctxt_exponent, clear_modulus));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid scalar_pow_mod result for {clear_base}.pow({clear_exponent}) % {clear_modulus}"
+        );
+    }
+}
@@ -1,15 +1,21 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_default_block_mul_test, default_mul_test, default_overflowing_mul_test,
     smart_block_mul_test, smart_mul_test, unchecked_block_mul_test,
     unchecked_mul_corner_cases_test, unchecked_mul_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
 
 create_parameterized_test!(integer_unchecked_mul_corner_cases);
 create_parameterized_test!(integer_unchecked_block_mul);
@@ -19,6 +25,8 @@ create_parameterized_test!(integer_smart_mul);
 create_parameterized_test!(integer_default_mul);
 create_parameterized_test!(integer_default_unsigned_overflowing_mul);
 create_parameterized_test!(integer_unchecked_mul);
+create_parameterized_test!(integer_default_widening_mul);
+create_parameterized_test!(integer_default_mul_high);
 
 fn integer_unchecked_mul<P>(param: P)
 where
@@ -83,3 +91,122 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::block_mul_parallelized);
     default_default_block_mul_test(param, executor);
 }
+
+fn integer_default_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::widening_mul_parallelized);
+    default_widening_mul_test(param, executor);
+}
+
+fn integer_default_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::mul_high_parallelized);
+    default_mul_high_test(param, executor);
+}
+
+pub(crate) fn default_widening_mul_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        assert_eq!(ct_res, tmp, "Failed determinism check, \n\n\n msg0: {clear_0}, msg1: {clear_1}, \n\n\nctxt0: {ctxt_0:?}, \n\n\nctxt1: {ctxt_1:?}\n\n\n");
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted,
+            clear_0 * clear_1,
+            "Invalid result for widening_mul, for {clear_0} * {clear_1}"
+        );
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = rng.gen_range(1..modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+        let clear_lhs = (clear_0 + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted,
+            clear_lhs * clear_1,
+            "Invalid result for widening_mul, for {clear_lhs} * {clear_1}"
+        );
+    }
+
+    // Maximum values, the high half is full
+    let ctxt = cks.encrypt(modulus - 1);
+    let ct_res = executor.execute((&ctxt, &ctxt));
+    let decrypted: u64 = cks.decrypt(&ct_res);
+    assert_eq!(decrypted, (modulus - 1) * (modulus - 1));
+}
+
+pub(crate) fn default_mul_high_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        let expected = (clear_0 * clear_1) / modulus;
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result for mul_high, for ({clear_0} * {clear_1}) / {modulus}"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_pow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_unsigned_overflowing_pow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_scalar_pow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_parallelized::<RadixCiphertext>);
+    default_pow_test(param, executor);
+}
+
+fn integer_default_unsigned_overflowing_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_overflowing_pow_parallelized);
+    default_overflowing_pow_test(param, executor);
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::scalar_pow_parallelized::<RadixCiphertext>);
+    default_scalar_pow_test(param, executor);
+}
+
+/// The exponents are encrypted on this number of blocks,
+/// to keep the number of multiplications low
+const NB_CTXT_EXPONENT: usize = 2;
+
+pub(crate) fn default_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let exponent_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow(NB_CTXT_EXPONENT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let cases = [(0, 0), (0, 1), (rng.gen_range(0..modulus), 0)]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| {
+            (
+                rng.gen_range(0..modulus),
+                rng.gen_range(0..exponent_modulus),
+            )
+        }))
+        .collect::<Vec<_>>();
+
+    for (clear_base, clear_exponent) in cases {
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(clear_exponent, NB_CTXT_EXPONENT);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+        let tmp = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let expected = (0..clear_exponent).fold(1, |acc, _| (acc * clear_base) % modulus);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result for pow, for {clear_base}.pow({clear_exponent}) % {modulus}"
+        );
+    }
+
+    // Non-clean base
+    let clear_base = rng.gen_range(0..modulus);
+    let clear_scalar = rng.gen_range(1..modulus);
+    let clear_exponent = rng.gen_range(0..exponent_modulus);
+    let ctxt_base = sks.unchecked_scalar_add(&cks.encrypt(clear_base), clear_scalar);
+    let ctxt_exponent = cks.as_ref().encrypt_radix(clear_exponent, NB_CTXT_EXPONENT);
+    let clear_base = (clear_base + clear_scalar) % modulus;
+
+    let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+    assert!(ct_res.block_carries_are_empty());
+
+    let expected = (0..clear_exponent).fold(1, |acc, _| (acc * clear_base) % modulus);
+    let decrypted: u64 = cks.decrypt(&ct_res);
+    assert_eq!(
+        decrypted, expected,
+        "Invalid result for pow, for {clear_base}.pow({clear_exponent}) % {modulus}"
+    );
+}
+
+pub(crate) fn default_overflowing_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, BooleanBlock),
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let exponent_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow(NB_CTXT_EXPONENT as u32);
+
+    executor.setup(&cks, sks);
+
+    // Small bases with big exponents are the interesting cases, as not overflowing
+    // requires ignoring the overflows of the unused powers
+    let mut cases = vec![(1, exponent_modulus - 1), (2, 1), (3, 2), (0, 0)];
+    for _ in 0..nb_tests_smaller {
+        cases.push((
+            rng.gen_range(0..8u64.min(modulus)),
+            rng.gen_range(0..exponent_modulus),
+        ));
+        cases.push((
+            rng.gen_range(0..modulus),
+            rng.gen_range(0..exponent_modulus),
+        ));
+    }
+
+    for (clear_base, clear_exponent) in cases {
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(clear_exponent, NB_CTXT_EXPONENT);
+
+        let (ct_res, ct_overflowed) = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let exact_result = (0..clear_exponent).try_fold(1u64, |acc, _| {
+            acc.checked_mul(clear_base).filter(|value| *value < modulus)
+        });
+        let expected_overflowed = exact_result.is_none();
+        let expected = (0..clear_exponent).fold(1, |acc, _| (acc * clear_base) % modulus);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&ct_overflowed);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result for overflowing_pow, for {clear_base}.pow({clear_exponent}) % {modulus}"
+        );
+        assert_eq!(
+            decrypted_overflowed, expected_overflowed,
+            "Invalid overflow flag for overflowing_pow, for {clear_base}.pow({clear_exponent}) % {modulus}"
+        );
+    }
+}
+
+pub(crate) fn default_scalar_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    let cases = [
+        (rng.gen_range(0..modulus), 0),
+        (rng.gen_range(0..modulus), 1),
+    ]
+    .into_iter()
+    .chain((0..nb_tests_smaller).map(|_| (rng.gen_range(0..modulus), rng.gen_range(0..64))))
+    .collect::<Vec<_>>();
+
+    for (clear_base, clear_exponent) in cases {
+        let ctxt_base = cks.encrypt(clear_base);
+
+        let ct_res = executor.execute((&ctxt_base, clear_exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = (0..clear_exponent).fold(1, |acc, _| (acc * clear_base) % modulus);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result for scalar_pow, for {clear_base}.pow({clear_exponent}) % {modulus}"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use std::sync::Arc;
+use tfhe_csprng::seeders::Seed;
+
+create_parameterized_test!(integer_default_oblivious_shuffle);
+
+fn integer_default_oblivious_shuffle<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::par_oblivious_shuffle::<RadixCiphertext>);
+    default_oblivious_shuffle_test_case(param, executor);
+}
+
+pub(crate) fn default_oblivious_shuffle_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(Seed, &'a [RadixCiphertext], u64), Vec<RadixCiphertext>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    let shuffled = executor.execute((Seed(0), &[], 16));
+    assert!(shuffled.is_empty());
+
+    let clear = rng.gen_range(0..modulus);
+    let shuffled = executor.execute((Seed(0), &[cks.encrypt(clear)], 16));
+    assert_eq!(shuffled.len(), 1);
+    assert_eq!(cks.decrypt::<u64>(&shuffled[0]), clear);
+
+    // Between 5 and 7 values, so that padding values are used. The values are distinct,
+    // so that each output gives the permutation that was applied
+    let num_values = rng.gen_range(5..=7);
+    let clears = rand::seq::index::sample(&mut rng, modulus as usize, num_values)
+        .into_iter()
+        .map(|value| value as u64)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // At least 4 seeds, for the permutations to be checked against each other
+    let mut permutations = Vec::new();
+    for _ in 0..nb_tests.max(4) {
+        let seed = Seed(rng.gen());
+        let shuffled = executor.execute((seed, &cts, 16));
+        assert_eq!(shuffled.len(), num_values);
+
+        let decrypted = shuffled
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+
+        let permutation = decrypted
+            .iter()
+            .map(|value| clears.iter().position(|clear| clear == value))
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_else(|| panic!("{decrypted:?} is not a permutation of {clears:?}"));
+        let mut sorted_permutation = permutation.clone();
+        sorted_permutation.sort_unstable();
+        assert!(
+            sorted_permutation.into_iter().eq(0..num_values),
+            "{decrypted:?} is not a permutation of {clears:?}"
+        );
+
+        if permutations.is_empty() {
+            let shuffled_2 = executor.execute((seed, &cts, 16));
+            assert_eq!(shuffled, shuffled_2, "Failed determinism test");
+        }
+
+        permutations.push(permutation);
+    }
+
+    // 4 uniform permutations of at least 5 elements are all the same with
+    // probability at most 1/120^3
+    permutations.sort_unstable();
+    permutations.dedup();
+    assert!(
+        permutations.len() > 1,
+        "Different seeds gave the same permutation"
+    );
+}
@@ -29,6 +29,7 @@ create_parameterized_test!(integer_unchecked_index_of);
 create_parameterized_test!(integer_unchecked_index_of_clear);
 create_parameterized_test!(integer_unchecked_first_index_of);
 create_parameterized_test!(integer_unchecked_first_index_of_clear);
+create_parameterized_test!(integer_unchecked_get_at_index);
 
 create_parameterized_test!(integer_default_match_value);
 create_parameterized_test!(integer_default_match_value_or);
@@ -41,6 +42,8 @@ create_parameterized_test!(integer_default_index_of);
 create_parameterized_test!(integer_default_index_of_clear);
 create_parameterized_test!(integer_default_first_index_of);
 create_parameterized_test!(integer_default_first_index_of_clear);
+create_parameterized_test!(integer_default_get_at_index);
+create_parameterized_test!(integer_default_set_at_index);
 
 fn integer_unchecked_match_value<P>(param: P)
 where
@@ -222,6 +225,30 @@ where
     default_first_index_of_clear_test_case(param, executor);
 }
 
+fn integer_unchecked_get_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_get_at_index_parallelized);
+    unchecked_get_at_index_test_case(param, executor);
+}
+
+fn integer_default_get_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::get_at_index_parallelized);
+    default_get_at_index_test_case(param, executor);
+}
+
+fn integer_default_set_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::set_at_index_parallelized);
+    default_set_at_index_test_case(param, executor);
+}
+
 /// This function takes a list of ciphertexts and their corresponding clear values
 /// and picks one ciphertext at random to make it so it has carries (via unchecked_add)
 ///
@@ -1936,3 +1963,168 @@ where
         assert_eq!(is_in, expected_is_in);
     }
 }
+
+pub(crate) fn unchecked_get_at_index_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [RadixCiphertext], &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        // Some indices are out of bounds
+        let clear_index = rng.gen_range(0..modulus.min(num_values as u64 + 2));
+
+        let cts = clears
+            .iter()
+            .copied()
+            .map(|v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let index = cks.encrypt(clear_index);
+
+        let result = executor.execute((&cts, &index));
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let expected = clears.get(clear_index as usize).copied().unwrap_or(0);
+        let result: u64 = cks.decrypt(&result);
+        assert_eq!(
+            result, expected,
+            "Invalid result for get_at_index, index: {clear_index}, values: {clears:?}"
+        );
+    }
+}
+
+pub(crate) fn default_get_at_index_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [RadixCiphertext], &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let clear_index = rng.gen_range(0..modulus.min(num_values as u64 + 2));
+
+        let mut cts = clears
+            .iter()
+            .copied()
+            .map(|v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+
+        // Make one of the values and the index have carries
+        let i = rng.gen_range(0..num_values);
+        let clear_0 = random_non_zero_value(&mut rng, modulus);
+        sks.unchecked_scalar_add_assign(&mut cts[i], clear_0);
+        clears[i] = clears[i].wrapping_add(clear_0) % modulus;
+
+        let mut index = cks.encrypt(0u64);
+        sks.unchecked_scalar_add_assign(&mut index, clear_index);
+
+        let result = executor.execute((&cts, &index));
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let result_2 = executor.execute((&cts, &index));
+        assert_eq!(result, result_2, "Failed determinism test");
+
+        let expected = clears.get(clear_index as usize).copied().unwrap_or(0);
+        let result: u64 = cks.decrypt(&result);
+        assert_eq!(
+            result, expected,
+            "Invalid result for get_at_index, index: {clear_index}, values: {clears:?}"
+        );
+    }
+}
+
+pub(crate) fn default_set_at_index_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut [RadixCiphertext],
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        // Some indices are out of bounds
+        let clear_index = rng.gen_range(0..modulus.min(num_values as u64 + 2));
+        let clear_value = rng.gen_range(0..modulus);
+
+        let mut cts = clears
+            .iter()
+            .copied()
+            .map(|v| cks.encrypt(v))
+            .collect::<Vec<_>>();
+        let index = cks.encrypt(clear_index);
+
+        // Make the value have carries
+        let clear_0 = random_non_zero_value(&mut rng, modulus);
+        let mut value = cks.encrypt(clear_value);
+        sks.unchecked_scalar_add_assign(&mut value, clear_0);
+        let clear_value = clear_value.wrapping_add(clear_0) % modulus;
+
+        executor.execute((&mut cts, &index, &value));
+
+        if let Some(clear) = clears.get_mut(clear_index as usize) {
+            *clear = clear_value;
+        }
+        for (ct, expected) in cts.iter().zip(clears.iter().copied()) {
+            panic_if_any_block_is_not_clean_or_trivial(ct, &cks);
+            let result: u64 = cks.decrypt(ct);
+            assert_eq!(
+                result, expected,
+                "Invalid result for set_at_index, index: {clear_index}, value: {clear_value}"
+            );
+        }
+    }
+}
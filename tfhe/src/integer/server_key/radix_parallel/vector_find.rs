@@ -1,5 +1,7 @@
+use super::bit_extractor::BitExtractor;
 use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::block_decomposition::{BlockDecomposer, Decomposable, DecomposableInto};
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
 use crate::prelude::CastInto;
 use crate::shortint::Ciphertext;
@@ -9,6 +11,10 @@ use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::Range;
 
+/// Slices with more elements than this are accessed at an encrypted index
+/// through a tree of selections rather than by comparing the index with each position
+const OBLIVIOUS_LINEAR_SCAN_MAX_LEN: usize = 16;
+
 /// MatchValues for the `match_value_parallelized` family of function
 ///
 /// This ensures the uniqueness of the inputs
@@ -1038,6 +1044,265 @@ impl ServerKey {
         self.unchecked_first_index_of_parallelized(cts, value)
     }
 
+    /// Returns the element of the slice at the encrypted `index`
+    ///
+    /// The index is not revealed, all the elements of the slice take part in the computation.
+    /// Small slices are read by comparing the index with each position, larger ones
+    /// through a tree of selections driven by the bits of the index.
+    ///
+    /// # Notes
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty
+    pub fn unchecked_get_at_index_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!cts.is_empty(), "Cannot read from an empty slice");
+        // Positions that the index cannot encrypt are never read
+        let cts = &cts[..self.num_reachable_positions(cts.len(), index)];
+
+        if cts.len() <= OBLIVIOUS_LINEAR_SCAN_MAX_LEN {
+            let selectors =
+                self.compute_equality_selectors(index, (0..cts.len() as u64).into_par_iter());
+            let masked = cts
+                .par_iter()
+                .zip(selectors.par_iter())
+                .map(|(ct, selector)| {
+                    let mut ct = ct.clone();
+                    self.zero_out_if_condition_is_false(&mut ct, &selector.0);
+                    ct
+                })
+                .collect::<Vec<_>>();
+            return self
+                .unchecked_sum_ciphertexts_vec_parallelized(masked)
+                .unwrap();
+        }
+
+        let (mut candidates, is_in_bounds) = rayon::join(
+            || {
+                let mut candidates = cts.to_vec();
+                for bit in self.extract_index_bits(index, cts.len()) {
+                    // The candidate without a sibling is only selected by out of bounds indices
+                    candidates = candidates
+                        .par_chunks(2)
+                        .map(|pair| match pair {
+                            [lhs, rhs] => self.if_then_else_parallelized(&bit, rhs, lhs),
+                            [single] => single.clone(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
+                }
+                candidates
+            },
+            || self.scalar_lt_parallelized(index, cts.len() as u64),
+        );
+        let mut result = candidates.pop().unwrap();
+        self.zero_out_if_condition_is_false(&mut result, &is_in_bounds.0);
+        result
+    }
+
+    /// Returns the element of the slice at the encrypted `index`
+    ///
+    /// The index is not revealed, all the elements of the slice take part in the computation.
+    /// Small slices are read by comparing the index with each position, larger ones
+    /// through a tree of selections driven by the bits of the index.
+    ///
+    /// # Notes
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let values = [12u8, 47, 3, 250];
+    /// let cts = values.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    /// let index = cks.as_ref().encrypt_radix(2u8, 1);
+    ///
+    /// let ct_res = sks.get_at_index_parallelized(&cts, &index);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, values[2]);
+    /// ```
+    pub fn get_at_index_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+        let mut tmp_index;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        self.unchecked_get_at_index_parallelized(cts, index)
+    }
+
+    /// Replaces the element of the slice at the encrypted `index` by `value`
+    ///
+    /// The index is not revealed, all the elements of the slice are rewritten.
+    /// Small slices compare the index with each position, larger ones
+    /// compute which position to write through a tree driven by the bits of the index.
+    ///
+    /// # Notes
+    ///
+    /// - If the index is out of bounds, the slice is left unchanged
+    pub fn unchecked_set_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &RadixCiphertext,
+        value: &T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        let num_reachable = self.num_reachable_positions(cts.len(), index);
+        let cts = &mut cts[..num_reachable];
+        if cts.is_empty() {
+            return;
+        }
+
+        let selectors = if cts.len() <= OBLIVIOUS_LINEAR_SCAN_MAX_LEN {
+            self.compute_equality_selectors(index, (0..cts.len() as u64).into_par_iter())
+        } else {
+            // Expands the bits of the index into a one-hot vector, starting from the msb
+            let is_in_bounds = self.scalar_lt_parallelized(index, cts.len() as u64);
+            let mut selectors = vec![is_in_bounds];
+            for bit in self.extract_index_bits(index, cts.len()).iter().rev() {
+                let not_bit = self.boolean_bitnot(bit);
+                selectors = selectors
+                    .par_iter()
+                    .flat_map(|selector| {
+                        [&not_bit, bit]
+                            .into_par_iter()
+                            .map(move |bit| self.boolean_bitand(selector, bit))
+                    })
+                    .collect();
+            }
+            selectors.truncate(cts.len());
+            selectors
+        };
+
+        cts.par_iter_mut()
+            .zip(selectors.par_iter())
+            .for_each(|(ct, selector)| {
+                *ct = self.if_then_else_parallelized(selector, value, ct);
+            });
+    }
+
+    /// Replaces the element of the slice at the encrypted `index` by `value`
+    ///
+    /// The index is not revealed, all the elements of the slice are rewritten.
+    /// Small slices compare the index with each position, larger ones
+    /// compute which position to write through a tree driven by the bits of the index.
+    ///
+    /// # Notes
+    ///
+    /// - If the index is out of bounds, the slice is left unchanged
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let mut values = [12u8, 47, 3, 250];
+    /// let mut cts = values.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    /// let index = cks.as_ref().encrypt_radix(1u8, 1);
+    /// let value = cks.encrypt(99u8);
+    ///
+    /// sks.set_at_index_parallelized(&mut cts, &index, &value);
+    ///
+    /// // Decrypt:
+    /// values[1] = 99;
+    /// for (ct, expected) in cts.iter().zip(values) {
+    ///     let res: u8 = cks.decrypt(ct);
+    ///     assert_eq!(res, expected);
+    /// }
+    /// ```
+    pub fn set_at_index_parallelized<T>(&self, cts: &mut [T], index: &RadixCiphertext, value: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_index;
+        let mut tmp_value;
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        let value = if value.block_carries_are_empty() {
+            value
+        } else {
+            tmp_value = value.clone();
+            self.full_propagate_parallelized(&mut tmp_value);
+            &tmp_value
+        };
+
+        self.unchecked_set_at_index_parallelized(cts, index, value);
+    }
+
+    /// Returns how many of the first `len` positions can be encrypted by the index
+    fn num_reachable_positions(&self, len: usize, index: &RadixCiphertext) -> usize {
+        let num_index_bits = index.blocks.len() as u32 * self.message_modulus().0.ilog2();
+        1usize
+            .checked_shl(num_index_bits)
+            .map_or(len, |num_positions| len.min(num_positions))
+    }
+
+    /// Returns the bits of the index needed to address `len` positions,
+    /// starting from the least significant one
+    fn extract_index_bits(&self, index: &RadixCiphertext, len: usize) -> Vec<BooleanBlock> {
+        let num_bits = (usize::BITS - (len - 1).leading_zeros()) as usize;
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        BitExtractor::new(&index.blocks, self, bits_per_block)
+            .extract_n_bits(num_bits)
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+
     fn compute_final_index_from_selectors(
         &self,
         selectors: Vec<BooleanBlock>,
@@ -0,0 +1,215 @@
+#![allow(clippy::use_self)]
+use crate::high_level_api::prelude::*;
+use crate::js_on_wasm_api::js_high_level_api::integers::*;
+use crate::js_on_wasm_api::js_high_level_api::{catch_panic, catch_panic_result, into_js_error};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct CompressedCiphertextListBuilder(crate::high_level_api::CompressedCiphertextListBuilder);
+
+#[wasm_bindgen]
+pub struct CompressedCiphertextList(crate::high_level_api::CompressedCiphertextList);
+
+#[wasm_bindgen]
+impl CompressedCiphertextList {
+    #[wasm_bindgen]
+    pub fn builder() -> Result<CompressedCiphertextListBuilder, JsError> {
+        catch_panic(|| {
+            CompressedCiphertextListBuilder(
+                crate::high_level_api::CompressedCiphertextListBuilder::new(),
+            )
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[wasm_bindgen]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_kind_of(&self, index: usize) -> Option<FheTypes> {
+        self.0.get_kind_of(index).map(Into::into)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bool(&self, index: usize) -> Result<FheBool, JsError> {
+        catch_panic_result(|| {
+            self.0
+                .get::<crate::FheBool>(index)
+                .map_err(into_js_error)
+                .map(|val| {
+                    val.map_or_else(
+                        || Err(JsError::new(&format!("Index {index} is out of bounds"))),
+                        |val| Ok(FheBool(val)),
+                    )
+                })?
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize(buffer: &[u8]) -> Result<CompressedCiphertextList, JsError> {
+        catch_panic_result(|| {
+            bincode::deserialize(buffer)
+                .map(CompressedCiphertextList)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_serialize(&self, serialized_size_limit: u64) -> Result<Vec<u8>, JsError> {
+        let mut buffer = vec![];
+        catch_panic_result(|| {
+            crate::safe_serialization::SerializationConfig::new(serialized_size_limit)
+                .serialize_into(&self.0, &mut buffer)
+                .map_err(into_js_error)
+        })?;
+
+        Ok(buffer)
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_deserialize(
+        buffer: &[u8],
+        serialized_size_limit: u64,
+    ) -> Result<CompressedCiphertextList, JsError> {
+        catch_panic_result(|| {
+            crate::safe_serialization::DeserializationConfig::new(serialized_size_limit)
+                .disable_conformance()
+                .deserialize_from(buffer)
+                .map(CompressedCiphertextList)
+                .map_err(into_js_error)
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl CompressedCiphertextListBuilder {
+    #[wasm_bindgen]
+    pub fn push_boolean(&mut self, value: &FheBool) -> Result<(), JsError> {
+        catch_panic(|| {
+            self.0.push(value.0.clone());
+        })
+    }
+
+    /// Compresses the pushed values, the server key set must have compression enabled
+    #[wasm_bindgen]
+    pub fn build(&self) -> Result<CompressedCiphertextList, JsError> {
+        catch_panic_result(|| {
+            self.0
+                .build()
+                .map(CompressedCiphertextList)
+                .map_err(into_js_error)
+        })
+    }
+}
+
+/// Helper macro to define push methods for the builder and get methods for the list
+/// one method per Fhe type possible as JS is not a typed language as Rust is
+macro_rules! define_compressed_list_push_and_get_methods {
+    (
+        unsigned: {
+            $($num_bits:literal),*
+            $(,)?
+        }
+    ) => {
+        ::paste::paste!{
+            #[wasm_bindgen]
+            impl CompressedCiphertextListBuilder {
+                $(
+                    #[wasm_bindgen]
+                    pub fn [<push_uint $num_bits>] (&mut self, value: &[<FheUint $num_bits>]) -> Result<(), JsError> {
+                        catch_panic(|| {
+                            self.0.push(value.0.clone());
+                        })
+                    }
+                )*
+            }
+
+            #[wasm_bindgen]
+            impl CompressedCiphertextList {
+                $(
+                    #[wasm_bindgen]
+                    pub fn [<get_uint $num_bits>] (&self, index: usize) -> Result<[<FheUint $num_bits>], JsError> {
+                        catch_panic_result(|| {
+                            self.0.get::<crate::[<FheUint $num_bits>]>(index)
+                                .map_err(into_js_error)
+                                .map(|val|
+                                      val.map_or_else(
+                                          || Err(JsError::new(&format!("Index {index} is out of bounds"))),
+                                          |val| Ok([<FheUint $num_bits>](val))
+                                    ))?
+                        })
+                    }
+                )*
+            }
+        }
+    };
+    (
+        signed: {
+            $($num_bits:literal),*
+            $(,)?
+        }
+    ) => {
+        ::paste::paste!{
+            #[wasm_bindgen]
+            impl CompressedCiphertextListBuilder {
+                $(
+                    #[wasm_bindgen]
+                    pub fn [<push_int $num_bits>] (&mut self, value: &[<FheInt $num_bits>]) -> Result<(), JsError> {
+                        catch_panic(|| {
+                            self.0.push(value.0.clone());
+                        })
+                    }
+                )*
+            }
+
+            #[wasm_bindgen]
+            impl CompressedCiphertextList {
+                $(
+                    #[wasm_bindgen]
+                    pub fn [<get_int $num_bits>] (&self, index: usize) -> Result<[<FheInt $num_bits>], JsError> {
+                        catch_panic_result(|| {
+                            self.0.get::<crate::[<FheInt $num_bits>]>(index)
+                                .map_err(into_js_error)
+                                .map(|val|
+                                      val.map_or_else(
+                                          || Err(JsError::new(&format!("Index {index} is out of bounds"))),
+                                          |val| Ok([<FheInt $num_bits>](val))
+                                    ))?
+                        })
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[cfg(feature = "extended-types")]
+define_compressed_list_push_and_get_methods!(
+    unsigned: { 24, 40, 48, 56, 72, 80, 88, 96, 104, 112, 120, 136, 144, 152, 168, 176, 184,
+                192, 200, 208, 216, 224, 232, 240, 248 }
+);
+
+define_compressed_list_push_and_get_methods!(
+    unsigned: { 2, 4, 6, 8, 10, 12, 14, 16, 32, 64, 128, 160, 256, 512, 1024, 2048 }
+);
+
+#[cfg(feature = "extended-types")]
+define_compressed_list_push_and_get_methods!(
+    signed: { 24, 40, 48, 56, 72, 80, 88, 96, 104, 112, 120, 136, 144, 152, 168, 176, 184, 192,
+              200, 208, 216, 224, 232, 240, 248 }
+);
+
+define_compressed_list_push_and_get_methods!(
+    signed: { 2, 4, 6, 8, 10, 12, 14, 16, 32, 64, 128, 160, 256, 512, 1024, 2048 }
+);
@@ -42,6 +42,14 @@ impl TfheConfigBuilder {
         )))
     }
 
+    #[wasm_bindgen]
+    pub fn enable_compression(
+        self,
+        compression_parameters: &crate::js_on_wasm_api::shortint::ShortintCompressionParameters,
+    ) -> Self {
+        Self(self.0.enable_compression(compression_parameters.0))
+    }
+
     #[wasm_bindgen]
     pub fn build(self) -> TfheConfig {
         TfheConfig(self.0.build())
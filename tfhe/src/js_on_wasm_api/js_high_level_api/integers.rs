@@ -197,6 +197,14 @@ macro_rules! create_wrapper_type_non_native_type (
                 })
             }
 
+            #[wasm_bindgen]
+            pub fn keyswitch(
+                &self,
+                key_switching_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfheKeySwitchingKey,
+            ) -> Result<$type_name, JsError> {
+                catch_panic(|| $type_name(key_switching_key.0.keyswitch(&self.0)))
+            }
+
             #[wasm_bindgen]
             pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
                 catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
@@ -715,6 +723,14 @@ macro_rules! create_wrapper_type_that_has_native_type (
                 catch_panic(|| self.0.decrypt(&client_key.0))
             }
 
+            #[wasm_bindgen]
+            pub fn keyswitch(
+                &self,
+                key_switching_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfheKeySwitchingKey,
+            ) -> Result<$type_name, JsError> {
+                catch_panic(|| $type_name(key_switching_key.0.keyswitch(&self.0)))
+            }
+
             #[wasm_bindgen]
             pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
                 catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
@@ -1,7 +1,9 @@
 use crate::high_level_api as hlapi;
 use crate::js_on_wasm_api::js_high_level_api::config::TfheConfig;
 use crate::js_on_wasm_api::js_high_level_api::{catch_panic, catch_panic_result, into_js_error};
-use crate::js_on_wasm_api::shortint::ShortintCompactPublicKeyEncryptionParameters;
+use crate::js_on_wasm_api::shortint::{
+    ShortintCompactPublicKeyEncryptionParameters, ShortintEncryptionKeyChoice,
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -136,6 +138,95 @@ impl TfheServerKey {
     }
 }
 
+#[wasm_bindgen]
+pub struct TfheKeySwitchingKey(pub(crate) hlapi::KeySwitchingKey);
+
+#[wasm_bindgen]
+impl TfheKeySwitchingKey {
+    #[wasm_bindgen]
+    pub fn new(
+        client_key_from: &TfheClientKey,
+        server_key_from: &TfheServerKey,
+        client_key_to: &TfheClientKey,
+        server_key_to: &TfheServerKey,
+    ) -> Result<TfheKeySwitchingKey, JsError> {
+        catch_panic_result(|| {
+            hlapi::KeySwitchingKey::new(
+                (&client_key_from.0, &server_key_from.0),
+                (&client_key_to.0, &server_key_to.0),
+            )
+            .map(Self)
+            .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn with_parameters(
+        client_key_from: &TfheClientKey,
+        server_key_from: &TfheServerKey,
+        client_key_to: &TfheClientKey,
+        server_key_to: &TfheServerKey,
+        ks_base_log: usize,
+        ks_level: usize,
+        destination_key: ShortintEncryptionKeyChoice,
+    ) -> Result<TfheKeySwitchingKey, JsError> {
+        catch_panic(|| {
+            let params =
+                crate::shortint::parameters::key_switching::ShortintKeySwitchingParameters {
+                    ks_base_log: crate::shortint::parameters::DecompositionBaseLog(ks_base_log),
+                    ks_level: crate::shortint::parameters::DecompositionLevelCount(ks_level),
+                    destination_key: destination_key.into(),
+                };
+
+            Self(hlapi::KeySwitchingKey::with_parameters(
+                (&client_key_from.0, &server_key_from.0),
+                (&client_key_to.0, &server_key_to.0),
+                params,
+            ))
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize(buffer: &[u8]) -> Result<TfheKeySwitchingKey, JsError> {
+        catch_panic_result(|| {
+            bincode::deserialize(buffer)
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_serialize(&self, serialized_size_limit: u64) -> Result<Vec<u8>, JsError> {
+        let mut buffer = vec![];
+        catch_panic_result(|| {
+            crate::safe_serialization::SerializationConfig::new(serialized_size_limit)
+                .serialize_into(&self.0, &mut buffer)
+                .map_err(into_js_error)
+        })?;
+
+        Ok(buffer)
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_deserialize(
+        buffer: &[u8],
+        serialized_size_limit: u64,
+    ) -> Result<TfheKeySwitchingKey, JsError> {
+        catch_panic_result(|| {
+            crate::safe_serialization::DeserializationConfig::new(serialized_size_limit)
+                .disable_conformance()
+                .deserialize_from(buffer)
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
+}
+
 #[wasm_bindgen]
 pub fn set_server_key(server_key: &TfheServerKey) -> Result<(), JsError> {
     catch_panic_result(|| {
@@ -1,5 +1,6 @@
 use wasm_bindgen::prelude::*;
 
+mod compressed_ciphertext_list;
 pub(crate) mod config;
 
 pub(crate) mod integers;
@@ -7,6 +7,7 @@ use crate::shortint::parameters::v0_11::classic::compact_pk::*;
 use crate::shortint::parameters::v0_11::classic::gaussian::*;
 use crate::shortint::parameters::v0_11::compact_public_key_only::p_fail_2_minus_64::ks_pbs::*;
 use crate::shortint::parameters::v0_11::key_switching::p_fail_2_minus_64::ks_pbs::*;
+use crate::shortint::parameters::v0_11::list_compression::*;
 use crate::shortint::parameters::v1_0::*;
 use crate::shortint::parameters::*;
 use std::panic::set_hook;
@@ -282,6 +283,59 @@ expose_predefined_pke_parameters!(
     ),
 );
 
+#[wasm_bindgen]
+pub struct ShortintCompressionParameters(
+    pub(crate) crate::shortint::parameters::list_compression::CompressionParameters,
+);
+
+macro_rules! expose_predefined_compression_parameters {
+    (
+        $(
+            $param_name:ident
+        ),*
+        $(,)?
+    ) => {
+        #[wasm_bindgen]
+        #[derive(Clone, Copy)]
+        #[allow(non_camel_case_types)]
+        pub enum ShortintCompressionParametersName {
+            $(
+                $param_name,
+            )*
+        }
+
+        // wasm bindgen does not support methods on enums
+        #[wasm_bindgen]
+        pub fn shortint_compression_params_name(param: ShortintCompressionParametersName) -> String {
+            match param {
+                $(
+                    ShortintCompressionParametersName::$param_name => stringify!($param_name).to_string(),
+                )*
+            }
+        }
+
+        #[wasm_bindgen]
+        impl ShortintCompressionParameters {
+            #[wasm_bindgen(constructor)]
+            pub fn new(name: ShortintCompressionParametersName) -> Self {
+                match name {
+                    $(
+                        ShortintCompressionParametersName::$param_name => {
+                            Self($param_name)
+                        }
+                    )*
+                }
+            }
+        }
+    }
+}
+
+expose_predefined_compression_parameters!(
+    COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V0_11_COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+);
+
 #[wasm_bindgen]
 impl ShortintCompactPublicKeyEncryptionParameters {
     #[wasm_bindgen]
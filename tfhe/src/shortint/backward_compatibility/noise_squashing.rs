@@ -1,4 +1,6 @@
-use crate::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+use crate::shortint::noise_squashing::{
+    CompressedNoiseSquashingKey, NoiseSquashingKey, NoiseSquashingPrivateKey,
+};
 use tfhe_versionable::VersionsDispatch;
 
 #[derive(VersionsDispatch)]
@@ -10,3 +12,8 @@ pub enum NoiseSquashingPrivateKeyVersions {
 pub enum NoiseSquashingKeyVersions {
     V0(NoiseSquashingKey),
 }
+
+#[derive(VersionsDispatch)]
+pub enum CompressedNoiseSquashingKeyVersions {
+    V0(CompressedNoiseSquashingKey),
+}
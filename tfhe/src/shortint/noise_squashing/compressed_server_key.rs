@@ -0,0 +1,148 @@
+use super::{NoiseSquashingKey, NoiseSquashingKeyConformanceParams, NoiseSquashingPrivateKey};
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::algorithms::lwe_bootstrap_key_conversion::par_convert_standard_lwe_bootstrap_key_to_fourier_128;
+use crate::core_crypto::algorithms::lwe_bootstrap_key_generation::par_allocate_and_generate_new_seeded_lwe_bootstrap_key;
+use crate::core_crypto::commons::math::random::CompressionSeed;
+use crate::core_crypto::entities::{Fourier128LweBootstrapKeyOwned, SeededLweBootstrapKeyOwned};
+use crate::shortint::backward_compatibility::noise_squashing::CompressedNoiseSquashingKeyVersions;
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::noise_squashing::NoiseSquashingParameters;
+use crate::shortint::server_key::{
+    CompressedModulusSwitchNoiseReductionKey, ModulusSwitchNoiseReductionKeyConformanceParams,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_csprng::seeders::Seeder;
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedNoiseSquashingKeyVersions)]
+pub struct CompressedNoiseSquashingKey {
+    bootstrapping_key: SeededLweBootstrapKeyOwned<u128>,
+    modulus_switch_noise_reduction_key: Option<CompressedModulusSwitchNoiseReductionKey>,
+    params: NoiseSquashingParameters,
+}
+
+impl ClientKey {
+    pub fn new_compressed_noise_squashing_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> CompressedNoiseSquashingKey {
+        let pbs_parameters = self
+            .parameters
+            .pbs_parameters()
+            .expect("CompressedNoiseSquashingKey generation requires PBSParameters");
+
+        let noise_squashing_parameters = noise_squashing_private_key.noise_squashing_parameters();
+
+        let (bootstrapping_key, modulus_switch_noise_reduction_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let seeded_bsk = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+                    &self.lwe_secret_key,
+                    noise_squashing_private_key.post_noise_squashing_secret_key(),
+                    noise_squashing_parameters.decomp_base_log,
+                    noise_squashing_parameters.decomp_level_count,
+                    noise_squashing_parameters.glwe_noise_distribution,
+                    noise_squashing_parameters.ciphertext_modulus,
+                    &mut engine.seeder,
+                );
+
+                let modulus_switch_noise_reduction_key = noise_squashing_parameters
+                    .modulus_switch_noise_reduction_params
+                    .map(|p| {
+                        let seed = engine.seeder.seed();
+
+                        CompressedModulusSwitchNoiseReductionKey::new(
+                            p,
+                            &self.lwe_secret_key,
+                            engine,
+                            pbs_parameters.ciphertext_modulus(),
+                            pbs_parameters.lwe_noise_distribution(),
+                            CompressionSeed { seed },
+                        )
+                    });
+
+                (seeded_bsk, modulus_switch_noise_reduction_key)
+            });
+
+        CompressedNoiseSquashingKey {
+            bootstrapping_key,
+            modulus_switch_noise_reduction_key,
+            params: noise_squashing_parameters,
+        }
+    }
+}
+
+impl CompressedNoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        client_key.new_compressed_noise_squashing_key(noise_squashing_private_key)
+    }
+
+    pub fn decompress(&self) -> NoiseSquashingKey {
+        let std_bsk = self
+            .bootstrapping_key
+            .as_view()
+            .par_decompress_into_lwe_bootstrap_key();
+
+        let mut fbsk = Fourier128LweBootstrapKeyOwned::new(
+            std_bsk.input_lwe_dimension(),
+            std_bsk.glwe_size(),
+            std_bsk.polynomial_size(),
+            std_bsk.decomposition_base_log(),
+            std_bsk.decomposition_level_count(),
+        );
+
+        par_convert_standard_lwe_bootstrap_key_to_fourier_128(&std_bsk, &mut fbsk);
+
+        NoiseSquashingKey::from_raw_parts(
+            fbsk,
+            self.modulus_switch_noise_reduction_key
+                .as_ref()
+                .map(CompressedModulusSwitchNoiseReductionKey::decompress),
+            self.params,
+        )
+    }
+
+    pub fn noise_squashing_parameters(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+}
+
+impl ParameterSetConformant for CompressedNoiseSquashingKey {
+    type ParameterSet = NoiseSquashingKeyConformanceParams;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self {
+            bootstrapping_key,
+            modulus_switch_noise_reduction_key,
+            params,
+        } = self;
+
+        let modulus_switch_noise_reduction_key_is_ok = match (
+            modulus_switch_noise_reduction_key.as_ref(),
+            parameter_set.modulus_switch_noise_reduction_params,
+        ) {
+            (None, None) => true,
+            (Some(key), Some(modulus_switch_noise_reduction_params)) => {
+                key.is_conformant(&ModulusSwitchNoiseReductionKeyConformanceParams {
+                    modulus_switch_noise_reduction_params,
+                    lwe_dimension: parameter_set.bootstrapping_key_input_lwe_dimension,
+                })
+            }
+            _ => return false,
+        };
+
+        bootstrapping_key.input_lwe_dimension()
+            == parameter_set.bootstrapping_key_input_lwe_dimension
+            && bootstrapping_key.glwe_size() == parameter_set.glwe_dimension.to_glwe_size()
+            && bootstrapping_key.polynomial_size() == parameter_set.polynomial_size
+            && bootstrapping_key.decomposition_base_log() == parameter_set.decomp_base_log
+            && bootstrapping_key.decomposition_level_count() == parameter_set.decomp_level_count
+            && bootstrapping_key.ciphertext_modulus() == parameter_set.ciphertext_modulus
+            && params.ciphertext_modulus == parameter_set.ciphertext_modulus
+            && modulus_switch_noise_reduction_key_is_ok
+    }
+}
@@ -1,7 +1,9 @@
+mod compressed_server_key;
 mod private_key;
 mod server_key;
 #[cfg(test)]
 pub mod tests;
 
+pub use compressed_server_key::CompressedNoiseSquashingKey;
 pub use private_key::NoiseSquashingPrivateKey;
-pub use server_key::NoiseSquashingKey;
+pub use server_key::{NoiseSquashingKey, NoiseSquashingKeyConformanceParams};
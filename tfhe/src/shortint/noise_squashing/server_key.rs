@@ -1,4 +1,5 @@
 use super::NoiseSquashingPrivateKey;
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::algorithms::lwe_bootstrap_key_conversion::par_convert_standard_lwe_bootstrap_key_to_fourier_128;
 use crate::core_crypto::algorithms::lwe_bootstrap_key_generation::par_allocate_and_generate_new_lwe_bootstrap_key;
 use crate::core_crypto::algorithms::lwe_keyswitch::keyswitch_lwe_ciphertext;
@@ -15,8 +16,14 @@ use crate::shortint::client_key::ClientKey;
 use crate::shortint::encoding::{compute_delta, PaddingBit};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::noise_squashing::NoiseSquashingParameters;
-use crate::shortint::parameters::{CarryModulus, MessageModulus, PBSOrder};
-use crate::shortint::server_key::{ModulusSwitchNoiseReductionKey, ServerKey};
+use crate::shortint::parameters::{
+    CarryModulus, CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    GlweDimension, LweDimension, MessageModulus, ModulusSwitchNoiseReductionParams, PBSOrder,
+    PBSParameters, PolynomialSize,
+};
+use crate::shortint::server_key::{
+    ModulusSwitchNoiseReductionKey, ModulusSwitchNoiseReductionKeyConformanceParams, ServerKey,
+};
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -86,6 +93,18 @@ impl ClientKey {
 }
 
 impl NoiseSquashingKey {
+    pub(crate) fn from_raw_parts(
+        bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+        modulus_switch_noise_reduction_key: Option<ModulusSwitchNoiseReductionKey>,
+        params: NoiseSquashingParameters,
+    ) -> Self {
+        Self {
+            bootstrapping_key,
+            modulus_switch_noise_reduction_key,
+            params,
+        }
+    }
+
     pub fn new(
         client_key: &ClientKey,
         noise_squashing_private_key: &NoiseSquashingPrivateKey,
@@ -93,6 +112,10 @@ impl NoiseSquashingKey {
         client_key.new_noise_squashing_key(noise_squashing_private_key)
     }
 
+    pub fn noise_squashing_parameters(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
     pub fn squash_ciphertext_noise(
         &self,
         ciphertext: &Ciphertext,
@@ -204,3 +227,68 @@ impl NoiseSquashingKey {
         res
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct NoiseSquashingKeyConformanceParams {
+    pub bootstrapping_key_input_lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub modulus_switch_noise_reduction_params: Option<ModulusSwitchNoiseReductionParams>,
+    pub ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+impl From<(PBSParameters, NoiseSquashingParameters)> for NoiseSquashingKeyConformanceParams {
+    fn from(
+        (pbs_params, noise_squashing_params): (PBSParameters, NoiseSquashingParameters),
+    ) -> Self {
+        Self {
+            bootstrapping_key_input_lwe_dimension: pbs_params.lwe_dimension(),
+            glwe_dimension: noise_squashing_params.glwe_dimension,
+            polynomial_size: noise_squashing_params.polynomial_size,
+            decomp_base_log: noise_squashing_params.decomp_base_log,
+            decomp_level_count: noise_squashing_params.decomp_level_count,
+            modulus_switch_noise_reduction_params: noise_squashing_params
+                .modulus_switch_noise_reduction_params,
+            ciphertext_modulus: noise_squashing_params.ciphertext_modulus,
+        }
+    }
+}
+
+impl ParameterSetConformant for NoiseSquashingKey {
+    type ParameterSet = NoiseSquashingKeyConformanceParams;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self {
+            bootstrapping_key,
+            modulus_switch_noise_reduction_key,
+            params,
+        } = self;
+
+        let modulus_switch_noise_reduction_key_is_ok = match (
+            modulus_switch_noise_reduction_key.as_ref(),
+            parameter_set.modulus_switch_noise_reduction_params,
+        ) {
+            (None, None) => true,
+            (Some(key), Some(modulus_switch_noise_reduction_params)) => {
+                key.is_conformant(&ModulusSwitchNoiseReductionKeyConformanceParams {
+                    modulus_switch_noise_reduction_params,
+                    lwe_dimension: parameter_set.bootstrapping_key_input_lwe_dimension,
+                })
+            }
+            _ => return false,
+        };
+
+        bootstrapping_key.input_lwe_dimension()
+            == parameter_set.bootstrapping_key_input_lwe_dimension
+            && bootstrapping_key.glwe_size() == parameter_set.glwe_dimension.to_glwe_size()
+            && bootstrapping_key.polynomial_size() == parameter_set.polynomial_size
+            && bootstrapping_key.decomposition_base_log() == parameter_set.decomp_base_log
+            && bootstrapping_key.decomposition_level_count() == parameter_set.decomp_level_count
+            // The Fourier bootstrapping key does not store a ciphertext modulus, the one it was
+            // generated with is the modulus of the parameters
+            && params.ciphertext_modulus == parameter_set.ciphertext_modulus
+            && modulus_switch_noise_reduction_key_is_ok
+    }
+}
@@ -1,32 +1,21 @@
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::prelude::CiphertextModulus;
 use crate::shortint::keycache::KEY_CACHE;
-use crate::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+use crate::shortint::noise_squashing::{
+    CompressedNoiseSquashingKey, NoiseSquashingKey, NoiseSquashingKeyConformanceParams,
+    NoiseSquashingPrivateKey,
+};
 use crate::shortint::parameters::*;
 use rand::prelude::*;
 use rand::thread_rng;
 
 #[test]
 fn test_noise_squashing_ci_run_filter() {
-    const TEST_NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128:
-        NoiseSquashingParameters = NoiseSquashingParameters {
-        glwe_dimension: GlweDimension(2),
-        polynomial_size: PolynomialSize(2048),
-        glwe_noise_distribution: DynamicDistribution::new_t_uniform(30),
-        decomp_base_log: DecompositionBaseLog(24),
-        decomp_level_count: DecompositionLevelCount(3),
-        modulus_switch_noise_reduction_params: Some(ModulusSwitchNoiseReductionParams {
-            modulus_switch_zeros_count: LweCiphertextCount(1449),
-            ms_bound: NoiseEstimationMeasureBound(288230376151711744f64),
-            ms_r_sigma_factor: RSigmaFactor(13.179852282053789f64),
-            ms_input_variance: Variance(2.63039184094559E-7f64),
-        }),
-        ciphertext_modulus: CoreCiphertextModulus::<u128>::new_native(),
-    };
-
     let keycache_entry = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
     let (cks, sks) = (keycache_entry.client_key(), keycache_entry.server_key());
     let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
         cks,
-        TEST_NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
     );
     let noise_squashing_key = NoiseSquashingKey::new(cks, &noise_squashing_private_key);
 
@@ -62,3 +51,61 @@ fn test_noise_squashing_ci_run_filter() {
         assert_eq!(recovered, expected_u128);
     }
 }
+
+#[test]
+fn test_compressed_noise_squashing_ci_run_filter() {
+    let keycache_entry = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    let (cks, sks) = (keycache_entry.client_key(), keycache_entry.server_key());
+    let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+        cks,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    );
+    let compressed_noise_squashing_key =
+        CompressedNoiseSquashingKey::new(cks, &noise_squashing_private_key);
+    let noise_squashing_key = compressed_noise_squashing_key.decompress();
+
+    let mut rng = thread_rng();
+
+    let id_lut = sks.generate_lookup_table(|x| x);
+
+    for _ in 0..10 {
+        let msg = rng.gen::<u64>() % cks.parameters.message_modulus().0;
+
+        let mut ct = cks.encrypt(msg);
+        sks.apply_lookup_table_assign(&mut ct, &id_lut);
+
+        let squashed_noise_ct = noise_squashing_key.squash_ciphertext_noise(&ct, sks);
+
+        let recovered =
+            noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed_noise_ct);
+
+        assert_eq!(recovered, u128::from(msg));
+    }
+}
+
+#[test]
+fn test_noise_squashing_key_conformance() {
+    let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    let noise_squashing_params = NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+    let keycache_entry = KEY_CACHE.get_from_param(params);
+    let cks = keycache_entry.client_key();
+    let noise_squashing_private_key = NoiseSquashingPrivateKey::new(cks, noise_squashing_params);
+    let compressed_noise_squashing_key =
+        CompressedNoiseSquashingKey::new(cks, &noise_squashing_private_key);
+    let noise_squashing_key = compressed_noise_squashing_key.decompress();
+
+    let conformance_params =
+        NoiseSquashingKeyConformanceParams::from((params.into(), noise_squashing_params));
+
+    assert!(noise_squashing_key.is_conformant(&conformance_params));
+    assert!(compressed_noise_squashing_key.is_conformant(&conformance_params));
+
+    let mismatched_modulus_params = NoiseSquashingKeyConformanceParams {
+        ciphertext_modulus: CiphertextModulus::try_new_power_of_2(127).unwrap(),
+        ..conformance_params
+    };
+
+    assert!(!noise_squashing_key.is_conformant(&mismatched_modulus_params));
+    assert!(!compressed_noise_squashing_key.is_conformant(&mismatched_modulus_params));
+}
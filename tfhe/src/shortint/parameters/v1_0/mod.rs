@@ -7,6 +7,7 @@ pub mod compact_public_key_only;
 pub mod key_switching;
 pub mod list_compression;
 pub mod multi_bit;
+pub mod noise_squashing;
 
 pub use classic::compact_pk::gaussian::p_fail_2_minus_128::ks_pbs::*;
 pub use classic::compact_pk::gaussian::p_fail_2_minus_128::pbs_ks::*;
@@ -35,6 +36,7 @@ pub use multi_bit::tuniform::p_fail_2_minus_40::ks_pbs::*;
 pub use multi_bit::tuniform::p_fail_2_minus_40::ks_pbs_gpu::*;
 pub use multi_bit::tuniform::p_fail_2_minus_64::ks_pbs::*;
 pub use multi_bit::tuniform::p_fail_2_minus_64::ks_pbs_gpu::*;
+pub use noise_squashing::p_fail_2_minus_128::*;
 
 use crate::shortint::parameters::{
     ClassicPBSParameters, CompactPublicKeyEncryptionParameters, CompressionParameters,
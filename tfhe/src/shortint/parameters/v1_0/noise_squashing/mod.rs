@@ -0,0 +1,3 @@
+pub mod p_fail_2_minus_128;
+
+pub use p_fail_2_minus_128::*;
@@ -171,6 +171,11 @@ impl crate::integer::ciphertext::Expandable for FheString {
             DataKind::Boolean => Err(crate::Error::new(
                 "Tried to expand a string while a boolean was stored".to_string(),
             )),
+            DataKind::UnsignedFixed { .. } | DataKind::SignedFixed { .. } => {
+                Err(crate::Error::new(
+                    "Tried to expand a string while a fixed-point number was stored".to_string(),
+                ))
+            }
         }
     }
 }
@@ -0,0 +1,7 @@
+//! SHA-256 and HMAC-SHA-256 on encrypted strings and bytes
+//!
+//! The digests are computed with the server key set with
+//! [set_server_key](crate::set_server_key), see [sha256] and [hmac_sha256].
+
+pub use crate::high_level_api::strings::hash::{digest_to_uint256, hmac_sha256, sha256, HashInput};
+pub use crate::strings::server_key::HashBackend;
@@ -0,0 +1,6 @@
+//! Extraction of values from encrypted JSON documents
+//!
+//! The values are extracted with the server key set with
+//! [set_server_key](crate::set_server_key), see [extract] and [extract_u64].
+
+pub use crate::high_level_api::strings::json::{extract, extract_u64};
@@ -1,5 +1,8 @@
 pub mod ciphertext;
 pub mod client_key;
+pub mod hash;
+pub mod json;
+pub mod regex;
 pub mod server_key;
 
 mod backward_compatibility;
@@ -0,0 +1,236 @@
+use super::parser::{Ast, CharSet};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Edge {
+    Epsilon,
+    /// Epsilon transition only allowed at the start of the text
+    Start,
+    /// Epsilon transition only allowed at the end of the text
+    End,
+    /// Consumes a character of the set
+    Chars(CharSet),
+}
+
+/// States reachable from a state using epsilon transitions
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Closure {
+    /// Reachable without going through an [Edge::End]
+    pub(crate) direct: Vec<usize>,
+    /// Reachable only by going through at least one [Edge::End]
+    pub(crate) through_end: Vec<usize>,
+}
+
+/// A non-deterministic finite automaton (Thompson construction)
+///
+/// To limit the number of encrypted values needed during evaluation, the automaton also
+/// precomputes the epsilon closures restricted to the *important* states: the ones that consume
+/// a character and the accepting state.
+#[derive(Clone, Debug)]
+pub(crate) struct Nfa {
+    edges: Vec<Vec<(Edge, usize)>>,
+    start: usize,
+    accept: usize,
+    /// Index (in `edges`) of each important state
+    important: Vec<usize>,
+    /// Character transitions of each important state, targets are indices in `edges`
+    char_edges: Vec<Vec<(CharSet, usize)>>,
+    /// Closures of each state (indexed by state in `edges`), outer index is 1 if at the start
+    /// of the text
+    closures: [Vec<Closure>; 2],
+}
+
+impl Nfa {
+    pub(crate) fn compile(ast: &Ast) -> Self {
+        let mut nfa = Self {
+            edges: vec![],
+            start: 0,
+            accept: 0,
+            important: vec![],
+            char_edges: vec![],
+            closures: [vec![], vec![]],
+        };
+
+        let (start, accept) = nfa.build(ast);
+        nfa.start = start;
+        nfa.accept = accept;
+
+        nfa.important = (0..nfa.edges.len())
+            .filter(|&s| {
+                s == accept
+                    || nfa.edges[s]
+                        .iter()
+                        .any(|(edge, _)| matches!(edge, Edge::Chars(_)))
+            })
+            .collect();
+        nfa.char_edges = nfa
+            .important
+            .iter()
+            .map(|&s| {
+                nfa.edges[s]
+                    .iter()
+                    .filter_map(|(edge, target)| match edge {
+                        Edge::Chars(set) => Some((*set, *target)),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+        nfa.closures = [false, true].map(|at_start| {
+            (0..nfa.edges.len())
+                .map(|s| nfa.compute_closure(s, at_start))
+                .collect()
+        });
+
+        nfa
+    }
+
+    pub(crate) fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Index of the accepting state among the important states
+    pub(crate) fn accept_index(&self) -> usize {
+        self.important.binary_search(&self.accept).unwrap()
+    }
+
+    pub(crate) fn num_important_states(&self) -> usize {
+        self.important.len()
+    }
+
+    /// Character transitions of an important state
+    pub(crate) fn char_edges(&self, important_index: usize) -> &[(CharSet, usize)] {
+        &self.char_edges[important_index]
+    }
+
+    /// Returns the important states (as indices among the important states) reachable from
+    /// `state` with epsilon transitions
+    pub(crate) fn closure(&self, state: usize, at_start: bool) -> &Closure {
+        &self.closures[usize::from(at_start)][state]
+    }
+
+    /// All the character sets used in transitions
+    pub(crate) fn char_sets(&self) -> Vec<CharSet> {
+        let mut sets = Vec::new();
+        for (set, _) in self.char_edges.iter().flatten() {
+            if !sets.contains(set) {
+                sets.push(*set);
+            }
+        }
+        sets
+    }
+
+    fn compute_closure(&self, state: usize, at_start: bool) -> Closure {
+        // 0: not visited, 1: visited without End, 2: visited only through End
+        let mut visited = vec![0u8; self.edges.len()];
+        let mut stack = vec![(state, false)];
+
+        while let Some((s, through_end)) = stack.pop() {
+            let mark = if through_end { 2 } else { 1 };
+            if visited[s] != 0 && visited[s] <= mark {
+                continue;
+            }
+            visited[s] = mark;
+
+            for (edge, target) in &self.edges[s] {
+                match edge {
+                    Edge::Epsilon => stack.push((*target, through_end)),
+                    Edge::Start if at_start => stack.push((*target, through_end)),
+                    Edge::End => stack.push((*target, true)),
+                    Edge::Start | Edge::Chars(_) => {}
+                }
+            }
+        }
+
+        let mut closure = Closure::default();
+        for (index, s) in self.important.iter().enumerate() {
+            match visited[*s] {
+                1 => closure.direct.push(index),
+                2 => closure.through_end.push(index),
+                _ => {}
+            }
+        }
+        closure
+    }
+
+    fn add_state(&mut self) -> usize {
+        self.edges.push(vec![]);
+        self.edges.len() - 1
+    }
+
+    fn add_edge(&mut self, from: usize, edge: Edge, to: usize) {
+        self.edges[from].push((edge, to));
+    }
+
+    /// Builds the fragment of the automaton matching `ast`, returns its entry and exit states
+    fn build(&mut self, ast: &Ast) -> (usize, usize) {
+        match ast {
+            Ast::Empty => {
+                let state = self.add_state();
+                (state, state)
+            }
+            Ast::Chars(set) => self.build_edge(Edge::Chars(*set)),
+            Ast::Start => self.build_edge(Edge::Start),
+            Ast::End => self.build_edge(Edge::End),
+            Ast::Group(ast) => self.build(ast),
+            Ast::Concat(items) => {
+                let (entry, mut exit) = self.build(&items[0]);
+                for item in &items[1..] {
+                    let (item_entry, item_exit) = self.build(item);
+                    self.add_edge(exit, Edge::Epsilon, item_entry);
+                    exit = item_exit;
+                }
+                (entry, exit)
+            }
+            Ast::Alternate(alternatives) => {
+                let entry = self.add_state();
+                let exit = self.add_state();
+                for alternative in alternatives {
+                    let (alt_entry, alt_exit) = self.build(alternative);
+                    self.add_edge(entry, Edge::Epsilon, alt_entry);
+                    self.add_edge(alt_exit, Edge::Epsilon, exit);
+                }
+                (entry, exit)
+            }
+            Ast::Repeat { ast, min, max } => {
+                let entry = self.add_state();
+                let mut exit = entry;
+
+                for _ in 0..*min {
+                    let (copy_entry, copy_exit) = self.build(ast);
+                    self.add_edge(exit, Edge::Epsilon, copy_entry);
+                    exit = copy_exit;
+                }
+
+                match max {
+                    None => {
+                        let loop_state = self.add_state();
+                        let (copy_entry, copy_exit) = self.build(ast);
+                        self.add_edge(exit, Edge::Epsilon, loop_state);
+                        self.add_edge(loop_state, Edge::Epsilon, copy_entry);
+                        self.add_edge(copy_exit, Edge::Epsilon, loop_state);
+                        exit = loop_state;
+                    }
+                    Some(max) => {
+                        for _ in *min..*max {
+                            let (copy_entry, copy_exit) = self.build(ast);
+                            let next = self.add_state();
+                            self.add_edge(exit, Edge::Epsilon, copy_entry);
+                            self.add_edge(copy_exit, Edge::Epsilon, next);
+                            self.add_edge(exit, Edge::Epsilon, next);
+                            exit = next;
+                        }
+                    }
+                }
+
+                (entry, exit)
+            }
+        }
+    }
+
+    fn build_edge(&mut self, edge: Edge) -> (usize, usize) {
+        let entry = self.add_state();
+        let exit = self.add_state();
+        self.add_edge(entry, edge, exit);
+        (entry, exit)
+    }
+}
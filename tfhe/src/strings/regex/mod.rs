@@ -0,0 +1,114 @@
+//! Regular expressions evaluated on encrypted strings
+//!
+//! A [CompiledRegex] is built once from a clear pattern, it can then be evaluated on any
+//! number of encrypted strings, see [ServerKey::regex_is_match], [ServerKey::regex_find] and
+//! [ServerKey::regex_captures].
+//!
+//! The supported syntax is a subset of the usual one, restricted to ASCII:
+//!
+//! - literal characters, `.` (any character), `^` and `$` anchors
+//! - character classes `[abc]`, `[a-z]`, `[^abc]`, and the `\d`, `\w` and `\s` escapes
+//! - groups `(...)` and alternations `a|b`
+//! - repetitions `*`, `+`, `?`, `{n}`, `{n,}`, `{,m}` and `{n,m}` (bounded counts are at most 64)
+//!
+//! [ServerKey::regex_is_match]: crate::strings::ServerKey::regex_is_match
+//! [ServerKey::regex_find]: crate::strings::ServerKey::regex_find
+//! [ServerKey::regex_captures]: crate::strings::ServerKey::regex_captures
+mod automaton;
+mod parser;
+
+pub(crate) use automaton::Nfa;
+pub(crate) use parser::CharSet;
+use parser::{parse, Ast};
+
+/// A regular expression compiled to an automaton, ready to be evaluated on encrypted strings
+///
+/// Capture groups are the groups that appear directly in the top level sequence of the pattern,
+/// e.g. `(\w+)@(\w+)` has two of them. Groups nested in a repetition, an alternation or another
+/// group are not capturing.
+#[derive(Clone, Debug)]
+pub struct CompiledRegex {
+    nfa: Nfa,
+    /// Consecutive parts of the pattern, each capture group is one of them
+    segments: Vec<Nfa>,
+    /// For each capture group, the index of its segment
+    group_segments: Vec<usize>,
+}
+
+impl CompiledRegex {
+    /// Compiles a case sensitive regular expression
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::strings::regex::CompiledRegex;
+    ///
+    /// let regex = CompiledRegex::new(r"^(\d+)-(\d+)$").unwrap();
+    /// assert_eq!(regex.num_groups(), 2);
+    ///
+    /// assert!(CompiledRegex::new("a{2").is_err());
+    /// ```
+    pub fn new(pattern: &str) -> crate::Result<Self> {
+        Self::compile(pattern, false)
+    }
+
+    /// Compiles a regular expression where ASCII letters match regardless of their case
+    pub fn new_case_insensitive(pattern: &str) -> crate::Result<Self> {
+        Self::compile(pattern, true)
+    }
+
+    fn compile(pattern: &str, case_insensitive: bool) -> crate::Result<Self> {
+        let ast = parse(pattern, case_insensitive)?;
+
+        let items = match &ast {
+            Ast::Concat(items) => items.as_slice(),
+            Ast::Group(_) => std::slice::from_ref(&ast),
+            _ => &[],
+        };
+
+        let mut segment_asts = vec![];
+        let mut group_segments = vec![];
+        let mut current = vec![];
+        for item in items {
+            if let Ast::Group(inner) = item {
+                segment_asts.push(Ast::Concat(std::mem::take(&mut current)));
+                group_segments.push(segment_asts.len());
+                segment_asts.push((**inner).clone());
+            } else {
+                current.push(item.clone());
+            }
+        }
+        segment_asts.push(Ast::Concat(current));
+
+        let segments = segment_asts
+            .iter()
+            .map(|ast| match ast {
+                Ast::Concat(items) if items.is_empty() => Nfa::compile(&Ast::Empty),
+                ast => Nfa::compile(ast),
+            })
+            .collect();
+
+        Ok(Self {
+            nfa: Nfa::compile(&ast),
+            segments,
+            group_segments,
+        })
+    }
+
+    /// Number of capture groups, the whole match is not counted
+    pub fn num_groups(&self) -> usize {
+        self.group_segments.len()
+    }
+
+    pub(crate) fn nfa(&self) -> &Nfa {
+        &self.nfa
+    }
+
+    pub(crate) fn segments(&self) -> &[Nfa] {
+        &self.segments
+    }
+
+    pub(crate) fn group_segments(&self) -> &[usize] {
+        &self.group_segments
+    }
+}
@@ -0,0 +1,382 @@
+//! Parser for the supported regular expression syntax
+//!
+//! ```text
+//! <regex>      ::= <concat> { '|' <concat> }
+//! <concat>     ::= { <repeat> }
+//! <repeat>     ::= <atom> { '*' | '+' | '?' | '{' n '}' | '{' n ',' [m] '}' | '{' ',' m '}' }
+//! <atom>       ::= '.' | '^' | '$' | '(' <regex> ')' | '[' ['^'] <class item>+ ']'
+//!               |  '\' <char> | <char>
+//! ```
+//!
+//! `\d`, `\w` and `\s` are the usual ASCII digit, word and whitespace classes, any other escaped
+//! character is taken literally.
+
+/// Maximum count allowed in a bounded repetition (`{n,m}`), as bounded repetitions are unrolled
+const MAX_REPETITION: usize = 64;
+
+/// Maximum number of states of the automaton of a pattern, as nested repetitions multiply the
+/// number of unrolled copies and the encrypted evaluation is quadratic in the number of states
+const MAX_STATES: usize = 1024;
+
+/// A set of ASCII characters, bit `i` is set if the character `i` is in the set
+///
+/// The null character is never part of a set, as it is used as padding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CharSet(pub(crate) u128);
+
+impl CharSet {
+    pub(crate) const ANY: Self = Self(!1);
+
+    pub(crate) fn single(c: u8) -> Self {
+        Self::range(c, c)
+    }
+
+    pub(crate) fn range(from: u8, to: u8) -> Self {
+        let mut set = 0u128;
+        for c in from..=to {
+            set |= 1 << c;
+        }
+        Self(set & Self::ANY.0)
+    }
+
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub(crate) fn complement(self) -> Self {
+        Self(!self.0 & Self::ANY.0)
+    }
+
+    pub(crate) fn contains(self, c: u8) -> bool {
+        c < 128 && (self.0 >> c) & 1 == 1
+    }
+
+    pub(crate) fn case_insensitive(self) -> Self {
+        let mut result = self;
+        for c in 0..128u8 {
+            if self.contains(c) {
+                result = result.union(Self::single(c.to_ascii_lowercase()));
+                result = result.union(Self::single(c.to_ascii_uppercase()));
+            }
+        }
+        result
+    }
+
+    /// Returns the set as a list of inclusive ranges
+    pub(crate) fn ranges(self) -> Vec<(u8, u8)> {
+        let mut ranges = Vec::new();
+        let mut c = 0u8;
+        while c < 128 {
+            if self.contains(c) {
+                let from = c;
+                while c < 127 && self.contains(c + 1) {
+                    c += 1;
+                }
+                ranges.push((from, c));
+            }
+            c += 1;
+        }
+        ranges
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Ast {
+    /// Matches the empty string
+    Empty,
+    /// Matches one character of the set
+    Chars(CharSet),
+    /// Matches the empty string at the start of the text
+    Start,
+    /// Matches the empty string at the end of the text
+    End,
+    Concat(Vec<Self>),
+    Alternate(Vec<Self>),
+    Repeat {
+        ast: Box<Self>,
+        min: usize,
+        max: Option<usize>,
+    },
+    Group(Box<Self>),
+}
+
+impl Ast {
+    /// Number of states of the automaton built from this AST, see
+    /// [Nfa::compile](super::automaton::Nfa::compile)
+    fn num_states(&self) -> usize {
+        match self {
+            Self::Empty => 1,
+            Self::Chars(_) | Self::Start | Self::End => 2,
+            Self::Group(ast) => ast.num_states(),
+            Self::Concat(items) => items
+                .iter()
+                .fold(0, |acc, item| acc.saturating_add(item.num_states())),
+            Self::Alternate(alternatives) => alternatives
+                .iter()
+                .fold(2, |acc, item| acc.saturating_add(item.num_states())),
+            Self::Repeat { ast, min, max } => {
+                let copy = ast.num_states();
+                let optional_copies = max.map_or(copy.saturating_add(1), |max| {
+                    (max - min).saturating_mul(copy.saturating_add(1))
+                });
+                min.saturating_mul(copy)
+                    .saturating_add(optional_copies)
+                    .saturating_add(1)
+            }
+        }
+    }
+}
+
+pub(crate) fn parse(pattern: &str, case_insensitive: bool) -> crate::Result<Ast> {
+    if !pattern.is_ascii() || pattern.contains('\0') {
+        return Err(crate::error!(
+            "Regular expression must only contain non-null ASCII characters"
+        ));
+    }
+
+    let mut parser = Parser {
+        input: pattern.as_bytes(),
+        pos: 0,
+        case_insensitive,
+    };
+    let ast = parser.regex()?;
+    if let Some(c) = parser.peek() {
+        return Err(parser.error(&format!("unexpected '{}'", c as char)));
+    }
+    if ast.num_states() > MAX_STATES {
+        return Err(crate::error!(
+            "Regular expression is too large once its repetitions are expanded"
+        ));
+    }
+    Ok(ast)
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    case_insensitive: bool,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> crate::Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c as char)))
+        }
+    }
+
+    fn error(&self, msg: &str) -> crate::Error {
+        crate::error!("Invalid regular expression at position {}: {msg}", self.pos)
+    }
+
+    fn chars(&self, set: CharSet) -> Ast {
+        if self.case_insensitive {
+            Ast::Chars(set.case_insensitive())
+        } else {
+            Ast::Chars(set)
+        }
+    }
+
+    fn regex(&mut self) -> crate::Result<Ast> {
+        let mut alternatives = vec![self.concat()?];
+        while self.eat(b'|') {
+            alternatives.push(self.concat()?);
+        }
+
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            Ast::Alternate(alternatives)
+        })
+    }
+
+    fn concat(&mut self) -> crate::Result<Ast> {
+        let mut items = vec![];
+        while let Some(c) = self.peek() {
+            if c == b'|' || c == b')' {
+                break;
+            }
+            items.push(self.repeat()?);
+        }
+
+        Ok(match items.len() {
+            0 => Ast::Empty,
+            1 => items.pop().unwrap(),
+            _ => Ast::Concat(items),
+        })
+    }
+
+    fn repeat(&mut self) -> crate::Result<Ast> {
+        let mut ast = self.atom()?;
+        loop {
+            let (min, max) = if self.eat(b'*') {
+                (0, None)
+            } else if self.eat(b'+') {
+                (1, None)
+            } else if self.eat(b'?') {
+                (0, Some(1))
+            } else if self.eat(b'{') {
+                self.bounds()?
+            } else {
+                break;
+            };
+
+            if matches!(ast, Ast::Start | Ast::End) {
+                return Err(self.error("anchors cannot be repeated"));
+            }
+            if max.is_some_and(|max| max < min) {
+                return Err(self.error("invalid repetition bounds"));
+            }
+            if min > MAX_REPETITION || max.is_some_and(|max| max > MAX_REPETITION) {
+                return Err(self.error(&format!(
+                    "repetition counts must not exceed {MAX_REPETITION}"
+                )));
+            }
+
+            ast = Ast::Repeat {
+                ast: Box::new(ast),
+                min,
+                max,
+            };
+        }
+        Ok(ast)
+    }
+
+    /// Parses the bounds of a `{...}` repetition, the opening brace is already consumed
+    fn bounds(&mut self) -> crate::Result<(usize, Option<usize>)> {
+        let min = self.number();
+        let bounds = if self.eat(b',') {
+            (min.unwrap_or(0), self.number())
+        } else {
+            let Some(count) = min else {
+                return Err(self.error("expected a repetition count"));
+            };
+            (count, Some(count))
+        };
+
+        self.expect(b'}')?;
+        Ok(bounds)
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.pos])
+            .unwrap()
+            .parse()
+            .ok()
+    }
+
+    fn atom(&mut self) -> crate::Result<Ast> {
+        let Some(c) = self.next() else {
+            return Err(self.error("unexpected end of pattern"));
+        };
+
+        match c {
+            b'.' => Ok(Ast::Chars(CharSet::ANY)),
+            b'^' => Ok(Ast::Start),
+            b'$' => Ok(Ast::End),
+            b'(' => {
+                let ast = self.regex()?;
+                self.expect(b')')?;
+                Ok(Ast::Group(Box::new(ast)))
+            }
+            b'[' => Ok(Ast::Chars(self.class()?)),
+            b'\\' => {
+                let set = self.escape()?;
+                Ok(self.chars(set))
+            }
+            b'*' | b'+' | b'?' | b'{' => {
+                self.pos -= 1;
+                Err(self.error("nothing to repeat"))
+            }
+            b')' | b']' | b'}' => {
+                self.pos -= 1;
+                Err(self.error(&format!("unmatched '{}'", c as char)))
+            }
+            c => Ok(self.chars(CharSet::single(c))),
+        }
+    }
+
+    fn escape(&mut self) -> crate::Result<CharSet> {
+        let Some(c) = self.next() else {
+            return Err(self.error("unexpected end of pattern after '\\'"));
+        };
+
+        Ok(match c {
+            b'd' => CharSet::range(b'0', b'9'),
+            b'w' => CharSet::range(b'a', b'z')
+                .union(CharSet::range(b'A', b'Z'))
+                .union(CharSet::range(b'0', b'9'))
+                .union(CharSet::single(b'_')),
+            b's' => CharSet::range(b'\t', b'\r').union(CharSet::single(b' ')),
+            b'n' => CharSet::single(b'\n'),
+            b't' => CharSet::single(b'\t'),
+            b'r' => CharSet::single(b'\r'),
+            c => CharSet::single(c),
+        })
+    }
+
+    /// Parses a character class, the opening bracket is already consumed
+    fn class(&mut self) -> crate::Result<CharSet> {
+        let negated = self.eat(b'^');
+        let mut set = CharSet(0);
+        let mut is_first = true;
+
+        loop {
+            let Some(c) = self.next() else {
+                return Err(self.error("unclosed character class"));
+            };
+
+            // A ']' right after the opening bracket is a literal
+            if c == b']' && !is_first {
+                break;
+            }
+            is_first = false;
+
+            let item = if c == b'\\' {
+                self.escape()?
+            } else if self.peek() == Some(b'-') && self.input.get(self.pos + 1) != Some(&b']') {
+                self.pos += 1;
+                let Some(to) = self.next() else {
+                    return Err(self.error("unclosed character class"));
+                };
+                if to < c {
+                    return Err(self.error("invalid character range"));
+                }
+                CharSet::range(c, to)
+            } else {
+                CharSet::single(c)
+            };
+            set = set.union(item);
+        }
+
+        // Case folding must happen before the negation, so that `[^a]` also excludes 'A'
+        if self.case_insensitive {
+            set = set.case_insensitive();
+        }
+        Ok(if negated { set.complement() } else { set })
+    }
+}
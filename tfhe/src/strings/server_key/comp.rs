@@ -1,7 +1,8 @@
-use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
-use crate::strings::ciphertext::{FheString, GenericPattern, GenericPatternRef};
+use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey, SignedRadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPattern, GenericPatternRef};
 use crate::strings::server_key::{FheStringIsEmpty, ServerKey};
 use crate::ClearString;
+use rayon::prelude::*;
 use std::borrow::Borrow;
 
 impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
@@ -338,4 +339,291 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         self.eq(&lhs, rhs.as_ref())
     }
+
+    /// Compares an encrypted string and a pattern (either encrypted or clear) lexicographically
+    ///
+    /// Returns an 8 bits signed ciphertext that encrypts -1, 0 or 1 if `lhs` is respectively less
+    /// than, equal to or greater than `rhs`, like `std::cmp::Ordering as i8`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("apple", "banana");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, s2, None));
+    ///
+    /// let result = sk.cmp(&enc_s1, enc_s2.as_ref());
+    /// let ordering = ck.inner().decrypt_signed_radix::<i8>(&result);
+    ///
+    /// assert_eq!(ordering, std::cmp::Ordering::Less as i8);
+    /// ```
+    pub fn cmp(&self, lhs: &FheString, rhs: GenericPatternRef<'_>) -> SignedRadixCiphertext {
+        let sk = self.inner();
+
+        let mut lhs_uint = lhs.to_uint();
+        let mut rhs_uint = match rhs {
+            GenericPatternRef::Clear(rhs) => FheString::trivial(self, rhs.str()).to_uint(),
+            GenericPatternRef::Enc(rhs) => rhs.to_uint(),
+        };
+
+        self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
+
+        let (lt, eq) = rayon::join(
+            || sk.lt_parallelized(&lhs_uint, &rhs_uint),
+            || sk.eq_parallelized(&lhs_uint, &rhs_uint),
+        );
+
+        self.ordering(&lt, &eq)
+    }
+
+    /// Compares an encrypted string and a pattern (either encrypted or clear) lexicographically,
+    /// ignoring case differences
+    ///
+    /// The result is encoded as in [Self::cmp].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("apple", "Banana");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, s2, None));
+    ///
+    /// let result = sk.cmp_ignore_case(&enc_s1, enc_s2.as_ref());
+    /// let ordering = ck.inner().decrypt_signed_radix::<i8>(&result);
+    ///
+    /// assert_eq!(ordering, std::cmp::Ordering::Less as i8);
+    /// ```
+    pub fn cmp_ignore_case(
+        &self,
+        lhs: &FheString,
+        rhs: GenericPatternRef<'_>,
+    ) -> SignedRadixCiphertext {
+        let (lhs, rhs) = rayon::join(
+            || self.to_lowercase(lhs),
+            || match rhs {
+                GenericPatternRef::Clear(rhs) => {
+                    GenericPattern::Clear(ClearString::new(rhs.str().to_lowercase()))
+                }
+                GenericPatternRef::Enc(rhs) => GenericPattern::Enc(self.to_lowercase(rhs)),
+            },
+        );
+
+        self.cmp(&lhs, rhs.as_ref())
+    }
+
+    /// Compares an encrypted string and a pattern (either encrypted or clear) in natural order,
+    /// where runs of ASCII digits are compared as numbers (e.g. "file2" < "file10")
+    ///
+    /// Digit runs are compared by length and then digit by digit, which is their numeric order
+    /// as long as they have no leading zeros. The rest of the strings is compared
+    /// lexicographically, and the comparison is case sensitive.
+    ///
+    /// The result is encoded as in [Self::cmp].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("file2.txt", "file10.txt");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, s2, None));
+    ///
+    /// let result = sk.natural_cmp(&enc_s1, enc_s2.as_ref());
+    /// let ordering = ck.inner().decrypt_signed_radix::<i8>(&result);
+    ///
+    /// assert_eq!(ordering, std::cmp::Ordering::Less as i8);
+    /// ```
+    pub fn natural_cmp(
+        &self,
+        lhs: &FheString,
+        rhs: GenericPatternRef<'_>,
+    ) -> SignedRadixCiphertext {
+        let sk = self.inner();
+
+        let rhs = match rhs {
+            GenericPatternRef::Clear(rhs) => FheString::trivial(self, rhs.str()),
+            GenericPatternRef::Enc(rhs) => rhs.clone(),
+        };
+
+        // Missing characters are nulls, and the trailing null ends the last digit runs
+        let len = lhs.len().max(rhs.len()) + 1;
+        let null = FheAsciiChar::null(self);
+        let lhs_chars: Vec<_> = (0..len)
+            .map(|i| lhs.chars().get(i).unwrap_or(&null))
+            .collect();
+        let rhs_chars: Vec<_> = (0..len)
+            .map(|i| rhs.chars().get(i).unwrap_or(&null))
+            .collect();
+
+        let is_digit = |char: &FheAsciiChar| {
+            let (ge_zero, le_nine) = rayon::join(
+                || sk.scalar_ge_parallelized(char.ciphertext(), b'0'),
+                || sk.scalar_le_parallelized(char.ciphertext(), b'9'),
+            );
+            sk.boolean_bitand(&ge_zero, &le_nine)
+        };
+
+        let positions: Vec<_> = lhs_chars
+            .par_iter()
+            .zip(rhs_chars.par_iter())
+            .map(|(l, r)| {
+                let ((l_digit, r_digit), (eq, lt)) = rayon::join(
+                    || rayon::join(|| is_digit(l), || is_digit(r)),
+                    || {
+                        rayon::join(
+                            || sk.eq_parallelized(l.ciphertext(), r.ciphertext()),
+                            || sk.lt_parallelized(l.ciphertext(), r.ciphertext()),
+                        )
+                    },
+                );
+                let (both_digits, (only_l_digit, only_r_digit)) = rayon::join(
+                    || sk.boolean_bitand(&l_digit, &r_digit),
+                    || {
+                        rayon::join(
+                            || sk.boolean_bitand(&l_digit, &sk.boolean_bitnot(&r_digit)),
+                            || sk.boolean_bitand(&r_digit, &sk.boolean_bitnot(&l_digit)),
+                        )
+                    },
+                );
+                (l_digit, both_digits, only_l_digit, only_r_digit, eq, lt)
+            })
+            .collect();
+
+        // For each position, whether the digit run starting there is longer in lhs or in rhs.
+        // The run of lhs is longer at i if only lhs has a digit at i, or if both have one and the
+        // run of lhs is longer at i + 1, so the flags are a carry chain scanned from the end.
+        let run_flags: Vec<_> = positions
+            .iter()
+            .rev()
+            .map(|(_, both_digits, only_l_digit, only_r_digit, _, _)| {
+                (
+                    only_l_digit.clone(),
+                    only_r_digit.clone(),
+                    both_digits.clone(),
+                )
+            })
+            .collect();
+        let run_flags =
+            hillis_steele_scan(run_flags, |(prev_l, prev_r, prev_both), (l, r, both)| {
+                let ((l, r), both) = rayon::join(
+                    || {
+                        rayon::join(
+                            || sk.boolean_bitor(l, &sk.boolean_bitand(both, prev_l)),
+                            || sk.boolean_bitor(r, &sk.boolean_bitand(both, prev_r)),
+                        )
+                    },
+                    || sk.boolean_bitand(both, prev_both),
+                );
+                (l, r, both)
+            });
+        let (l_run_is_longer, r_run_is_longer): (Vec<_>, Vec<_>) =
+            run_flags.into_iter().rev().map(|(l, r, _)| (l, r)).unzip();
+
+        // For each position, whether all the previous characters are equal
+        let eqs = positions
+            .iter()
+            .map(|(_, _, _, _, eq, _)| eq.clone())
+            .collect();
+        let mut prefix_eq = vec![sk.create_trivial_boolean_block(true)];
+        prefix_eq.extend(hillis_steele_scan(eqs, |prev, eq| {
+            sk.boolean_bitand(prev, eq)
+        }));
+
+        // Only the first differing position decides the order. As the previous characters are
+        // equal, a digit before it means both strings are in the middle of a number.
+        let lt_blocks: Vec<_> = positions
+            .par_iter()
+            .enumerate()
+            .map(|(i, (_, both_digits, _, _, eq, lt))| {
+                let in_number = if i == 0 {
+                    both_digits.clone()
+                } else {
+                    sk.boolean_bitor(both_digits, &positions[i - 1].0)
+                };
+
+                let ((num_lt, num_gt), is_first_diff) = rayon::join(
+                    || {
+                        rayon::join(
+                            || sk.boolean_bitand(&in_number, &r_run_is_longer[i]),
+                            || sk.boolean_bitand(&in_number, &l_run_is_longer[i]),
+                        )
+                    },
+                    || sk.boolean_bitand(&prefix_eq[i], &sk.boolean_bitnot(eq)),
+                );
+
+                let lex_lt = sk.boolean_bitand(lt, &sk.boolean_bitnot(&num_gt));
+                let lt_here = sk.boolean_bitor(&num_lt, &lex_lt);
+                sk.boolean_bitand(&is_first_diff, &lt_here).0
+            })
+            .collect();
+
+        let lt = BooleanBlock::new_unchecked(sk.is_at_least_one_comparisons_block_true(lt_blocks));
+
+        self.ordering(&lt, &prefix_eq[len])
+    }
+
+    /// Encodes the result of a comparison as an 8 bits signed ciphertext, like
+    /// `std::cmp::Ordering as i8`
+    fn ordering(&self, lt: &BooleanBlock, eq: &BooleanBlock) -> SignedRadixCiphertext {
+        let sk = self.inner();
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(u8::MAX);
+
+        let gt = sk.boolean_bitnot(&sk.boolean_bitor(lt, eq));
+        let gt: SignedRadixCiphertext = gt.into_radix(num_blocks, sk);
+        let lt: SignedRadixCiphertext = lt.clone().into_radix(num_blocks, sk);
+
+        sk.sub_parallelized(&gt, &lt)
+    }
+}
+
+/// Computes all the prefixes `values[0] . values[1] . ... . values[i]` of an associative operation
+/// in a logarithmic number of parallel steps
+///
+/// `combine` is given the aggregate of the earlier elements first.
+fn hillis_steele_scan<E, F>(mut values: Vec<E>, combine: F) -> Vec<E>
+where
+    E: Clone + Send + Sync,
+    F: Fn(&E, &E) -> E + Sync,
+{
+    let mut space = 1;
+    while space < values.len() {
+        let step_output: Vec<_> = values[space..]
+            .par_iter()
+            .enumerate()
+            .map(|(i, value)| combine(&values[i], value))
+            .collect();
+        for (value, output) in values[space..].iter_mut().zip(step_output) {
+            *value = output;
+        }
+
+        space *= 2;
+    }
+
+    values
 }
@@ -0,0 +1,266 @@
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheString, GenericPatternRef};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Returns the Levenshtein distance between an encrypted string and a pattern (either
+    /// encrypted or clear), that is the minimum number of single character insertions, deletions
+    /// and substitutions required to change one into the other
+    ///
+    /// The result is a 16 bits ciphertext. Padding is not taken into account, so the result only
+    /// depends on the actual content of the strings.
+    ///
+    /// The dynamic programming table is computed one anti-diagonal at a time, as the cells of an
+    /// anti-diagonal only depend on the two previous ones and can be computed in parallel. This
+    /// requires a number of comparisons proportional to the product of the lengths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s1 = FheString::new(&ck, "kitten", Some(1));
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, "sitting", None));
+    ///
+    /// let result = sk.edit_distance(&enc_s1, enc_s2.as_ref());
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u16>(&result), 3);
+    /// ```
+    pub fn edit_distance(&self, lhs: &FheString, rhs: GenericPatternRef<'_>) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let trivial_or_enc_rhs = match rhs {
+            GenericPatternRef::Clear(rhs) => FheString::trivial(self, rhs.str()),
+            GenericPatternRef::Enc(rhs) => rhs.clone(),
+        };
+        let (lhs_chars, rhs_chars) = (lhs.chars(), trivial_or_enc_rhs.chars());
+        let (n, m) = (lhs_chars.len(), rhs_chars.len());
+
+        // The distance is at most the length of the longest string, and the deletion and insertion
+        // candidates of a cell can exceed it by one, so every cell of the table must hold this
+        // value without wrapping around
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(n.max(m) as u64 + 1);
+
+        let ((deletion_costs, insertion_costs), substitution_costs) = rayon::join(
+            || {
+                rayon::join(
+                    || self.char_costs(lhs, num_blocks),
+                    || self.char_costs(&trivial_or_enc_rhs, num_blocks),
+                )
+            },
+            || {
+                lhs_chars
+                    .par_iter()
+                    .map(|lhs_char| {
+                        rhs_chars
+                            .par_iter()
+                            .map(|rhs_char| {
+                                sk.ne_parallelized(lhs_char.ciphertext(), rhs_char.ciphertext())
+                                    .into_radix::<RadixCiphertext>(num_blocks, sk)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let add_cost = |distance: &RadixCiphertext, cost: &Option<RadixCiphertext>| {
+            cost.as_ref().map_or_else(
+                || sk.scalar_add_parallelized(distance, 1u8),
+                |cost| sk.add_parallelized(distance, cost),
+            )
+        };
+
+        // distances[i][j] is the distance between the first i characters of lhs and the first j
+        // characters of rhs
+        let mut distances = vec![vec![sk.create_trivial_zero_radix(num_blocks); m + 1]; n + 1];
+        for i in 1..=n {
+            distances[i][0] = add_cost(&distances[i - 1][0], &deletion_costs[i - 1]);
+        }
+        for j in 1..=m {
+            distances[0][j] = add_cost(&distances[0][j - 1], &insertion_costs[j - 1]);
+        }
+
+        for diagonal in 2..=n + m {
+            let cells: Vec<_> = (diagonal.saturating_sub(m).max(1)..=n.min(diagonal - 1))
+                .map(|i| (i, diagonal - i))
+                .collect();
+
+            let values: Vec<_> = cells
+                .par_iter()
+                .map(|&(i, j)| {
+                    let ((deletion, insertion), substitution) = rayon::join(
+                        || {
+                            rayon::join(
+                                || add_cost(&distances[i - 1][j], &deletion_costs[i - 1]),
+                                || add_cost(&distances[i][j - 1], &insertion_costs[j - 1]),
+                            )
+                        },
+                        || {
+                            sk.add_parallelized(
+                                &distances[i - 1][j - 1],
+                                &substitution_costs[i - 1][j - 1],
+                            )
+                        },
+                    );
+
+                    let min = sk.min_parallelized(&deletion, &insertion);
+                    sk.min_parallelized(&min, &substitution)
+                })
+                .collect();
+
+            for ((i, j), value) in cells.into_iter().zip(values) {
+                distances[i][j] = value;
+            }
+        }
+
+        let distance = distances.swap_remove(n).swap_remove(m);
+        sk.cast_to_unsigned(
+            distance,
+            sk.num_blocks_to_represent_unsigned_value(u16::MAX),
+        )
+    }
+
+    /// Returns the Hamming distance between an encrypted string and a pattern (either encrypted
+    /// or clear), that is the number of positions at which their characters differ
+    ///
+    /// If the strings have different lengths, each character of the longest one that has no
+    /// counterpart in the other one counts as a difference. Padding is not taken into account.
+    ///
+    /// The result is a 16 bits ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "karolin", Some(2));
+    /// let clear_s = GenericPattern::Clear(ClearString::new("kathrin".to_string()));
+    ///
+    /// let result = sk.hamming_distance(&enc_s, clear_s.as_ref());
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u16>(&result), 3);
+    /// ```
+    pub fn hamming_distance(&self, lhs: &FheString, rhs: GenericPatternRef<'_>) -> RadixCiphertext {
+        let sk = self.inner();
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(u16::MAX);
+
+        let trivial_or_enc_rhs = match rhs {
+            GenericPatternRef::Clear(rhs) => FheString::trivial(self, rhs.str()),
+            GenericPatternRef::Enc(rhs) => rhs.clone(),
+        };
+        let (lhs_chars, rhs_chars) = (lhs.chars(), trivial_or_enc_rhs.chars());
+
+        // A `None` is a difference known in the clear
+        let differences: Vec<Option<BooleanBlock>> = (0..lhs_chars.len().max(rhs_chars.len()))
+            .into_par_iter()
+            .map(|i| match (lhs_chars.get(i), rhs_chars.get(i)) {
+                (Some(lhs_char), Some(rhs_char)) => {
+                    Some(sk.ne_parallelized(lhs_char.ciphertext(), rhs_char.ciphertext()))
+                }
+                (Some(char), None) if lhs.is_padded() => {
+                    Some(sk.scalar_ne_parallelized(char.ciphertext(), 0u8))
+                }
+                (None, Some(char)) if trivial_or_enc_rhs.is_padded() => {
+                    Some(sk.scalar_ne_parallelized(char.ciphertext(), 0u8))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let clear_count = differences.iter().filter(|diff| diff.is_none()).count();
+        let enc_differences: Vec<RadixCiphertext> = differences
+            .into_par_iter()
+            .flatten()
+            .map(|diff| diff.into_radix(num_blocks, sk))
+            .collect();
+
+        let count = sk
+            .sum_ciphertexts_parallelized(enc_differences.iter())
+            .unwrap_or_else(|| sk.create_trivial_zero_radix(num_blocks));
+        sk.scalar_add_parallelized(&count, clear_count as u64)
+    }
+
+    /// Returns `true` if the Levenshtein distance between an encrypted string and a pattern
+    /// (either encrypted or clear) is lower or equal to `max_distance`
+    ///
+    /// See [Self::edit_distance].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s1 = FheString::new(&ck, "jonathan", None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, "jonatan", None));
+    ///
+    /// let result = sk.fuzzy_eq(&enc_s1, enc_s2.as_ref(), 1);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result));
+    /// ```
+    pub fn fuzzy_eq(
+        &self,
+        lhs: &FheString,
+        rhs: GenericPatternRef<'_>,
+        max_distance: u16,
+    ) -> BooleanBlock {
+        let sk = self.inner();
+
+        // The distance is at least the difference between the lengths
+        let rhs_len_is_known = match rhs {
+            GenericPatternRef::Clear(rhs) => Some(rhs.str().len()),
+            GenericPatternRef::Enc(rhs) => (!rhs.is_padded()).then_some(rhs.len()),
+        };
+        if let (false, Some(rhs_len)) = (lhs.is_padded(), rhs_len_is_known) {
+            if lhs.len().abs_diff(rhs_len) > max_distance as usize {
+                return sk.create_trivial_boolean_block(false);
+            }
+        }
+
+        let distance = self.edit_distance(lhs, rhs);
+        sk.scalar_le_parallelized(&distance, max_distance)
+    }
+
+    /// Returns the cost of deleting each character of the string: 1 for an actual character and
+    /// 0 for padding
+    ///
+    /// A `None` is a cost of 1 known in the clear.
+    fn char_costs(&self, str: &FheString, num_blocks: usize) -> Vec<Option<RadixCiphertext>> {
+        let sk = self.inner();
+
+        if !str.is_padded() {
+            return vec![None; str.len()];
+        }
+
+        str.chars()
+            .par_iter()
+            .map(|char| {
+                let is_not_null = sk.scalar_ne_parallelized(char.ciphertext(), 0u8);
+                Some(is_not_null.into_radix(num_blocks, sk))
+            })
+            .collect()
+    }
+}
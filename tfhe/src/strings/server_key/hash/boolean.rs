@@ -0,0 +1,364 @@
+use super::{Sha256Compression, K};
+use crate::integer::{RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+const WORD_BITS: usize = 32;
+
+// Carry states of a range of bits used by the adder, a range that kills an incoming carry has the
+// state 0
+const PROPAGATE: u64 = 1;
+const GENERATE: u64 = 2;
+
+/// Evaluates the compression function on words made of 32 blocks encrypting one bit each (least
+/// significant bit first)
+///
+/// Rotations and shifts only move blocks. Bitwise functions of up to 3 bits are evaluated with a
+/// single PBS on the sum of the bits, and additions use carry save adders followed by a
+/// Kogge-Stone adder.
+pub(super) struct BooleanSha256<'a> {
+    sk: &'a IntegerServerKey,
+    bits_per_block: usize,
+    extract_bit_luts: Vec<LookupTableOwned>,
+    /// Cleans a radix block while its bits are packed together
+    pack_lut: LookupTableOwned,
+    /// Maps the sum of bits to the parity
+    parity_lut: LookupTableOwned,
+    /// Maps the sum of 3 bits to the majority
+    majority_lut: LookupTableOwned,
+    /// Maps `2 * e + f` to `e & f`
+    and_lut: LookupTableOwned,
+    /// Maps `2 * e + g` to `!e & g`
+    and_not_lut: LookupTableOwned,
+    /// Maps the sum of 2 bits to the carry state of their position
+    carry_state_lut: LookupTableOwned,
+    /// Maps `3 * high + low` to the carry state of the concatenation of the ranges
+    combine_lut: LookupTableOwned,
+    /// Maps `3 * state + previous_state` to the sum bit
+    sum_bit_lut: LookupTableOwned,
+}
+
+impl<'a> BooleanSha256<'a> {
+    pub(super) fn new(sk: &'a IntegerServerKey) -> Self {
+        let key = &sk.key;
+        let message_modulus = key.message_modulus.0;
+
+        assert!(
+            message_modulus >= 4 && key.carry_modulus.0 >= 4,
+            "The boolean hash backend requires at least 2 bits of message and 2 bits of carry"
+        );
+
+        let bits_per_block = message_modulus.ilog2() as usize;
+
+        Self {
+            sk,
+            bits_per_block,
+            extract_bit_luts: (0..bits_per_block)
+                .map(|k| key.generate_lookup_table(|x| (x >> k) & 1))
+                .collect(),
+            pack_lut: key.generate_lookup_table(|x| x % message_modulus),
+            parity_lut: key.generate_lookup_table(|x| x & 1),
+            majority_lut: key.generate_lookup_table(|x| u64::from(x >= 2)),
+            and_lut: key.generate_lookup_table(|x| u64::from(x == 3)),
+            and_not_lut: key.generate_lookup_table(|x| u64::from(x == 1)),
+            carry_state_lut: key.generate_lookup_table(|x| x.min(GENERATE)),
+            combine_lut: key.generate_lookup_table(|x| {
+                let (high, low) = (x / 3, x % 3);
+                if high == PROPAGATE {
+                    low
+                } else {
+                    high
+                }
+            }),
+            sum_bit_lut: key.generate_lookup_table(|x| {
+                let (state, previous) = (x / 3, x % 3);
+                (state + u64::from(previous == GENERATE)) & 1
+            }),
+        }
+    }
+
+    fn zero(&self) -> Ciphertext {
+        self.sk.key.create_trivial(0)
+    }
+
+    /// Applies the lookup table to `scale * lhs + rhs`
+    fn bivariate(
+        &self,
+        lhs: &Ciphertext,
+        rhs: &Ciphertext,
+        scale: u8,
+        lut: &LookupTableOwned,
+    ) -> Ciphertext {
+        let key = &self.sk.key;
+
+        let mut packed = key.unchecked_scalar_mul(lhs, scale);
+        key.unchecked_add_assign(&mut packed, rhs);
+        key.apply_lookup_table(&packed, lut)
+    }
+
+    /// Applies the lookup table to the sum of the bits
+    fn sum_lut(&self, bits: &[&Ciphertext], lut: &LookupTableOwned) -> Ciphertext {
+        let key = &self.sk.key;
+
+        let mut sum = bits[0].clone();
+        for bit in &bits[1..] {
+            key.unchecked_add_assign(&mut sum, bit);
+        }
+        key.apply_lookup_table(&sum, lut)
+    }
+
+    fn rotate_right(word: &[Ciphertext], amount: usize) -> Vec<&Ciphertext> {
+        (0..WORD_BITS)
+            .map(|i| &word[(i + amount) % WORD_BITS])
+            .collect()
+    }
+
+    fn shift_right<'b>(
+        word: &'b [Ciphertext],
+        amount: usize,
+        zero: &'b Ciphertext,
+    ) -> Vec<&'b Ciphertext> {
+        (0..WORD_BITS)
+            .map(|i| word.get(i + amount).unwrap_or(zero))
+            .collect()
+    }
+
+    /// Xors 3 words bit by bit
+    fn xor3(&self, words: &[Vec<&Ciphertext>; 3]) -> Vec<Ciphertext> {
+        (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| self.sum_lut(&[words[0][i], words[1][i], words[2][i]], &self.parity_lut))
+            .collect()
+    }
+
+    /// Reduces 3 words to 2 words with the same sum (modulo 2^32)
+    fn carry_save_add(
+        &self,
+        a: &[Ciphertext],
+        b: &[Ciphertext],
+        c: &[Ciphertext],
+    ) -> (Vec<Ciphertext>, Vec<Ciphertext>) {
+        let (sums, carries): (Vec<_>, Vec<_>) = (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| {
+                let bits = [&a[i], &b[i], &c[i]];
+                rayon::join(
+                    || self.sum_lut(&bits, &self.parity_lut),
+                    || self.sum_lut(&bits, &self.majority_lut),
+                )
+            })
+            .unzip();
+
+        let mut shifted_carries = Vec::with_capacity(WORD_BITS);
+        shifted_carries.push(self.zero());
+        shifted_carries.extend(carries.into_iter().take(WORD_BITS - 1));
+
+        (sums, shifted_carries)
+    }
+
+    /// Adds two words (modulo 2^32) with a Kogge-Stone adder
+    fn add2(&self, a: &[Ciphertext], b: &[Ciphertext]) -> Vec<Ciphertext> {
+        let states: Vec<_> = (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| self.sum_lut(&[&a[i], &b[i]], &self.carry_state_lut))
+            .collect();
+
+        // After the step with distance d, prefix_states[i] is the carry state of the bits in
+        // [i + 1 - 2d, i]
+        let mut prefix_states = states.clone();
+        let mut distance = 1;
+        while distance < WORD_BITS {
+            prefix_states = (0..WORD_BITS)
+                .into_par_iter()
+                .map(|i| {
+                    if i < distance {
+                        prefix_states[i].clone()
+                    } else {
+                        self.bivariate(
+                            &prefix_states[i],
+                            &prefix_states[i - distance],
+                            3,
+                            &self.combine_lut,
+                        )
+                    }
+                })
+                .collect();
+            distance *= 2;
+        }
+
+        let zero = self.zero();
+        states
+            .par_iter()
+            .enumerate()
+            .map(|(i, state)| {
+                let previous = if i == 0 { &zero } else { &prefix_states[i - 1] };
+                self.bivariate(state, previous, 3, &self.sum_bit_lut)
+            })
+            .collect()
+    }
+
+    /// Adds words (modulo 2^32)
+    fn add(&self, words: Vec<Vec<Ciphertext>>) -> Vec<Ciphertext> {
+        let mut words = words;
+        while words.len() > 2 {
+            let c = words.pop().unwrap();
+            let b = words.pop().unwrap();
+            let a = words.pop().unwrap();
+            let (sums, carries) = self.carry_save_add(&a, &b, &c);
+            words.push(sums);
+            words.push(carries);
+        }
+
+        let b = words.pop().unwrap();
+        let a = words.pop().unwrap();
+        self.add2(&a, &b)
+    }
+
+    fn ch(&self, e: &[Ciphertext], f: &[Ciphertext], g: &[Ciphertext]) -> Vec<Ciphertext> {
+        let key = &self.sk.key;
+
+        // Exactly one of the two terms may be set, so their sum is a bit. It is not cleaned, as
+        // it is only used once as an input of a 3 bits addition
+        (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| {
+                let (e_and_f, not_e_and_g) = rayon::join(
+                    || self.bivariate(&e[i], &f[i], 2, &self.and_lut),
+                    || self.bivariate(&e[i], &g[i], 2, &self.and_not_lut),
+                );
+                key.unchecked_add(&e_and_f, &not_e_and_g)
+            })
+            .collect()
+    }
+
+    fn maj(&self, a: &[Ciphertext], b: &[Ciphertext], c: &[Ciphertext]) -> Vec<Ciphertext> {
+        (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| self.sum_lut(&[&a[i], &b[i], &c[i]], &self.majority_lut))
+            .collect()
+    }
+}
+
+impl Sha256Compression for BooleanSha256<'_> {
+    type Word = Vec<Ciphertext>;
+
+    fn word_from_radix(&self, word: &RadixCiphertext) -> Vec<Ciphertext> {
+        assert_eq!(word.blocks.len() * self.bits_per_block, WORD_BITS);
+
+        (0..WORD_BITS)
+            .into_par_iter()
+            .map(|i| {
+                let block = &word.blocks[i / self.bits_per_block];
+                let lut = &self.extract_bit_luts[i % self.bits_per_block];
+                self.sk.key.apply_lookup_table(block, lut)
+            })
+            .collect()
+    }
+
+    fn word_to_radix(&self, word: &Vec<Ciphertext>) -> RadixCiphertext {
+        let blocks = word
+            .par_chunks_exact(self.bits_per_block)
+            .map(|bits| {
+                // Horner scheme from the most significant bit, cleaning after each step to keep
+                // the noise level low
+                let (last, rest) = bits.split_last().unwrap();
+                rest.iter().rev().fold(last.clone(), |acc, bit| {
+                    self.bivariate(&acc, bit, 2, &self.pack_lut)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    fn trivial(&self, value: u32) -> Vec<Ciphertext> {
+        (0..WORD_BITS)
+            .map(|i| self.sk.key.create_trivial(u64::from((value >> i) & 1)))
+            .collect()
+    }
+
+    fn compress(
+        &self,
+        state: &[Vec<Ciphertext>; 8],
+        block: &[Vec<Ciphertext>],
+    ) -> [Vec<Ciphertext>; 8] {
+        let zero = self.zero();
+
+        let mut w = block.to_vec();
+        for i in 16..64 {
+            let (s0, s1) = rayon::join(
+                || {
+                    self.xor3(&[
+                        Self::rotate_right(&w[i - 15], 7),
+                        Self::rotate_right(&w[i - 15], 18),
+                        Self::shift_right(&w[i - 15], 3, &zero),
+                    ])
+                },
+                || {
+                    self.xor3(&[
+                        Self::rotate_right(&w[i - 2], 17),
+                        Self::rotate_right(&w[i - 2], 19),
+                        Self::shift_right(&w[i - 2], 10, &zero),
+                    ])
+                },
+            );
+            let word = self.add(vec![w[i - 16].clone(), s0, w[i - 7].clone(), s1]);
+            w.push(word);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+
+        for i in 0..64 {
+            let ((s1, ch), (s0, maj)) = rayon::join(
+                || {
+                    rayon::join(
+                        || {
+                            self.xor3(&[
+                                Self::rotate_right(&e, 6),
+                                Self::rotate_right(&e, 11),
+                                Self::rotate_right(&e, 25),
+                            ])
+                        },
+                        || self.ch(&e, &f, &g),
+                    )
+                },
+                || {
+                    rayon::join(
+                        || {
+                            self.xor3(&[
+                                Self::rotate_right(&a, 2),
+                                Self::rotate_right(&a, 13),
+                                Self::rotate_right(&a, 22),
+                            ])
+                        },
+                        || self.maj(&a, &b, &c),
+                    )
+                },
+            );
+
+            // `ch` is the last word, so that it is reduced first along with clean words
+            let t1 = self.add(vec![self.trivial(K[i]), w[i].clone(), h, s1, ch]);
+            let (d_plus_t1, t1_plus_t2) = rayon::join(
+                || self.add2(&d, &t1),
+                || self.add(vec![t1.clone(), s0, maj]),
+            );
+
+            h = g;
+            g = f;
+            f = e;
+            e = d_plus_t1;
+            d = c;
+            c = b;
+            b = a;
+            a = t1_plus_t2;
+        }
+
+        let mut new_state = [a, b, c, d, e, f, g, h];
+        new_state
+            .par_iter_mut()
+            .zip(state.par_iter())
+            .for_each(|(word, previous)| *word = self.add2(word, previous));
+        new_state
+    }
+}
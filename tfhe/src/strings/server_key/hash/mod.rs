@@ -0,0 +1,389 @@
+mod boolean;
+mod radix;
+
+pub use crate::high_level_api::HashBackend;
+
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::FheString;
+use crate::strings::server_key::{FheStringLen, ServerKey};
+use boolean::BooleanSha256;
+use radix::RadixSha256;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INIT: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const BLOCK_BYTES: usize = 64;
+const HMAC_IPAD: u8 = 0x36;
+const HMAC_OPAD: u8 = 0x5c;
+
+/// The data to hash, either an encrypted ASCII string or encrypted bytes
+///
+/// The bytes are radix ciphertexts encrypting a `u8` each. For a padded string, the padding is not
+/// part of the hashed message, and its length stays hidden.
+#[derive(Clone, Copy)]
+pub enum HashData<'a> {
+    String(&'a FheString),
+    Bytes(&'a [RadixCiphertext]),
+}
+
+impl<'a> From<&'a FheString> for HashData<'a> {
+    fn from(value: &'a FheString) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<'a> From<&'a [RadixCiphertext]> for HashData<'a> {
+    fn from(value: &'a [RadixCiphertext]) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+/// Number of bytes of a message, the bytes after it are zeros
+enum MessageLen {
+    Clear(usize),
+    Enc(RadixCiphertext),
+}
+
+/// A way of evaluating the SHA-256 compression function
+trait Sha256Compression: Sync {
+    type Word: Clone + Send + Sync;
+
+    fn word_from_radix(&self, word: &RadixCiphertext) -> Self::Word;
+
+    fn word_to_radix(&self, word: &Self::Word) -> RadixCiphertext;
+
+    fn trivial(&self, value: u32) -> Self::Word;
+
+    /// Processes a block of 16 words and returns the new hash state
+    fn compress(&self, state: &[Self::Word; 8], block: &[Self::Word]) -> [Self::Word; 8];
+}
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Computes the SHA-256 digest of encrypted data
+    ///
+    /// Returns the 8 words of the digest as 32 bits ciphertexts, the first one holding the first
+    /// 4 bytes of the digest (in big-endian order).
+    ///
+    /// If the data is a padded string, its encrypted length determines where the message ends,
+    /// which requires an additional compression for each block the message could end in.
+    ///
+    /// The compression function is evaluated with the [HashBackend] of the key, see
+    /// [Self::with_hash_backend].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::server_key::HashData;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "abc", None);
+    ///
+    /// let digest = sk.sha256(HashData::String(&enc_s));
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&digest[0]), 0xba7816bf);
+    /// ```
+    pub fn sha256(&self, data: HashData<'_>) -> [RadixCiphertext; 8] {
+        let (bytes, len) = self.message_bytes(data);
+        self.sha256_impl(bytes, &len)
+    }
+
+    /// Computes the HMAC-SHA-256 of encrypted data with an encrypted key
+    ///
+    /// As for [Self::sha256], padded strings are supported both as key and as data, their
+    /// padding is not part of the key or the message. Keys longer than 64 bytes are hashed first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::server_key::HashData;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let key = FheString::new(&ck, "Jefe", None);
+    /// let message = FheString::new(&ck, "what do ya want for nothing?", None);
+    ///
+    /// let mac = sk.hmac_sha256(HashData::String(&key), HashData::String(&message));
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&mac[0]), 0x5bdcc146);
+    /// ```
+    pub fn hmac_sha256(&self, key: HashData<'_>, data: HashData<'_>) -> [RadixCiphertext; 8] {
+        let sk = self.inner();
+
+        let (key, (message, message_len)) =
+            rayon::join(|| self.hmac_key(key), || self.message_bytes(data));
+
+        let (inner_key, outer_key): (Vec<_>, Vec<_>) = key
+            .par_iter()
+            .map(|byte| {
+                rayon::join(
+                    || sk.scalar_bitxor_parallelized(byte, HMAC_IPAD),
+                    || sk.scalar_bitxor_parallelized(byte, HMAC_OPAD),
+                )
+            })
+            .unzip();
+
+        let inner_len = match message_len {
+            MessageLen::Clear(len) => MessageLen::Clear(BLOCK_BYTES + len),
+            MessageLen::Enc(len) => {
+                MessageLen::Enc(sk.scalar_add_parallelized(&len, BLOCK_BYTES as u64))
+            }
+        };
+        let inner_digest = self.sha256_impl([inner_key, message].concat(), &inner_len);
+
+        let outer_message = [outer_key, self.digest_bytes(&inner_digest)].concat();
+        let outer_len = MessageLen::Clear(outer_message.len());
+        self.sha256_impl(outer_message, &outer_len)
+    }
+
+    /// Returns the bytes of the data, followed by zeros if the message is shorter
+    fn message_bytes(&self, data: HashData<'_>) -> (Vec<RadixCiphertext>, MessageLen) {
+        let sk = self.inner();
+        let num_blocks = self.num_ascii_blocks();
+
+        match data {
+            HashData::String(str) => {
+                let bytes = str.chars().iter().map(|c| c.ciphertext().clone()).collect();
+                let len = match self.len(str) {
+                    FheStringLen::NoPadding(len) => MessageLen::Clear(len),
+                    FheStringLen::Padding(len) => MessageLen::Enc(len),
+                };
+                (bytes, len)
+            }
+            HashData::Bytes(bytes) => {
+                let len = bytes.len();
+                let bytes = bytes
+                    .par_iter()
+                    .map(|byte| {
+                        assert_eq!(
+                            byte.blocks.len(),
+                            num_blocks,
+                            "Hashed bytes must be encrypted on {num_blocks} blocks"
+                        );
+                        let mut byte = byte.clone();
+                        if !byte.block_carries_are_empty() {
+                            sk.full_propagate_parallelized(&mut byte);
+                        }
+                        byte
+                    })
+                    .collect();
+                (bytes, MessageLen::Clear(len))
+            }
+        }
+    }
+
+    /// Returns the 64 bytes of the HMAC block key
+    fn hmac_key(&self, key: HashData<'_>) -> Vec<RadixCiphertext> {
+        let sk = self.inner();
+        let zero = sk.create_trivial_zero_radix(self.num_ascii_blocks());
+
+        let (mut bytes, len) = self.message_bytes(key);
+        if bytes.len() <= BLOCK_BYTES {
+            bytes.resize(BLOCK_BYTES, zero);
+            return bytes;
+        }
+
+        let mut hashed = self.digest_bytes(&self.sha256_impl(bytes.clone(), &len));
+        hashed.resize(BLOCK_BYTES, zero);
+
+        match len {
+            MessageLen::Clear(_) => hashed,
+            MessageLen::Enc(len) => {
+                // Only keys that are actually longer than a block are hashed
+                let is_long = sk.scalar_gt_parallelized(&len, BLOCK_BYTES as u64);
+                bytes.truncate(BLOCK_BYTES);
+                hashed
+                    .par_iter()
+                    .zip(bytes.par_iter())
+                    .map(|(hashed, byte)| sk.if_then_else_parallelized(&is_long, hashed, byte))
+                    .collect()
+            }
+        }
+    }
+
+    fn sha256_impl(&self, bytes: Vec<RadixCiphertext>, len: &MessageLen) -> [RadixCiphertext; 8] {
+        match self.hash_backend() {
+            HashBackend::Radix => self.hash_with(&RadixSha256::new(self.inner()), bytes, len),
+            HashBackend::Boolean => self.hash_with(&BooleanSha256::new(self.inner()), bytes, len),
+        }
+    }
+
+    fn hash_with<B: Sha256Compression>(
+        &self,
+        backend: &B,
+        bytes: Vec<RadixCiphertext>,
+        len: &MessageLen,
+    ) -> [RadixCiphertext; 8] {
+        let sk = self.inner();
+        let num_blocks = self.num_ascii_blocks();
+        let trivial_byte =
+            |byte: u8| -> RadixCiphertext { sk.create_trivial_radix(byte, num_blocks) };
+
+        // The message is followed by a 0x80 byte, zeros and its length in bits on 64 bits, so
+        // that the total length is a multiple of the block size
+        let max_len = bytes.len();
+        let total_len = (max_len + 9).div_ceil(BLOCK_BYTES) * BLOCK_BYTES;
+
+        let mut padded = bytes;
+        let ending = match len {
+            MessageLen::Clear(len) => {
+                assert_eq!(*len, max_len);
+                padded.push(trivial_byte(0x80));
+                padded.resize(total_len - 8, trivial_byte(0));
+                padded.extend(((*len as u64) * 8).to_be_bytes().map(trivial_byte));
+                None
+            }
+            MessageLen::Enc(len) => {
+                padded.resize(total_len, trivial_byte(0));
+
+                // The bytes after the message are zeros, the end marker can be added to the one
+                // at the end of the message
+                padded[..=max_len]
+                    .par_iter_mut()
+                    .enumerate()
+                    .for_each(|(i, byte)| {
+                        let is_end = sk.scalar_eq_parallelized(len, i as u64);
+                        let mut marker = trivial_byte(0x80);
+                        sk.zero_out_if_condition_is_false(&mut marker, is_end.as_ref());
+                        sk.bitor_assign_parallelized(byte, &marker);
+                    });
+
+                // The length goes in the last 8 bytes of the block of the message end, these
+                // bytes are zeros in the block where the message ends
+                let (final_block, len_words) = rayon::join(
+                    || {
+                        let end = sk.scalar_add_parallelized(len, 8u8);
+                        sk.scalar_right_shift_parallelized(&end, BLOCK_BYTES.ilog2())
+                    },
+                    || {
+                        let word_blocks = 4 * num_blocks;
+                        let mut bit_len = sk.cast_to_unsigned(len.clone(), 2 * word_blocks);
+                        sk.scalar_left_shift_assign_parallelized(&mut bit_len, 3);
+                        let low = RadixCiphertext::from(bit_len.blocks[..word_blocks].to_vec());
+                        let high = RadixCiphertext::from(bit_len.blocks[word_blocks..].to_vec());
+                        [high, low]
+                    },
+                );
+                Some((final_block, len_words))
+            }
+        };
+
+        // Big-endian words, the first byte is the most significant
+        let words: Vec<B::Word> = padded
+            .par_chunks_exact(4)
+            .map(|bytes| {
+                let blocks = bytes
+                    .iter()
+                    .rev()
+                    .flat_map(|byte| byte.blocks.iter().cloned());
+                backend.word_from_radix(&RadixCiphertext::from(blocks.collect::<Vec<_>>()))
+            })
+            .collect();
+        let mut state = INIT.map(|value| backend.trivial(value));
+
+        let Some((final_block, len_words)) = ending else {
+            for block in words.chunks_exact(16) {
+                state = backend.compress(&state, block);
+            }
+            return state.each_ref().map(|word| backend.word_to_radix(word));
+        };
+
+        // The message may end in any block, the state after each of them with the length
+        // appended is computed and the digest is selected with the encrypted index of the final
+        // block
+        let len_words = len_words.map(|word| backend.word_from_radix(&word));
+        let num_hash_blocks = words.len() / 16;
+        let mut digest: Option<[RadixCiphertext; 8]> = None;
+
+        for (index, block) in words.chunks_exact(16).enumerate() {
+            let mut block_with_len = block.to_vec();
+            block_with_len[14..].clone_from_slice(&len_words);
+
+            let ((candidate, is_final), next_state) = rayon::join(
+                || {
+                    rayon::join(
+                        || backend.compress(&state, &block_with_len),
+                        || sk.scalar_eq_parallelized(&final_block, index as u64),
+                    )
+                },
+                || (index + 1 < num_hash_blocks).then(|| backend.compress(&state, block)),
+            );
+
+            let candidate = self.select_digest(backend, &candidate, &is_final);
+            digest = Some(match digest {
+                None => candidate,
+                Some(digest) => {
+                    let mut words = digest.into_iter().zip(candidate);
+                    std::array::from_fn(|_| {
+                        let (lhs, rhs) = words.next().unwrap();
+                        sk.bitor_parallelized(&lhs, &rhs)
+                    })
+                }
+            });
+
+            if let Some(next_state) = next_state {
+                state = next_state;
+            }
+        }
+
+        digest.expect("There's at least one block")
+    }
+
+    /// Returns the words of the state as radix ciphertexts, zeroed if the condition is false
+    fn select_digest<B: Sha256Compression>(
+        &self,
+        backend: &B,
+        state: &[B::Word; 8],
+        condition: &BooleanBlock,
+    ) -> [RadixCiphertext; 8] {
+        let sk = self.inner();
+
+        let mut words = state.each_ref().map(|word| backend.word_to_radix(word));
+        words.par_iter_mut().for_each(|word| {
+            sk.zero_out_if_condition_is_false(word, condition.as_ref());
+        });
+        words
+    }
+
+    /// Splits the digest words into 32 bytes, in big-endian order
+    fn digest_bytes(&self, digest: &[RadixCiphertext; 8]) -> Vec<RadixCiphertext> {
+        let num_blocks = self.num_ascii_blocks();
+
+        digest
+            .iter()
+            .flat_map(|word| {
+                word.blocks
+                    .chunks_exact(num_blocks)
+                    .rev()
+                    .map(|blocks| RadixCiphertext::from(blocks.to_vec()))
+            })
+            .collect()
+    }
+}
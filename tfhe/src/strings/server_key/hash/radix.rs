@@ -0,0 +1,150 @@
+use super::{Sha256Compression, K};
+use crate::integer::{RadixCiphertext, ServerKey as IntegerServerKey};
+use rayon::prelude::*;
+
+/// Evaluates the compression function with integer operations on 32 bits radix ciphertexts
+pub(super) struct RadixSha256<'a> {
+    sk: &'a IntegerServerKey,
+    num_blocks: usize,
+}
+
+impl<'a> RadixSha256<'a> {
+    pub(super) fn new(sk: &'a IntegerServerKey) -> Self {
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(u32::MAX);
+        Self { sk, num_blocks }
+    }
+
+    /// Xors the rotations of the word by each of the given amounts
+    fn rotations_xor(&self, word: &RadixCiphertext, amounts: [u32; 3]) -> RadixCiphertext {
+        let rotations: Vec<_> = amounts
+            .par_iter()
+            .map(|amount| self.sk.scalar_rotate_right_parallelized(word, *amount))
+            .collect();
+
+        let xor = self.sk.bitxor_parallelized(&rotations[0], &rotations[1]);
+        self.sk.bitxor_parallelized(&xor, &rotations[2])
+    }
+
+    /// Xors two rotations of the word and a right shift of it, used for the message schedule
+    fn schedule_sigma(
+        &self,
+        word: &RadixCiphertext,
+        rotations: [u32; 2],
+        shift: u32,
+    ) -> RadixCiphertext {
+        let sk = self.sk;
+
+        let ((rot_a, rot_b), shifted) = rayon::join(
+            || {
+                rayon::join(
+                    || sk.scalar_rotate_right_parallelized(word, rotations[0]),
+                    || sk.scalar_rotate_right_parallelized(word, rotations[1]),
+                )
+            },
+            || sk.scalar_right_shift_parallelized(word, shift),
+        );
+
+        let xor = sk.bitxor_parallelized(&rot_a, &rot_b);
+        sk.bitxor_parallelized(&xor, &shifted)
+    }
+
+    fn sum<'b>(&self, words: impl IntoIterator<Item = &'b RadixCiphertext>) -> RadixCiphertext {
+        let words: Vec<_> = words.into_iter().collect();
+        self.sk
+            .sum_ciphertexts_parallelized(words)
+            .expect("There are words to sum")
+    }
+}
+
+impl Sha256Compression for RadixSha256<'_> {
+    type Word = RadixCiphertext;
+
+    fn word_from_radix(&self, word: &RadixCiphertext) -> RadixCiphertext {
+        assert_eq!(word.blocks.len(), self.num_blocks);
+        word.clone()
+    }
+
+    fn word_to_radix(&self, word: &RadixCiphertext) -> RadixCiphertext {
+        word.clone()
+    }
+
+    fn trivial(&self, value: u32) -> RadixCiphertext {
+        self.sk.create_trivial_radix(value, self.num_blocks)
+    }
+
+    fn compress(
+        &self,
+        state: &[RadixCiphertext; 8],
+        block: &[RadixCiphertext],
+    ) -> [RadixCiphertext; 8] {
+        let sk = self.sk;
+
+        let mut w = block.to_vec();
+        for i in 16..64 {
+            let (s0, s1) = rayon::join(
+                || self.schedule_sigma(&w[i - 15], [7, 18], 3),
+                || self.schedule_sigma(&w[i - 2], [17, 19], 10),
+            );
+            let word = self.sum([&w[i - 16], &s0, &w[i - 7], &s1]);
+            w.push(word);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+
+        for i in 0..64 {
+            let ((s1, ch), (s0, maj)) = rayon::join(
+                || {
+                    rayon::join(
+                        || self.rotations_xor(&e, [6, 11, 25]),
+                        || {
+                            let (e_and_f, not_e_and_g) = rayon::join(
+                                || sk.bitand_parallelized(&e, &f),
+                                || sk.bitand_parallelized(&sk.bitnot(&e), &g),
+                            );
+                            sk.bitxor_parallelized(&e_and_f, &not_e_and_g)
+                        },
+                    )
+                },
+                || {
+                    rayon::join(
+                        || self.rotations_xor(&a, [2, 13, 22]),
+                        || {
+                            // maj(a, b, c) = (a & (b ^ c)) ^ (b & c)
+                            let (a_and_b_xor_c, b_and_c) = rayon::join(
+                                || sk.bitand_parallelized(&a, &sk.bitxor_parallelized(&b, &c)),
+                                || sk.bitand_parallelized(&b, &c),
+                            );
+                            sk.bitxor_parallelized(&a_and_b_xor_c, &b_and_c)
+                        },
+                    )
+                },
+            );
+
+            let k = self.trivial(K[i]);
+            let (t1, t2) = rayon::join(
+                || self.sum([&h, &s1, &ch, &k, &w[i]]),
+                || sk.add_parallelized(&s0, &maj),
+            );
+            let (d_plus_t1, t1_plus_t2) = rayon::join(
+                || sk.add_parallelized(&d, &t1),
+                || sk.add_parallelized(&t1, &t2),
+            );
+
+            h = g;
+            g = f;
+            f = e;
+            e = d_plus_t1;
+            d = c;
+            c = b;
+            b = a;
+            a = t1_plus_t2;
+        }
+
+        let mut new_state = [a, b, c, d, e, f, g, h];
+        new_state
+            .par_iter_mut()
+            .zip(state.par_iter())
+            .for_each(|(word, previous)| sk.add_assign_parallelized(word, previous));
+        new_state
+    }
+}
@@ -0,0 +1,172 @@
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern};
+use crate::strings::server_key::ServerKey;
+use crate::strings::N;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+// Characters that end a number, boolean or null value: the separators and the ASCII whitespaces
+const SCALAR_END: [u8; 8] = [b',', b'}', b']', b' ', b'\t', b'\n', 0x0C, b'\r'];
+
+// A key is searched as `"key":`, and clear patterns are limited to N characters
+const MAX_KEY_LEN: usize = N - 3;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Extracts the value found at the given key path in an encrypted JSON document
+    ///
+    /// Each key of the `path` is searched, as `"key":`, after the previous one, so `["a", "b"]`
+    /// selects the value of `b` in the object value of `a`. Returns the value and a
+    /// [BooleanBlock] that encrypts `false` if one of the keys was not found, in which case the
+    /// value is empty.
+    ///
+    /// The value can be a string, returned without its quotes, or a number, boolean or null,
+    /// returned as written in the document. Objects and arrays can not be extracted as a whole,
+    /// their fields are reached by extending the path.
+    ///
+    /// The document is not validated and the search is oblivious: the first occurrence of each
+    /// key is selected, even if it belongs to a nested object that comes before the expected
+    /// one. Whitespace is allowed anywhere except between a key and its colon, and escaped quotes
+    /// are not supported in the extracted string values.
+    ///
+    /// The result is padded, so that its length does not depend on the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is empty, or if a key is not an ASCII string of at most 29 characters
+    /// without quotes, backslashes or nulls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, r#"{"a":{"b":"xy"}}"#, None);
+    /// let (value, found) = sk.json_get(&enc_s, &["a", "b"]);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&found));
+    /// assert_eq!(ck.decrypt_ascii(&value), "xy");
+    /// ```
+    pub fn json_get(&self, document: &FheString, path: &[&str]) -> (FheString, BooleanBlock) {
+        let (value, found, _) = self.json_value(document, path);
+
+        (value, found)
+    }
+
+    /// Extracts the number found at the given key path in an encrypted JSON document, and parses
+    /// it as a decimal unsigned 64 bits integer
+    ///
+    /// See [Self::json_get] for how the value is located. Returns the parsed value and a
+    /// [BooleanBlock] that encrypts `false` if the value was not found, is a string, or is not a
+    /// valid `u64` (see [Self::parse_u64]), in which case the value is 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [Self::json_get].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, r#"{"id": 42}"#, Some(2));
+    /// let (value, is_valid) = sk.json_get_u64(&enc_s, &["id"]);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&is_valid));
+    /// assert_eq!(ck.inner().decrypt_radix::<u64>(&value), 42);
+    /// ```
+    pub fn json_get_u64(
+        &self,
+        document: &FheString,
+        path: &[&str],
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+
+        let (value, found, is_string) = self.json_value(document, path);
+
+        let (mut number, is_number) = self.parse_u64(&value, 10);
+        let is_valid = sk.boolean_bitand(
+            &sk.boolean_bitand(&is_number, &found),
+            &sk.boolean_bitnot(&is_string),
+        );
+        sk.zero_out_if_condition_is_false(&mut number, is_valid.as_ref());
+
+        (number, is_valid)
+    }
+
+    /// Returns the value at `path`, whether it was found and whether it is a string
+    fn json_value(
+        &self,
+        document: &FheString,
+        path: &[&str],
+    ) -> (FheString, BooleanBlock, BooleanBlock) {
+        assert!(!path.is_empty(), "the key path must not be empty");
+
+        let sk = self.inner();
+
+        let mut rest = document.clone();
+        let mut found = sk.create_trivial_boolean_block(true);
+        for key in path {
+            assert!(
+                key.is_ascii() && key.len() <= MAX_KEY_LEN,
+                "JSON keys must be ASCII strings of at most {MAX_KEY_LEN} characters, got {key:?}"
+            );
+            assert!(
+                !key.contains(['"', '\\', '\0']),
+                "JSON keys must not contain quotes, backslashes or nulls, got {key:?}"
+            );
+            let pattern = GenericPattern::Clear(ClearString::new(format!("\"{key}\":")));
+
+            let (_, after_key, is_match) = self.split_once(&rest, pattern.as_ref());
+            rest = after_key;
+            sk.boolean_bitand_assign(&mut found, &is_match);
+        }
+
+        let quote = GenericPattern::Clear(ClearString::new("\"".to_string()));
+        let (rest, is_string) = self.strip_prefix(&self.trim_start(&rest), quote.as_ref());
+
+        // A string ends at its closing quote, other values at the first separator or whitespace
+        let is_not_string = sk.boolean_bitnot(&is_string);
+        let mut is_end: Vec<_> = rest
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let (is_quote, is_scalar_end) = rayon::join(
+                    || sk.scalar_eq_parallelized(char.ciphertext(), b'"'),
+                    || sk.is_in_clears_parallelized(char.ciphertext(), &SCALAR_END),
+                );
+                sk.boolean_bitor(
+                    &sk.boolean_bitand(&is_quote, &is_string),
+                    &sk.boolean_bitand(&is_scalar_end, &is_not_string),
+                )
+            })
+            .collect();
+
+        // If a key is missing the whole value is set to null
+        if let Some(first) = is_end.first_mut() {
+            sk.boolean_bitor_assign(first, &sk.boolean_bitnot(&found));
+        }
+
+        let chars = rest
+            .chars()
+            .iter()
+            .map(|char| char.ciphertext().clone())
+            .collect();
+        let value = self.truncate_at_first(&rest, chars, is_end);
+
+        (value, found, is_string)
+    }
+}
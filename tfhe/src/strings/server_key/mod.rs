@@ -1,8 +1,15 @@
 mod comp;
+mod distance;
+mod hash;
+mod json;
 mod no_patterns;
+mod parse;
 mod pattern;
+mod regex;
+mod slice;
 mod trim;
 
+pub use hash::{HashBackend, HashData};
 pub use trim::split_ascii_whitespace;
 
 use crate::integer::bigint::static_unsigned::StaticUnsignedBigInt;
@@ -19,6 +26,7 @@ where
     T: Borrow<IntegerServerKey> + Sync,
 {
     inner: T,
+    hash_backend: HashBackend,
 }
 
 pub type ServerKeyRef<'a> = ServerKey<&'a IntegerServerKey>;
@@ -32,7 +40,22 @@ where
     }
 
     pub fn new(inner: T) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            hash_backend: HashBackend::default(),
+        }
+    }
+
+    /// Returns the key with the given backend for the hash functions, see [HashBackend]
+    pub fn with_hash_backend(self, hash_backend: HashBackend) -> Self {
+        Self {
+            hash_backend,
+            ..self
+        }
+    }
+
+    pub fn hash_backend(&self) -> HashBackend {
+        self.hash_backend
     }
 }
 
@@ -58,6 +81,53 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         num_ascii_blocks(sk.message_modulus())
     }
 
+    // Replaces `str` chars by `chars`, setting to null every char from the first one for which
+    // `is_end` is true. As the result may now end with nulls, a null is appended to make it padded
+    // (even if `str` claims to be padded, as it may come from untrusted blocks)
+    fn truncate_at_first(
+        &self,
+        str: &FheString,
+        mut chars: Vec<RadixCiphertext>,
+        mut is_end: Vec<BooleanBlock>,
+    ) -> FheString {
+        let sk = self.inner();
+
+        // After the step with distance d, is_end[i] is true if any of the flags in
+        // [i + 1 - 2d, i] is true
+        let mut distance = 1;
+        while distance < is_end.len() {
+            is_end = (0..is_end.len())
+                .into_par_iter()
+                .map(|i| {
+                    if i < distance {
+                        is_end[i].clone()
+                    } else {
+                        sk.boolean_bitor(&is_end[i], &is_end[i - distance])
+                    }
+                })
+                .collect();
+            distance *= 2;
+        }
+
+        chars
+            .par_iter_mut()
+            .zip(is_end.par_iter())
+            .for_each(|(char, after_end)| {
+                sk.zero_out_if_condition_equals(char, after_end.as_ref(), 1);
+            });
+
+        let mut result = str.clone();
+        for (char, truncated) in result.chars_mut().iter_mut().zip(chars) {
+            *char.ciphertext_mut() = truncated;
+        }
+        if result.chars().is_empty() {
+            result.set_is_padded(false);
+        } else {
+            result.append_null(self);
+        }
+        result
+    }
+
     // If an iterator is longer than the other, the "excess" characters are ignored. This function
     // performs the equality check by transforming the `str` and `pat` chars into two UInts
     fn asciis_eq<'a, I, U>(&self, str: I, pat: U) -> BooleanBlock
@@ -1,4 +1,4 @@
-use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
 use crate::strings::ciphertext::{FheString, UIntArg};
 use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
 use rayon::prelude::*;
@@ -127,6 +127,70 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         }
     }
 
+    /// Returns whether an encrypted string is a valid ASCII string, that is each character is
+    /// lower than 128 and nulls only appear in the padding at the end of the string.
+    ///
+    /// Strings encrypted with a [ClientKey](crate::strings::ClientKey) are always valid, this is
+    /// meant for strings built from untrusted blocks, such as the ones of a proven compact list:
+    /// the proof only guarantees that each block is in the message space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    ///
+    /// // The chars "a", "\0" and "b", with a null that is not in the padding
+    /// let uint = ck.encrypt_radix(0x61_00_62u32, 12);
+    /// let enc_s = FheString::from_uint(uint, true);
+    ///
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let result = sk.is_valid_ascii(&enc_s);
+    /// assert!(!ck.inner().decrypt_bool(&result));
+    ///
+    /// let enc_s = FheString::new(&ck, "ab", Some(1));
+    /// let result = sk.is_valid_ascii(&enc_s);
+    /// assert!(ck.inner().decrypt_bool(&result));
+    /// ```
+    pub fn is_valid_ascii(&self, str: &FheString) -> BooleanBlock {
+        let sk = self.inner();
+
+        let (is_ascii, is_null): (Vec<_>, Vec<_>) = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                rayon::join(
+                    || sk.scalar_lt_parallelized(char.ciphertext(), 128u8),
+                    || sk.scalar_eq_parallelized(char.ciphertext(), 0u8),
+                )
+            })
+            .unzip();
+
+        let mut conditions = is_ascii;
+        if str.is_padded() {
+            // A null can only be followed by another null
+            conditions.par_extend(
+                is_null
+                    .par_windows(2)
+                    .map(|pair| sk.boolean_bitor(&sk.boolean_bitnot(&pair[0]), &pair[1])),
+            );
+        } else {
+            conditions.par_extend(is_null.par_iter().map(|is_null| sk.boolean_bitnot(is_null)));
+        }
+
+        let blocks = conditions
+            .into_iter()
+            .map(|condition| condition.0)
+            .collect();
+        BooleanBlock::new_unchecked(sk.are_all_comparisons_block_true(blocks))
+    }
+
     /// Returns a new encrypted string with all characters converted to uppercase.
     ///
     /// # Examples
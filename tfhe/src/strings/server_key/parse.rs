@@ -0,0 +1,319 @@
+use crate::integer::prelude::*;
+use crate::integer::server_key::MatchValues;
+use crate::integer::{
+    BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey, SignedRadixCiphertext,
+};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Parses the string as an unsigned 64 bits integer written in base `radix`, following the
+    /// rules of [u64::from_str_radix]
+    ///
+    /// Digits above 9 are the letters `a` to `z`, in lower or upper case. An optional `+` sign
+    /// may precede the digits. Returns the parsed value and a [BooleanBlock] that encrypts
+    /// `false` if the string is not a valid number (empty, invalid character, or value
+    /// overflowing a `u64`), in which case the value is 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "1234", Some(1));
+    /// let (value, is_valid) = sk.parse_u64(&enc_s, 10);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&is_valid));
+    /// assert_eq!(ck.inner().decrypt_radix::<u64>(&value), 1234);
+    /// ```
+    pub fn parse_u64(&self, str: &FheString, radix: u32) -> (RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+
+        let (magnitude, is_valid, _) = self.parse_magnitude(str, radix, false);
+
+        let mut value = magnitude;
+        sk.zero_out_if_condition_is_false(&mut value, is_valid.as_ref());
+
+        (value, is_valid)
+    }
+
+    /// Parses the string as a signed 64 bits integer written in base `radix`, following the
+    /// rules of [i64::from_str_radix]
+    ///
+    /// Same as [Self::parse_u64], except that a `-` sign is also accepted and that the value must
+    /// fit in an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn parse_i64(&self, str: &FheString, radix: u32) -> (SignedRadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+
+        let (magnitude, is_valid, is_negative) = self.parse_magnitude(str, radix, true);
+
+        // The magnitude of i64::MIN is one more than the one of i64::MAX
+        let ((too_large, is_min_magnitude), negated) = rayon::join(
+            || {
+                rayon::join(
+                    || sk.scalar_gt_parallelized(&magnitude, i64::MAX as u64),
+                    || sk.scalar_eq_parallelized(&magnitude, i64::MIN.unsigned_abs()),
+                )
+            },
+            || sk.neg_parallelized(&magnitude),
+        );
+        let is_min = sk.boolean_bitand(&is_min_magnitude, &is_negative);
+        let in_range =
+            sk.boolean_bitnot(&sk.boolean_bitand(&too_large, &sk.boolean_bitnot(&is_min)));
+        let is_valid = sk.boolean_bitand(&is_valid, &in_range);
+
+        let mut value = sk.if_then_else_parallelized(&is_negative, &negated, &magnitude);
+        sk.zero_out_if_condition_is_false(&mut value, is_valid.as_ref());
+
+        (
+            SignedRadixCiphertext::from_blocks(value.into_blocks()),
+            is_valid,
+        )
+    }
+
+    /// Formats an unsigned integer of at most 64 bits as its decimal representation
+    ///
+    /// The result is a padded string, its number of characters (including padding) only depends
+    /// on the number of blocks of `n`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    ///
+    /// let n = ck.encrypt_radix(1003u64, 8);
+    ///
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let string = sk.to_decimal_string(&n);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&string), "1003");
+    /// ```
+    pub fn to_decimal_string(&self, n: &RadixCiphertext) -> FheString {
+        let sk = self.inner();
+
+        let num_bits = n.blocks().len() as u32 * sk.message_modulus().0.ilog2();
+        assert!(
+            (1..=64).contains(&num_bits),
+            "to_decimal_string only supports integers of 1 to 64 bits, got {num_bits} bits"
+        );
+
+        let max_value = u64::MAX >> (64 - num_bits);
+        let num_digits = max_value.ilog10() + 1;
+
+        let mut n = n.clone();
+        if !n.block_carries_are_empty() {
+            sk.full_propagate_parallelized(&mut n);
+        }
+        let digits = self.decimal_digits(n, num_digits);
+
+        let num_ascii_blocks = self.num_ascii_blocks();
+        let (chars, leading_zeros) = rayon::join(
+            || {
+                digits
+                    .par_iter()
+                    .map(|digit| {
+                        let mut digit = digit.clone();
+                        let missing = num_ascii_blocks - digit.blocks().len();
+                        sk.extend_radix_with_trivial_zero_blocks_msb_assign(&mut digit, missing);
+                        sk.scalar_add_assign_parallelized(&mut digit, b'0');
+                        FheAsciiChar { enc_char: digit }
+                    })
+                    .collect()
+            },
+            || {
+                // The last digit is always kept, so that 0 is formatted as "0"
+                let is_zero: Vec<_> = digits[..digits.len() - 1]
+                    .par_iter()
+                    .map(|digit| sk.scalar_eq_parallelized(digit, 0u8))
+                    .collect();
+
+                let mut all_zeros = sk.create_trivial_boolean_block(true);
+                let leading: Vec<RadixCiphertext> = is_zero
+                    .iter()
+                    .map(|is_zero| {
+                        sk.boolean_bitand_assign(&mut all_zeros, is_zero);
+                        all_zeros.clone().into_radix::<RadixCiphertext>(16, sk)
+                    })
+                    .collect();
+
+                sk.sum_ciphertexts_parallelized(leading.iter())
+                    .unwrap_or_else(|| sk.create_trivial_zero_radix(16))
+            },
+        );
+
+        // Shifting left removes the leading zeros and fills the end with nulls
+        let digits_str = FheString {
+            enc_string: chars,
+            padded: false,
+        };
+        let mut result = self.left_shift_chars(&digits_str, &leading_zeros);
+        result.append_null(self);
+
+        result
+    }
+
+    /// Splits `n`, which is lower than `10^num_digits`, into its decimal digits, most
+    /// significant first
+    fn decimal_digits(&self, n: RadixCiphertext, num_digits: u32) -> Vec<RadixCiphertext> {
+        if num_digits == 1 {
+            return vec![n];
+        }
+
+        let sk = self.inner();
+        let low_digits = num_digits / 2;
+        let high_digits = num_digits - low_digits;
+
+        let trim = |mut ct: RadixCiphertext, digits: u32| {
+            let num_blocks = sk.num_blocks_to_represent_unsigned_value(10u64.pow(digits) - 1);
+            if ct.blocks().len() > num_blocks {
+                let num_blocks_to_remove = ct.blocks().len() - num_blocks;
+                sk.trim_radix_blocks_msb_assign(&mut ct, num_blocks_to_remove);
+            }
+            ct
+        };
+
+        let (high, low) = sk.scalar_div_rem_parallelized(&n, 10u64.pow(low_digits));
+        let (mut high, low) = rayon::join(
+            || self.decimal_digits(trim(high, high_digits), high_digits),
+            || self.decimal_digits(trim(low, low_digits), low_digits),
+        );
+
+        high.extend(low);
+        high
+    }
+
+    /// Parses the digits of a number, returns its magnitude, whether the string is a valid
+    /// number (ignoring the range of signed integers) and whether it has a `-` sign
+    fn parse_magnitude(
+        &self,
+        str: &FheString,
+        radix: u32,
+        allow_minus: bool,
+    ) -> (RadixCiphertext, BooleanBlock, BooleanBlock) {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, got {radix}"
+        );
+
+        let sk = self.inner();
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(u64::MAX);
+        let chars = str.chars();
+
+        if chars.is_empty() {
+            return (
+                sk.create_trivial_zero_radix(num_blocks),
+                sk.create_trivial_boolean_block(false),
+                sk.create_trivial_boolean_block(false),
+            );
+        }
+
+        let digit_values = MatchValues::new(
+            (0..128u8)
+                .filter_map(|c| (c as char).to_digit(radix).map(|digit| (c, digit as u8)))
+                .collect(),
+        )
+        .unwrap();
+
+        let (digits, (first_is_sign, is_negative)): (Vec<_>, _) = rayon::join(
+            || {
+                chars
+                    .par_iter()
+                    .map(|c| {
+                        let (digit, is_digit) =
+                            sk.match_value_parallelized(c.ciphertext(), &digit_values);
+                        let is_null_or_digit = if str.is_padded() {
+                            let is_null = sk.scalar_eq_parallelized(c.ciphertext(), 0u8);
+                            sk.boolean_bitor(&is_null, &is_digit)
+                        } else {
+                            is_digit.clone()
+                        };
+                        (digit, is_digit, is_null_or_digit)
+                    })
+                    .collect()
+            },
+            || {
+                let first = chars[0].ciphertext();
+                let (is_plus, is_minus) = rayon::join(
+                    || sk.scalar_eq_parallelized(first, b'+'),
+                    || {
+                        if allow_minus {
+                            sk.scalar_eq_parallelized(first, b'-')
+                        } else {
+                            sk.create_trivial_boolean_block(false)
+                        }
+                    },
+                );
+                (sk.boolean_bitor(&is_plus, &is_minus), is_minus)
+            },
+        );
+
+        // The first character is a digit, or a sign followed by a digit. The following ones
+        // are digits, or the padding that ends the string
+        let first_is_valid = match digits.get(1) {
+            Some((_, second_is_digit, _)) => sk.boolean_bitor(
+                &digits[0].1,
+                &sk.boolean_bitand(&first_is_sign, second_is_digit),
+            ),
+            None => digits[0].1.clone(),
+        };
+        let mut validity_blocks = vec![first_is_valid.into_raw_parts()];
+        validity_blocks.extend(
+            digits[1..]
+                .iter()
+                .map(|(_, _, is_null_or_digit)| is_null_or_digit.clone().into_raw_parts()),
+        );
+
+        let mut value = sk.create_trivial_zero_radix(num_blocks);
+        let mut overflowed = sk.create_trivial_boolean_block(false);
+        for (digit, is_digit, _) in digits {
+            let digit = sk.extend_radix_with_trivial_zero_blocks_msb(
+                &digit,
+                num_blocks - digit.blocks().len(),
+            );
+
+            // value * radix + digit overflows if value > u64::MAX / radix, or if the addition
+            // overflows
+            let (mut shifted, mul_overflows) = rayon::join(
+                || sk.scalar_mul_parallelized(&value, u64::from(radix)),
+                || sk.scalar_gt_parallelized(&value, u64::MAX / u64::from(radix)),
+            );
+            let add_overflows =
+                sk.unsigned_overflowing_add_assign_parallelized(&mut shifted, &digit);
+            let step_overflows = sk.boolean_bitor(&mul_overflows, &add_overflows);
+
+            // Non digit characters (sign and padding) leave the value unchanged
+            value = sk.if_then_else_parallelized(&is_digit, &shifted, &value);
+            let step_overflows = sk.boolean_bitand(&step_overflows, &is_digit);
+            sk.boolean_bitor_assign(&mut overflowed, &step_overflows);
+        }
+
+        validity_blocks.push(sk.boolean_bitnot(&overflowed).into_raw_parts());
+        let is_valid =
+            BooleanBlock::new_unchecked(sk.are_all_comparisons_block_true(validity_blocks));
+
+        (value, is_valid, is_negative)
+    }
+}
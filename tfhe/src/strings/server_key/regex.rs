@@ -0,0 +1,472 @@
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::FheString;
+use crate::strings::regex::{CharSet, CompiledRegex, Nfa};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+/// A boolean that stays in the clear as long as it does not depend on encrypted data
+///
+/// Many states of the automaton are trivially active or inactive (e.g. because of anchors or
+/// because the pattern can not have reached them yet), keeping them clear saves a lot of PBS.
+#[derive(Clone)]
+enum Bit {
+    Clear(bool),
+    Enc(BooleanBlock),
+}
+
+const FALSE: Bit = Bit::Clear(false);
+const TRUE: Bit = Bit::Clear(true);
+
+impl Bit {
+    fn and(&self, other: &Self, sk: &IntegerServerKey) -> Self {
+        match (self, other) {
+            (Self::Clear(false), _) | (_, Self::Clear(false)) => FALSE,
+            (Self::Clear(true), bit) | (bit, Self::Clear(true)) => bit.clone(),
+            (Self::Enc(lhs), Self::Enc(rhs)) => Self::Enc(sk.boolean_bitand(lhs, rhs)),
+        }
+    }
+
+    fn or(&self, other: &Self, sk: &IntegerServerKey) -> Self {
+        Self::any(vec![self.clone(), other.clone()], sk)
+    }
+
+    fn not(&self, sk: &IntegerServerKey) -> Self {
+        match self {
+            Self::Clear(value) => Self::Clear(!value),
+            Self::Enc(block) => Self::Enc(sk.boolean_bitnot(block)),
+        }
+    }
+
+    fn any(bits: Vec<Self>, sk: &IntegerServerKey) -> Self {
+        let mut blocks = Vec::with_capacity(bits.len());
+        for bit in bits {
+            match bit {
+                Self::Clear(true) => return TRUE,
+                Self::Clear(false) => {}
+                Self::Enc(block) => blocks.push(block.into_raw_parts()),
+            }
+        }
+
+        match blocks.len() {
+            0 => FALSE,
+            1 => Self::Enc(BooleanBlock::new_unchecked(blocks.pop().unwrap())),
+            _ => Self::Enc(BooleanBlock::new_unchecked(
+                sk.is_at_least_one_comparisons_block_true(blocks),
+            )),
+        }
+    }
+
+    fn into_block(self, sk: &IntegerServerKey) -> BooleanBlock {
+        match self {
+            Self::Clear(value) => sk.create_trivial_boolean_block(value),
+            Self::Enc(block) => block,
+        }
+    }
+}
+
+/// Encrypted data shared by all the evaluations of automata on a string
+struct Text<'a> {
+    sk: &'a IntegerServerKey,
+    len: usize,
+    /// For each character set, whether the character at each position is in the set
+    in_set: HashMap<CharSet, Vec<Bit>>,
+    /// Whether each position (`0..=len`) is the end of the text
+    is_end: Vec<Bit>,
+}
+
+impl<'a> Text<'a> {
+    fn new(sk: &'a IntegerServerKey, str: &FheString, nfas: &[&Nfa]) -> Self {
+        let chars = str.chars();
+        let len = chars.len();
+
+        let mut sets = vec![];
+        for nfa in nfas {
+            for set in nfa.char_sets() {
+                if !sets.contains(&set) {
+                    sets.push(set);
+                }
+            }
+        }
+
+        let (in_set, is_end) = rayon::join(
+            || {
+                sets.into_par_iter()
+                    .map(|set| {
+                        let bits = chars
+                            .par_iter()
+                            .map(|c| Bit::Enc(char_in_set(sk, c.ciphertext(), set)))
+                            .collect();
+                        (set, bits)
+                    })
+                    .collect::<HashMap<_, _>>()
+            },
+            || {
+                // With padding, the end of the text is the first null character
+                let mut is_end: Vec<_> = if str.is_padded() {
+                    chars
+                        .par_iter()
+                        .map(|c| Bit::Enc(sk.scalar_eq_parallelized(c.ciphertext(), 0u8)))
+                        .collect()
+                } else {
+                    vec![FALSE; len]
+                };
+                is_end.push(TRUE);
+                is_end
+            },
+        );
+
+        Self {
+            sk,
+            len,
+            in_set,
+            is_end,
+        }
+    }
+
+    /// Computes the states active after following epsilon transitions from the given states
+    fn close(&self, nfa: &Nfa, seeds: &[(usize, Bit)], position: usize) -> Vec<Bit> {
+        let mut direct = vec![vec![]; nfa.num_important_states()];
+        let mut through_end = vec![vec![]; nfa.num_important_states()];
+
+        for (state, bit) in seeds {
+            let closure = nfa.closure(*state, position == 0);
+            for index in &closure.direct {
+                direct[*index].push(bit.clone());
+            }
+            for index in &closure.through_end {
+                through_end[*index].push(bit.clone());
+            }
+        }
+
+        direct
+            .into_par_iter()
+            .zip(through_end.into_par_iter())
+            .map(|(direct, through_end)| {
+                let direct = Bit::any(direct, self.sk);
+                let through_end =
+                    Bit::any(through_end, self.sk).and(&self.is_end[position], self.sk);
+                direct.or(&through_end, self.sk)
+            })
+            .collect()
+    }
+
+    /// Follows the character transitions from the active states, consuming the character at
+    /// `position`
+    fn step(&self, nfa: &Nfa, active: &[Bit], position: usize) -> Vec<(usize, Bit)> {
+        active
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(index, bit)| {
+                let transitions = if matches!(bit, Bit::Clear(false)) {
+                    &[][..]
+                } else {
+                    nfa.char_edges(index)
+                };
+                transitions.iter().map(move |(set, target)| {
+                    let in_set = &self.in_set[set][position];
+                    (*target, bit.and(in_set, self.sk))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns whether the automaton matches `text[from..to]`, for each `to` in `from..=len`
+    fn anchored_matches(&self, nfa: &Nfa, from: usize) -> Vec<Bit> {
+        let accept = nfa.accept_index();
+
+        let mut active = self.close(nfa, &[(nfa.start(), TRUE)], from);
+        let mut matches = vec![active[accept].clone()];
+        for position in from..self.len {
+            let seeds = self.step(nfa, &active, position);
+            active = self.close(nfa, &seeds, position + 1);
+            matches.push(active[accept].clone());
+        }
+        matches
+    }
+
+    /// Returns whether the automaton matches `text[from..to]`, indexed by `[from][to - from]`
+    fn all_matches(&self, nfa: &Nfa) -> Vec<Vec<Bit>> {
+        (0..=self.len)
+            .into_par_iter()
+            .map(|from| self.anchored_matches(nfa, from))
+            .collect()
+    }
+
+    /// Returns, as a 16 blocks radix, the index of the set bit of a one-hot vector over the
+    /// positions `0..=len`, or 0 if no bit is set
+    fn position(&self, one_hot: &[Bit]) -> RadixCiphertext {
+        // The position is the number of indices i > 0 such that a bit is set at or after i
+        let mut at_or_after = vec![FALSE; one_hot.len()];
+        let mut acc = FALSE;
+        for (i, bit) in one_hot.iter().enumerate().rev() {
+            acc = acc.or(bit, self.sk);
+            at_or_after[i] = acc.clone();
+        }
+
+        count_bits(self.sk, at_or_after.into_iter().skip(1).collect())
+    }
+
+    /// Keeps only the last set bit of a vector
+    fn keep_last(&self, bits: &[Bit]) -> Vec<Bit> {
+        let mut result = vec![FALSE; bits.len()];
+        let mut seen_after = FALSE;
+        for (i, bit) in bits.iter().enumerate().rev() {
+            result[i] = bit.and(&seen_after.not(self.sk), self.sk);
+            seen_after = seen_after.or(bit, self.sk);
+        }
+        result
+    }
+}
+
+fn char_in_set(sk: &IntegerServerKey, c: &RadixCiphertext, set: CharSet) -> BooleanBlock {
+    let in_ranges = |ranges: Vec<(u8, u8)>| {
+        let checks = ranges
+            .into_par_iter()
+            .map(|(from, to)| {
+                if from == to {
+                    sk.scalar_eq_parallelized(c, from)
+                } else {
+                    let (ge, le) = rayon::join(
+                        || sk.scalar_ge_parallelized(c, from),
+                        || sk.scalar_le_parallelized(c, to),
+                    );
+                    sk.boolean_bitand(&ge, &le)
+                }
+            })
+            .map(Bit::Enc)
+            .collect();
+        Bit::any(checks, sk).into_block(sk)
+    };
+
+    let ranges = set.ranges();
+    let complement_ranges = set.complement().ranges();
+
+    // Null characters (padding) are never in a set, so testing the complement also requires
+    // checking for them
+    if ranges.len() <= complement_ranges.len() + 1 {
+        in_ranges(ranges)
+    } else {
+        let (in_complement, is_null) = rayon::join(
+            || in_ranges(complement_ranges),
+            || sk.scalar_eq_parallelized(c, 0u8),
+        );
+        sk.boolean_bitnot(&sk.boolean_bitor(&in_complement, &is_null))
+    }
+}
+
+/// Counts the bits that are set, the result is a 16 blocks radix
+fn count_bits(sk: &IntegerServerKey, bits: Vec<Bit>) -> RadixCiphertext {
+    let mut clear_count = 0u32;
+    let mut blocks = vec![];
+    for bit in bits {
+        match bit {
+            Bit::Clear(value) => clear_count += u32::from(value),
+            Bit::Enc(block) => blocks.push(block),
+        }
+    }
+
+    let radixes: Vec<RadixCiphertext> = blocks
+        .into_par_iter()
+        .map(|block| block.into_radix(16, sk))
+        .collect();
+
+    let mut count = sk
+        .sum_ciphertexts_parallelized(radixes.iter())
+        .unwrap_or_else(|| sk.create_trivial_zero_radix(16));
+    sk.scalar_add_assign_parallelized(&mut count, clear_count);
+
+    count
+}
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Returns whether the regular expression matches somewhere in the encrypted string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::regex::CompiledRegex;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let regex = CompiledRegex::new("^h.*o$").unwrap();
+    ///
+    /// let enc_s = FheString::new(&ck, "hello", None);
+    /// let result = sk.regex_is_match(&enc_s, &regex);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result));
+    /// ```
+    pub fn regex_is_match(&self, str: &FheString, regex: &CompiledRegex) -> BooleanBlock {
+        let sk = self.inner();
+        let nfa = regex.nfa();
+        let text = Text::new(sk, str, &[nfa]);
+        let accept = nfa.accept_index();
+
+        // A single pass where the automaton is restarted at each position
+        let mut active = vec![FALSE; nfa.num_important_states()];
+        let mut matched = FALSE;
+        for position in 0..=text.len {
+            let mut seeds = if position == 0 {
+                vec![]
+            } else {
+                text.step(nfa, &active, position - 1)
+            };
+            seeds.push((nfa.start(), TRUE));
+
+            active = text.close(nfa, &seeds, position);
+            matched = matched.or(&active[accept], sk);
+            if matches!(matched, Bit::Clear(true)) {
+                break;
+            }
+        }
+
+        matched.into_block(sk)
+    }
+
+    /// Returns the leftmost match of the regular expression
+    ///
+    /// Among the matches starting at the leftmost position, the longest one is returned.
+    /// The result is the start index, the end index (exclusive) and whether there was a match.
+    /// Indices are 0 if there was no match.
+    pub fn regex_find(
+        &self,
+        str: &FheString,
+        regex: &CompiledRegex,
+    ) -> (RadixCiphertext, RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+        let text = Text::new(sk, str, &[regex.nfa()]);
+
+        let (start, end, found) = self.leftmost_longest(&text, regex.nfa());
+        let (start, end) = rayon::join(|| text.position(&start), || text.position(&end));
+
+        (start, end, found.into_block(sk))
+    }
+
+    /// Returns the bounds of the leftmost-longest match (see [Self::regex_find]) and of each
+    /// capture group of the regular expression, as well as whether there was a match
+    ///
+    /// The first element is the whole match, followed by one element per capture group. Each
+    /// element is the start index and the end index (exclusive). Indices are 0 if there was no
+    /// match.
+    ///
+    /// When a group could match different parts of the string, the leftmost groups take the
+    /// longest possible parts.
+    pub fn regex_captures(
+        &self,
+        str: &FheString,
+        regex: &CompiledRegex,
+    ) -> (Vec<(RadixCiphertext, RadixCiphertext)>, BooleanBlock) {
+        let sk = self.inner();
+        let segments = regex.segments();
+        let nfas: Vec<_> = std::iter::once(regex.nfa()).chain(segments).collect();
+        let text = Text::new(sk, str, &nfas);
+        let len = text.len;
+
+        let (start, end, found) = self.leftmost_longest(&text, regex.nfa());
+        let segment_matches: Vec<_> = segments
+            .par_iter()
+            .map(|nfa| text.all_matches(nfa))
+            .collect();
+
+        // can_finish[j][x] is true if the segments j.. can match from x to the end of the match
+        let mut can_finish = vec![end];
+        for matches in segment_matches.iter().rev() {
+            let next = can_finish.last().unwrap();
+            let current = (0..=len)
+                .into_par_iter()
+                .map(|x| {
+                    let bits = matches[x]
+                        .iter()
+                        .enumerate()
+                        .map(|(offset, is_match)| is_match.and(&next[x + offset], sk))
+                        .collect();
+                    Bit::any(bits, sk)
+                })
+                .collect();
+            can_finish.push(current);
+        }
+        can_finish.reverse();
+
+        // Starting from the start of the match, each segment greedily takes the longest
+        // part that still allows the following segments to match
+        let mut boundaries = vec![start];
+        for (j, matches) in segment_matches.iter().enumerate() {
+            let current = boundaries.last().unwrap();
+            let candidates: Vec<_> = (0..=len)
+                .into_par_iter()
+                .map(|y| {
+                    let bits = (0..=y)
+                        .map(|x| {
+                            current[x]
+                                .and(&matches[x][y - x], sk)
+                                .and(&can_finish[j + 1][y], sk)
+                        })
+                        .collect();
+                    Bit::any(bits, sk)
+                })
+                .collect();
+            boundaries.push(text.keep_last(&candidates));
+        }
+
+        let positions: Vec<_> = boundaries
+            .par_iter()
+            .map(|one_hot| text.position(one_hot))
+            .collect();
+
+        let whole_match = (positions[0].clone(), positions[segments.len()].clone());
+        let groups = regex
+            .group_segments()
+            .iter()
+            .map(|&j| (positions[j].clone(), positions[j + 1].clone()));
+
+        (
+            std::iter::once(whole_match).chain(groups).collect(),
+            found.into_block(sk),
+        )
+    }
+
+    /// Returns the one-hot encoded start and end of the leftmost-longest match, and whether
+    /// there is a match
+    fn leftmost_longest(&self, text: &Text<'_>, nfa: &Nfa) -> (Vec<Bit>, Vec<Bit>, Bit) {
+        let sk = self.inner();
+        let len = text.len;
+        let matches = text.all_matches(nfa);
+
+        let match_from: Vec<_> = matches
+            .par_iter()
+            .map(|ends| Bit::any(ends.clone(), sk))
+            .collect();
+
+        // Keep the first start with a match
+        let mut start = vec![FALSE; len + 1];
+        let mut seen = FALSE;
+        for (from, has_match) in match_from.iter().enumerate() {
+            start[from] = has_match.and(&seen.not(sk), sk);
+            seen = seen.or(has_match, sk);
+        }
+
+        // For this start, keep the last end
+        let longest: Vec<_> = matches
+            .par_iter()
+            .map(|ends| text.keep_last(ends))
+            .collect();
+        let end = (0..=len)
+            .into_par_iter()
+            .map(|to| {
+                let bits = (0..=to)
+                    .map(|from| start[from].and(&longest[from][to - from], sk))
+                    .collect();
+                Bit::any(bits, sk)
+            })
+            .collect();
+
+        (start, end, seen)
+    }
+}
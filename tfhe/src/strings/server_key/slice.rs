@@ -0,0 +1,219 @@
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, UIntArg};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Returns the character at the given index, and a [BooleanBlock] that encrypts `true` if
+    /// the index is within the bounds of the string
+    ///
+    /// If the index is out of bounds, the returned character is 0. The index can be clear or
+    /// encrypted, a clear index does not require any PBS for a string without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "hello", Some(2));
+    /// let index = UIntArg::Enc(ck.encrypt_u16(1, None));
+    ///
+    /// let (char, in_bounds) = sk.char_at(&enc_s, &index);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&in_bounds));
+    /// assert_eq!(ck.inner().decrypt_radix::<u8>(&char), b'e');
+    /// ```
+    pub fn char_at(&self, str: &FheString, index: &UIntArg) -> (RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+        let chars = str.chars();
+
+        let char = match index {
+            UIntArg::Clear(index) => match chars.get(*index as usize) {
+                Some(char) if !str.is_padded() => {
+                    return (
+                        char.ciphertext().clone(),
+                        sk.create_trivial_boolean_block(true),
+                    );
+                }
+                Some(char) => char.ciphertext().clone(),
+                None => {
+                    return (
+                        sk.create_trivial_zero_radix(self.num_ascii_blocks()),
+                        sk.create_trivial_boolean_block(false),
+                    );
+                }
+            },
+            UIntArg::Enc(index) => {
+                let selected: Vec<_> = chars
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, char)| {
+                        let is_selected = sk.scalar_eq_parallelized(index.cipher(), i as u64);
+                        let mut char = char.ciphertext().clone();
+                        sk.zero_out_if_condition_is_false(&mut char, is_selected.as_ref());
+                        char
+                    })
+                    .collect();
+
+                let char = sk
+                    .sum_ciphertexts_parallelized(selected.iter())
+                    .unwrap_or_else(|| sk.create_trivial_zero_radix(self.num_ascii_blocks()));
+
+                if !str.is_padded() {
+                    let in_bounds = sk.scalar_lt_parallelized(index.cipher(), chars.len() as u64);
+                    return (char, in_bounds);
+                }
+                char
+            }
+        };
+
+        // With padding, the index is out of bounds if it points to a null character
+        let in_bounds = sk.scalar_ne_parallelized(&char, 0u8);
+        (char, in_bounds)
+    }
+
+    /// Returns the characters of the string from `start` (inclusive) to `end` (exclusive)
+    ///
+    /// Unlike the slicing of Rust strings, this never panics: bounds greater than the length of
+    /// the string are clamped to it, and if `start >= end` the result is empty.
+    ///
+    /// Clear bounds are applied without any PBS, only encrypted bounds require homomorphic
+    /// shifts and comparisons. With encrypted bounds the result is padded and has as many
+    /// characters (including padding) as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "hello world", None);
+    /// let start = UIntArg::Enc(ck.encrypt_u16(6, None));
+    /// let end = UIntArg::Clear(9);
+    ///
+    /// let result = sk.substring(&enc_s, &start, &end);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result), "wor");
+    /// ```
+    pub fn substring(&self, str: &FheString, start: &UIntArg, end: &UIntArg) -> FheString {
+        let sk = self.inner();
+
+        let mut chars = str.chars().to_vec();
+        let mut may_have_nulls = str.is_padded();
+
+        // A clear end removes the characters after it, which also makes the encrypted shift
+        // below fill them with nulls
+        if let UIntArg::Clear(end) = end {
+            chars.truncate(*end as usize);
+        }
+        if chars.is_empty() {
+            return FheString::empty();
+        }
+
+        let mut chars = match start {
+            UIntArg::Clear(start) => {
+                let start = (*start as usize).min(chars.len());
+                chars.drain(..start);
+
+                if let UIntArg::Enc(end) = end {
+                    let count = self.saturating_sub_scalar(end.cipher(), start as u64);
+                    self.keep_first_chars(&mut chars, &count);
+                    may_have_nulls = true;
+                }
+                chars
+            }
+            UIntArg::Enc(start) => {
+                // Clamping the shift to the length keeps it in the range handled by
+                // `left_shift_chars`, and leaves room for its multiplication by 8
+                let mut shift = sk.scalar_min_parallelized(start.cipher(), chars.len() as u64);
+                sk.extend_radix_with_trivial_zero_blocks_msb_assign(
+                    &mut shift,
+                    sk.num_blocks_to_represent_unsigned_value(7u8),
+                );
+
+                let str = FheString {
+                    enc_string: chars,
+                    padded: false,
+                };
+                let mut chars = self.left_shift_chars(&str, &shift).enc_string;
+
+                if let UIntArg::Enc(end) = end {
+                    let count = self.saturating_sub(end.cipher(), start.cipher());
+                    self.keep_first_chars(&mut chars, &count);
+                }
+                may_have_nulls = true;
+                chars
+            }
+        };
+
+        if chars.is_empty() {
+            return FheString::empty();
+        }
+        if may_have_nulls {
+            chars.push(FheAsciiChar::null(self));
+        }
+
+        FheString {
+            enc_string: chars,
+            padded: may_have_nulls,
+        }
+    }
+
+    /// Returns the first `len` characters of the string, or the whole string if it is shorter
+    ///
+    /// This is the same as [Self::substring] with a start of 0.
+    pub fn truncate(&self, str: &FheString, len: &UIntArg) -> FheString {
+        self.substring(str, &UIntArg::Clear(0), len)
+    }
+
+    /// Replaces by nulls the characters whose index is greater or equal to `count`
+    fn keep_first_chars(&self, chars: &mut [FheAsciiChar], count: &RadixCiphertext) {
+        let sk = self.inner();
+
+        chars.par_iter_mut().enumerate().for_each(|(i, char)| {
+            let keep = sk.scalar_gt_parallelized(count, i as u64);
+            sk.zero_out_if_condition_is_false(char.ciphertext_mut(), keep.as_ref());
+        });
+    }
+
+    /// Computes `lhs - rhs`, or 0 if `rhs > lhs`
+    fn saturating_sub(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let num_blocks = lhs.blocks().len().max(rhs.blocks().len());
+        let mut lhs = lhs.clone();
+        let mut rhs = rhs.clone();
+        self.pad_or_trim_ciphertext(&mut lhs, num_blocks);
+        self.pad_or_trim_ciphertext(&mut rhs, num_blocks);
+
+        let (mut diff, overflowed) = sk.unsigned_overflowing_sub_parallelized(&lhs, &rhs);
+        let not_overflowed = sk.boolean_bitnot(&overflowed);
+        sk.zero_out_if_condition_is_false(&mut diff, not_overflowed.as_ref());
+        diff
+    }
+
+    /// Computes `lhs - rhs`, or 0 if `rhs > lhs`
+    fn saturating_sub_scalar(&self, lhs: &RadixCiphertext, rhs: u64) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let (mut diff, overflowed) = sk.unsigned_overflowing_scalar_sub_parallelized(lhs, rhs);
+        let not_overflowed = sk.boolean_bitnot(&overflowed);
+        sk.zero_out_if_condition_is_false(&mut diff, not_overflowed.as_ref());
+        diff
+    }
+}
@@ -3,7 +3,14 @@ mod test_compact;
 mod test_compression;
 mod test_concat;
 mod test_contains;
+mod test_distance;
 mod test_find_replace;
+mod test_hash;
+mod test_json;
+mod test_ordering;
+mod test_parse;
+mod test_regex;
+mod test_slice;
 mod test_split;
 mod test_up_low_case;
 mod test_whitespace;
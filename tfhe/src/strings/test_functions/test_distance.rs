@@ -0,0 +1,109 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern};
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+#[test]
+fn distance_test_parameterized() {
+    distance_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+fn clear_edit_distance(lhs: &str, rhs: &str) -> u16 {
+    let rhs = rhs.as_bytes();
+    let mut row: Vec<u16> = (0..=rhs.len() as u16).collect();
+
+    for (i, lhs_char) in lhs.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i as u16 + 1;
+        for (j, rhs_char) in rhs.iter().enumerate() {
+            let substitution = diagonal + u16::from(lhs_char != *rhs_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[rhs.len()]
+}
+
+fn clear_hamming_distance(lhs: &str, rhs: &str) -> u16 {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    (0..lhs.len().max(rhs.len()))
+        .filter(|i| lhs.get(*i) != rhs.get(*i))
+        .count() as u16
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn distance_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for lhs_pad in [0, 2] {
+        for rhs_pad in [0, 1] {
+            for (lhs, rhs) in [
+                ("", ""),
+                ("", "ab"),
+                ("abc", ""),
+                ("abc", "abc"),
+                ("kitten", "sitting"),
+                ("flaw", "lawn"),
+                ("ab", "ba"),
+                ("abcd", "ad"),
+                // The longest length is a power of the message modulus minus one
+                ("a", "xyz"),
+                ("abc", "xyz"),
+            ] {
+                let enc_lhs = FheString::new_trivial(&cks, lhs, Some(lhs_pad));
+                let enc_rhs = GenericPattern::Enc(FheString::new_trivial(&cks, rhs, Some(rhs_pad)));
+                let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+                let expected_edit = clear_edit_distance(lhs, rhs);
+                let expected_hamming = clear_hamming_distance(lhs, rhs);
+
+                for pattern in [&enc_rhs, &clear_rhs] {
+                    let result = sk.edit_distance(&enc_lhs, pattern.as_ref());
+                    assert_eq!(
+                        cks.inner().decrypt_radix::<u16>(&result),
+                        expected_edit,
+                        "edit_distance({lhs:?}, {rhs:?}) with padding {lhs_pad}, {rhs_pad}"
+                    );
+
+                    let result = sk.hamming_distance(&enc_lhs, pattern.as_ref());
+                    assert_eq!(
+                        cks.inner().decrypt_radix::<u16>(&result),
+                        expected_hamming,
+                        "hamming_distance({lhs:?}, {rhs:?}) with padding {lhs_pad}, {rhs_pad}"
+                    );
+
+                    for max_distance in [0, 1, 3] {
+                        let result = sk.fuzzy_eq(&enc_lhs, pattern.as_ref(), max_distance);
+                        assert_eq!(
+                            cks.inner().decrypt_bool(&result),
+                            expected_edit <= max_distance,
+                            "fuzzy_eq({lhs:?}, {rhs:?}, {max_distance}) with padding {lhs_pad}, \
+                            {rhs_pad}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // encrypted
+    {
+        let enc_lhs = FheString::new(&cks, "saturday", Some(1));
+        let enc_rhs = GenericPattern::Enc(FheString::new(&cks, "sunday", None));
+
+        let result = sk.edit_distance(&enc_lhs, enc_rhs.as_ref());
+        assert_eq!(cks.inner().decrypt_radix::<u16>(&result), 3);
+
+        let result = sk.fuzzy_eq(&enc_lhs, enc_rhs.as_ref(), 2);
+        assert!(!cks.inner().decrypt_bool(&result));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixCiphertext};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::{HashBackend, HashData, ServerKey};
+use std::fmt::Write;
+
+#[test]
+fn hash_test_parameterized() {
+    hash_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+fn decrypt_digest(
+    cks: &ClientKey<crate::integer::ClientKey>,
+    digest: &[RadixCiphertext; 8],
+) -> String {
+    digest.iter().fold(String::new(), |mut hex, word| {
+        write!(hex, "{:08x}", cks.inner().decrypt_radix::<u32>(word)).unwrap();
+        hex
+    })
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn hash_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let num_blocks = 8 / cks.parameters().message_modulus().0.ilog2() as usize;
+    let cks = ClientKey::new(cks);
+
+    let trivial_bytes = |bytes: &[u8]| -> Vec<RadixCiphertext> {
+        bytes
+            .iter()
+            .map(|byte| cks.inner().create_trivial_radix(*byte, num_blocks))
+            .collect()
+    };
+
+    // The whole computation is done on trivial ciphertexts, encrypted inputs take the same path
+    // but would make the test too slow
+    let sha256_vectors = [
+        (
+            "",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            "abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+
+    for backend in [HashBackend::Radix, HashBackend::Boolean] {
+        let sk = ServerKey::new(&sks).with_hash_backend(backend);
+
+        for (str, expected) in sha256_vectors {
+            for pad in [None, Some(10)] {
+                let enc_str = FheString::new_trivial(&cks, str, pad);
+
+                let digest = sk.sha256(HashData::String(&enc_str));
+                assert_eq!(
+                    decrypt_digest(&cks, &digest),
+                    expected,
+                    "sha256({str:?}) with padding {pad:?} and {backend:?}"
+                );
+            }
+
+            let bytes = trivial_bytes(str.as_bytes());
+            let digest = sk.sha256(HashData::Bytes(&bytes));
+            assert_eq!(
+                decrypt_digest(&cks, &digest),
+                expected,
+                "sha256({str:?}) as bytes with {backend:?}"
+            );
+        }
+
+        // RFC 4231, test case 2
+        let key = FheString::new_trivial(&cks, "Jefe", Some(3));
+        let message = FheString::new_trivial(&cks, "what do ya want for nothing?", None);
+        let mac = sk.hmac_sha256(HashData::String(&key), HashData::String(&message));
+        assert_eq!(
+            decrypt_digest(&cks, &mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            "hmac_sha256 with {backend:?}"
+        );
+
+        // RFC 4231, test case 6, the key is longer than a block
+        let key = trivial_bytes(&[0xaa; 131]);
+        let message = FheString::new_trivial(
+            &cks,
+            "Test Using Larger Than Block-Size Key - Hash Key First",
+            Some(2),
+        );
+        let mac = sk.hmac_sha256(HashData::Bytes(&key), HashData::String(&message));
+        assert_eq!(
+            decrypt_digest(&cks, &mac),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            "hmac_sha256 with a long key and {backend:?}"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+#[test]
+fn json_test_parameterized() {
+    json_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn json_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    let document = r#"{"a": "x,y", "b":{"c":12},"d" :1,"e":true}"#;
+
+    // trivial
+    for pad in [None, Some(3)] {
+        let enc_document = FheString::new_trivial(&cks, document, pad);
+
+        for (path, expected, expected_u64) in [
+            (&["a"][..], Some("x,y"), None),
+            (&["b", "c"], Some("12"), Some(12)),
+            (&["c"], Some("12"), Some(12)),
+            (&["e"], Some("true"), None),
+            // Whitespace between a key and its colon is not supported
+            (&["d"], None, None),
+            (&["f"], None, None),
+            (&["c", "a"], None, None),
+        ] {
+            let (value, found) = sk.json_get(&enc_document, path);
+            assert_eq!(
+                cks.inner().decrypt_bool(&found),
+                expected.is_some(),
+                "json_get({path:?}) with padding {pad:?}"
+            );
+            assert_eq!(
+                cks.decrypt_ascii(&value),
+                expected.unwrap_or(""),
+                "json_get({path:?}) with padding {pad:?}"
+            );
+
+            let (value, is_valid) = sk.json_get_u64(&enc_document, path);
+            assert_eq!(
+                cks.inner().decrypt_bool(&is_valid),
+                expected_u64.is_some(),
+                "json_get_u64({path:?}) with padding {pad:?}"
+            );
+            assert_eq!(
+                cks.inner().decrypt_radix::<u64>(&value),
+                expected_u64.unwrap_or(0),
+                "json_get_u64({path:?}) with padding {pad:?}"
+            );
+        }
+    }
+
+    // A number written as a string is not parsed
+    let enc_document = FheString::new_trivial(&cks, r#"{"n":"7"}"#, None);
+    let (value, is_valid) = sk.json_get_u64(&enc_document, &["n"]);
+    assert!(!cks.inner().decrypt_bool(&is_valid));
+    assert_eq!(cks.inner().decrypt_radix::<u64>(&value), 0);
+
+    // A key followed by whitespace is skipped, the next occurrence without whitespace is selected
+    let enc_document = FheString::new_trivial(&cks, r#"{"d" :1,"d":2}"#, None);
+    let (value, is_valid) = sk.json_get_u64(&enc_document, &["d"]);
+    assert!(cks.inner().decrypt_bool(&is_valid));
+    assert_eq!(cks.inner().decrypt_radix::<u64>(&value), 2);
+
+    for invalid_key in ["a\"b", "a\\b", "a\0b", "é", &"k".repeat(30)] {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            sk.json_get(&enc_document, &[invalid_key])
+        }));
+        assert!(
+            result.is_err(),
+            "json_get with the key {invalid_key:?} must panic"
+        );
+    }
+    let (_, found) = sk.json_get(&enc_document, &[&"k".repeat(29)]);
+    assert!(!cks.inner().decrypt_bool(&found));
+
+    // encrypted
+    {
+        let enc_document = FheString::new(&cks, r#"{"k": 5}"#, Some(1));
+
+        let (value, found) = sk.json_get(&enc_document, &["k"]);
+        assert!(cks.inner().decrypt_bool(&found));
+        assert_eq!(cks.decrypt_ascii(&value), "5");
+    }
+}
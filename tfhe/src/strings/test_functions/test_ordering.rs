@@ -0,0 +1,115 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, SignedRadixCiphertext};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern};
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+use std::cmp::Ordering;
+
+#[test]
+fn ordering_test_parameterized() {
+    ordering_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+/// Splits the string in runs of digits and runs of other characters, digit runs being compared
+/// by length first
+fn clear_natural_cmp(lhs: &str, rhs: &str) -> Ordering {
+    fn chunks(str: &str) -> Vec<(bool, &str)> {
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        let bytes = str.as_bytes();
+        for i in 1..=bytes.len() {
+            if i == bytes.len() || bytes[i].is_ascii_digit() != bytes[start].is_ascii_digit() {
+                chunks.push((bytes[start].is_ascii_digit(), &str[start..i]));
+                start = i;
+            }
+        }
+        chunks
+    }
+
+    let (lhs, rhs) = (chunks(lhs), chunks(rhs));
+    for ((l_is_number, l), (r_is_number, r)) in lhs.iter().zip(rhs.iter()) {
+        let ordering = if *l_is_number && *r_is_number {
+            l.len().cmp(&r.len()).then(l.cmp(r))
+        } else {
+            l.cmp(r)
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    lhs.len().cmp(&rhs.len())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ordering_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    let decrypt_ordering =
+        |result: &SignedRadixCiphertext| cks.inner().decrypt_signed_radix::<i8>(result);
+
+    // trivial
+    for lhs_pad in [0, 2] {
+        for rhs_pad in [0, 1] {
+            for (lhs, rhs) in [
+                ("", ""),
+                ("", "a"),
+                ("abc", "ABC"),
+                ("Apple", "apple"),
+                ("apple", "Banana"),
+                ("file2", "file10"),
+                ("file10", "file9"),
+                ("file10", "file10"),
+                ("a1b", "a12"),
+                ("a1", "ab"),
+                ("x10y2", "x10y11"),
+                ("7", "a"),
+            ] {
+                let enc_lhs = FheString::new_trivial(&cks, lhs, Some(lhs_pad));
+                let enc_rhs = GenericPattern::Enc(FheString::new_trivial(&cks, rhs, Some(rhs_pad)));
+                let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+                for pattern in [&enc_rhs, &clear_rhs] {
+                    let result = sk.cmp(&enc_lhs, pattern.as_ref());
+                    assert_eq!(
+                        decrypt_ordering(&result),
+                        lhs.cmp(rhs) as i8,
+                        "cmp({lhs:?}, {rhs:?}) with padding {lhs_pad}, {rhs_pad}"
+                    );
+
+                    let result = sk.cmp_ignore_case(&enc_lhs, pattern.as_ref());
+                    assert_eq!(
+                        decrypt_ordering(&result),
+                        lhs.to_lowercase().cmp(&rhs.to_lowercase()) as i8,
+                        "cmp_ignore_case({lhs:?}, {rhs:?}) with padding {lhs_pad}, {rhs_pad}"
+                    );
+
+                    let result = sk.natural_cmp(&enc_lhs, pattern.as_ref());
+                    assert_eq!(
+                        decrypt_ordering(&result),
+                        clear_natural_cmp(lhs, rhs) as i8,
+                        "natural_cmp({lhs:?}, {rhs:?}) with padding {lhs_pad}, {rhs_pad}"
+                    );
+                }
+            }
+        }
+    }
+
+    // encrypted
+    {
+        let enc_lhs = FheString::new(&cks, "img12", Some(1));
+        let enc_rhs = GenericPattern::Enc(FheString::new(&cks, "IMG3", None));
+
+        let result = sk.natural_cmp(&enc_lhs, enc_rhs.as_ref());
+        assert_eq!(decrypt_ordering(&result), Ordering::Greater as i8);
+
+        let result = sk.cmp_ignore_case(&enc_lhs, enc_rhs.as_ref());
+        assert_eq!(decrypt_ordering(&result), Ordering::Less as i8);
+    }
+}
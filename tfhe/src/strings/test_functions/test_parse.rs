@@ -0,0 +1,128 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+#[test]
+fn parse_test_parameterized() {
+    parse_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+#[test]
+fn to_decimal_string_test_parameterized() {
+    to_decimal_string_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn parse_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for pad in [0, 2] {
+        for (str, radix) in [
+            ("", 10),
+            ("0", 10),
+            ("42", 10),
+            ("+42", 10),
+            ("-42", 10),
+            ("+", 10),
+            ("-", 10),
+            ("4a2", 10),
+            (" 42", 10),
+            ("18446744073709551615", 10),
+            ("18446744073709551616", 10),
+            ("9223372036854775807", 10),
+            ("-9223372036854775808", 10),
+            ("-9223372036854775809", 10),
+            ("00000000000000000000000012", 10),
+            ("fF", 16),
+            ("-7fffffffffffffff", 16),
+            ("g", 16),
+            ("101", 2),
+            ("102", 2),
+            ("zz", 36),
+        ] {
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let (value, is_valid) = sk.parse_u64(&enc_str, radix);
+            let expected = u64::from_str_radix(str, radix).ok();
+            assert_eq!(
+                cks.inner().decrypt_bool(&is_valid),
+                expected.is_some(),
+                "parse_u64({str:?}, {radix}) with padding {pad}"
+            );
+            assert_eq!(
+                cks.inner().decrypt_radix::<u64>(&value),
+                expected.unwrap_or(0),
+                "parse_u64({str:?}, {radix}) with padding {pad}"
+            );
+
+            let (value, is_valid) = sk.parse_i64(&enc_str, radix);
+            let expected = i64::from_str_radix(str, radix).ok();
+            assert_eq!(
+                cks.inner().decrypt_bool(&is_valid),
+                expected.is_some(),
+                "parse_i64({str:?}, {radix}) with padding {pad}"
+            );
+            assert_eq!(
+                cks.inner().decrypt_signed_radix::<i64>(&value),
+                expected.unwrap_or(0),
+                "parse_i64({str:?}, {radix}) with padding {pad}"
+            );
+        }
+    }
+
+    // encrypted
+    {
+        for (str, expected) in [("-123", Some(-123)), ("1-23", None)] {
+            let enc_str = FheString::new(&cks, str, Some(1));
+
+            let (value, is_valid) = sk.parse_i64(&enc_str, 10);
+            assert_eq!(cks.inner().decrypt_bool(&is_valid), expected.is_some());
+            assert_eq!(
+                cks.inner().decrypt_signed_radix::<i64>(&value),
+                expected.unwrap_or(0)
+            );
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn to_decimal_string_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for num_blocks in [1, 4, 32] {
+        let max = u64::MAX >> (64 - 2 * num_blocks);
+        for value in [0, 7, 10, 100, max / 3, max]
+            .into_iter()
+            .filter(|v| *v <= max)
+        {
+            let n = sk.inner().create_trivial_radix(value, num_blocks);
+
+            let result = sk.to_decimal_string(&n);
+            assert_eq!(cks.decrypt_ascii(&result), value.to_string());
+        }
+    }
+
+    // encrypted
+    {
+        let n = cks.inner().encrypt_radix(4_200_000_069u64, 32);
+
+        let result = sk.to_decimal_string(&n);
+        assert_eq!(cks.decrypt_ascii(&result), "4200000069");
+    }
+}
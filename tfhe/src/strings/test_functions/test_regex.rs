@@ -0,0 +1,161 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixCiphertext};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::regex::CompiledRegex;
+use crate::strings::server_key::ServerKey;
+
+#[test]
+fn regex_test_parameterized() {
+    regex_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+#[test]
+fn regex_parse_errors() {
+    for pattern in [
+        "a{2",
+        "(ab",
+        "ab)",
+        "*a",
+        "[a-",
+        "[z-a]",
+        "^*",
+        "a{3,2}",
+        "a{65}",
+        "é",
+        // Each repetition is small, but the expanded pattern is not
+        "(a{64}){64}",
+        "((a{64}){64}){64}",
+    ] {
+        assert!(
+            CompiledRegex::new(pattern).is_err(),
+            "{pattern} should fail"
+        );
+    }
+
+    assert!(CompiledRegex::new(r"\w{64}").is_ok());
+    assert_eq!(CompiledRegex::new("ab").unwrap().num_groups(), 0);
+    assert_eq!(CompiledRegex::new(r"(\w+)@(\w+)").unwrap().num_groups(), 2);
+    // Nested groups are not capturing
+    assert_eq!(CompiledRegex::new("((a)|b)*c(d)").unwrap().num_groups(), 1);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn regex_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    let decrypt_position = |ct: &RadixCiphertext| cks.inner().decrypt_radix::<u32>(ct);
+
+    let is_match_cases = [
+        ("ab", "ab", false, true),
+        ("a?b", "b", false, true),
+        ("^ab|cd$", "abcd", false, true),
+        ("^ab|cd$", "xabcdx", false, false),
+        ("^[0-9]*$", "4453", false, true),
+        ("^[0-9]*$", "44a3", false, false),
+        ("a{2,3}", "xaay", false, true),
+        ("^a{2,3}$", "aaaa", false, false),
+        ("[^a-c]", "abc", false, false),
+        ("[^a-c]", "abcd", false, true),
+        ("^$", "", false, true),
+        ("b$", "ab", false, true),
+        ("^hello$", "HeLLo", true, true),
+        ("[^a]", "A", true, false),
+    ];
+
+    // trivial
+    for pad in [0, 2] {
+        for (pattern, str, case_insensitive, expected) in is_match_cases {
+            let regex = if case_insensitive {
+                CompiledRegex::new_case_insensitive(pattern).unwrap()
+            } else {
+                CompiledRegex::new(pattern).unwrap()
+            };
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let result = sk.regex_is_match(&enc_str, &regex);
+            assert_eq!(
+                cks.inner().decrypt_bool(&result),
+                expected,
+                "is_match({pattern:?}, {str:?}) with padding {pad}"
+            );
+        }
+
+        for (pattern, str, expected) in [
+            ("[0-9]+", "id: 042", Some((4, 7))),
+            ("a*", "bbb", Some((0, 0))),
+            ("b+", "abbbc", Some((1, 4))),
+            ("c$", "abcc", Some((3, 4))),
+            ("x", "abc", None),
+        ] {
+            let regex = CompiledRegex::new(pattern).unwrap();
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let (start, end, found) = sk.regex_find(&enc_str, &regex);
+            let result = (decrypt_position(&start), decrypt_position(&end));
+            let found = cks.inner().decrypt_bool(&found);
+
+            assert_eq!(found, expected.is_some(), "find({pattern:?}, {str:?})");
+            assert_eq!(
+                result,
+                expected.unwrap_or((0, 0)),
+                "find({pattern:?}, {str:?})"
+            );
+        }
+
+        for (pattern, str, expected) in [
+            (
+                r"(\w+)@(\w+)",
+                "to: bob@zama",
+                Some(vec![(4, 12), (4, 7), (8, 12)]),
+            ),
+            ("(a*)(a)", "aaa", Some(vec![(0, 3), (0, 2), (2, 3)])),
+            (
+                r"^(\d+)-(\d+)$",
+                "12-345",
+                Some(vec![(0, 6), (0, 2), (3, 6)]),
+            ),
+            ("(a)b", "ac", None),
+        ] {
+            let regex = CompiledRegex::new(pattern).unwrap();
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let (captures, found) = sk.regex_captures(&enc_str, &regex);
+            let result: Vec<_> = captures
+                .iter()
+                .map(|(start, end)| (decrypt_position(start), decrypt_position(end)))
+                .collect();
+            let found = cks.inner().decrypt_bool(&found);
+
+            assert_eq!(found, expected.is_some(), "captures({pattern:?}, {str:?})");
+            let expected = expected.unwrap_or_else(|| vec![(0, 0); regex.num_groups() + 1]);
+            assert_eq!(result, expected, "captures({pattern:?}, {str:?})");
+        }
+    }
+
+    // encrypted
+    {
+        let regex = CompiledRegex::new("^a.c$").unwrap();
+
+        for (str, expected) in [("abc", true), ("abd", false)] {
+            let enc_str = FheString::new(&cks, str, Some(1));
+
+            let result = sk.regex_is_match(&enc_str, &regex);
+            assert_eq!(cks.inner().decrypt_bool(&result), expected);
+        }
+
+        let regex = CompiledRegex::new("b+").unwrap();
+        let enc_str = FheString::new(&cks, "abbc", None);
+
+        let (start, end, found) = sk.regex_find(&enc_str, &regex);
+        assert!(cks.inner().decrypt_bool(&found));
+        assert_eq!((decrypt_position(&start), decrypt_position(&end)), (1, 3));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{FheString, UIntArg};
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+#[test]
+fn slice_test_parameterized() {
+    slice_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+}
+
+fn clear_substring(str: &str, start: u16, end: u16) -> &str {
+    let end = (end as usize).min(str.len());
+    let start = (start as usize).min(end);
+    &str[start..end]
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn slice_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for pad in [0, 2] {
+        for str in ["", "a", "abc", "hello world"] {
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            for index in [0u16, 1, 2, 10, 11, 300] {
+                let expected = str.as_bytes().get(index as usize).copied();
+
+                for index in [
+                    UIntArg::Clear(index),
+                    UIntArg::Enc(cks.trivial_encrypt_u16(index, None)),
+                ] {
+                    let (char, in_bounds) = sk.char_at(&enc_str, &index);
+                    assert_eq!(
+                        cks.inner().decrypt_bool(&in_bounds),
+                        expected.is_some(),
+                        "char_at({str:?}) with padding {pad}"
+                    );
+                    assert_eq!(
+                        cks.inner().decrypt_radix::<u8>(&char),
+                        expected.unwrap_or(0),
+                        "char_at({str:?}) with padding {pad}"
+                    );
+                }
+            }
+
+            for (start, end) in [(0, 0), (0, 1), (1, 3), (2, 1), (3, 11), (6, 300), (20, 30)] {
+                let expected = clear_substring(str, start, end);
+
+                let clear_start = UIntArg::Clear(start);
+                let enc_start = UIntArg::Enc(cks.trivial_encrypt_u16(start, None));
+                let clear_end = UIntArg::Clear(end);
+                let enc_end = UIntArg::Enc(cks.trivial_encrypt_u16(end, None));
+
+                for (start, end) in [
+                    (&clear_start, &clear_end),
+                    (&clear_start, &enc_end),
+                    (&enc_start, &clear_end),
+                    (&enc_start, &enc_end),
+                ] {
+                    let result = sk.substring(&enc_str, start, end);
+                    assert_eq!(
+                        cks.decrypt_ascii(&result),
+                        expected,
+                        "substring({str:?}) with padding {pad}"
+                    );
+                }
+
+                let result = sk.truncate(&enc_str, &enc_end);
+                assert_eq!(cks.decrypt_ascii(&result), clear_substring(str, 0, end));
+            }
+        }
+    }
+
+    // encrypted
+    {
+        let str = "hello world";
+        let enc_str = FheString::new(&cks, str, Some(1));
+
+        let index = UIntArg::Enc(cks.encrypt_u16(4, None));
+        let (char, in_bounds) = sk.char_at(&enc_str, &index);
+        assert!(cks.inner().decrypt_bool(&in_bounds));
+        assert_eq!(cks.inner().decrypt_radix::<u8>(&char), b'o');
+
+        let start = UIntArg::Enc(cks.encrypt_u16(2, None));
+        let end = UIntArg::Enc(cks.encrypt_u16(7, None));
+        let result = sk.substring(&enc_str, &start, &end);
+        assert_eq!(cks.decrypt_ascii(&result), &str[2..7]);
+    }
+}